@@ -1,8 +1,8 @@
 use async_trait::async_trait;
 use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
 use gatehouse::{
-    EvaluationSession, FactLoadResult, FactRegistry, FactSource, PermissionChecker, PolicyBuilder,
-    PolicyDomain, RebacPolicy, RelationshipQuery,
+    EvaluationSession, FactLoadResult, FactRegistry, FactSource, PermissionChecker, Policy,
+    PolicyBuilder, PolicyDomain, PolicyExt, RebacPolicy, RelationshipQuery,
 };
 use std::collections::HashMap;
 use std::hint::black_box;
@@ -678,12 +678,69 @@ fn bench_subject_only_batch(c: &mut Criterion) {
     group.finish();
 }
 
+/// Builds a tree `depth` combinators deep that alternates AND and OR levels,
+/// each paired with a leaf, so every level is evaluated before a grant.
+fn build_nested_tree(depth: usize) -> Box<dyn Policy<UnitDomain>> {
+    let mut policy = PolicyBuilder::<UnitDomain>::new("nested_leaf").build();
+    for level in 0..depth {
+        policy = if level % 2 == 0 {
+            policy
+                .and(PolicyBuilder::<UnitDomain>::new(format!("and_allow_{level}")).build())
+                .boxed()
+        } else {
+            policy
+                .or(PolicyBuilder::<UnitDomain>::new(format!("or_deny_{level}"))
+                    .when(|_: &Subject, _: &Action, _: &Resource, _: &Context| false)
+                    .build())
+                .boxed()
+        };
+    }
+    policy
+}
+
+fn bench_compiled_policy_tree(c: &mut Criterion) {
+    let runtime = Runtime::new().expect("failed to create Tokio runtime");
+    let subject: Subject = ();
+    let action: Action = ();
+    let resource: Resource = ();
+    let context: Context = ();
+    let mut group = c.benchmark_group("compiled_policy_tree");
+
+    for &depth in &[1usize, 5, 16] {
+        let mut interpreted_checker = PermissionChecker::<UnitDomain>::new();
+        interpreted_checker.add_policy(build_nested_tree(depth));
+
+        let mut compiled_checker = PermissionChecker::<UnitDomain>::new();
+        compiled_checker.add_policy(build_nested_tree(depth).compile());
+
+        for (label, checker) in [
+            ("interpreted", &interpreted_checker),
+            ("compiled", &compiled_checker),
+        ] {
+            group.bench_with_input(BenchmarkId::new(label, depth), checker, |b, checker| {
+                b.iter(|| {
+                    let session = EvaluationSession::empty();
+                    let result = runtime.block_on(
+                        checker
+                            .bind(&session, &subject, &action, &context)
+                            .check(&resource),
+                    );
+                    black_box(result)
+                });
+            });
+        }
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_permission_checker,
     bench_in_ram_fact_source,
     bench_latency_fact_source,
     bench_parallel_fact_state,
-    bench_subject_only_batch
+    bench_subject_only_batch,
+    bench_compiled_policy_tree
 );
 criterion_main!(benches);
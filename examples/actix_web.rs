@@ -43,7 +43,7 @@ use actix_web::{
 use async_trait::async_trait;
 use gatehouse::{
     AccessEvaluation, AndPolicy, EvalTrace, EvaluationSession, FactLoadResult, FactRegistry,
-    FactSource, PermissionChecker, Policy, PolicyBuilder, PolicyDomain, RebacPolicy,
+    FactSource, PermissionChecker, Policy, PolicyBuilder, PolicyDomain, PolicyExt, RebacPolicy,
     RelationshipQuery,
 };
 use serde::Serialize;
@@ -323,12 +323,12 @@ fn collaborator_policy() -> Box<dyn Policy<BlogDomain>> {
             .when(|_user, action, _post, _ctx| matches!(action, Action::View | Action::Edit))
             .build(),
     );
-    let has_editor_relationship: Arc<dyn Policy<BlogDomain>> =
-        Arc::new(RebacPolicy::<BlogDomain, Uuid, Uuid, Relation>::new(
-            |user: &User| user.id,
-            |post: &BlogPost| post.id,
-            Relation::Editor,
-        ));
+    let has_editor_relationship = RebacPolicy::<BlogDomain, Uuid, Uuid, Relation>::new(
+        |user: &User| user.id,
+        |post: &BlogPost| post.id,
+        Relation::Editor,
+    )
+    .arced();
 
     Box::new(
         AndPolicy::try_new(vec![is_view_or_edit, has_editor_relationship])
@@ -359,13 +359,14 @@ fn published_posts_are_public_policy() -> Box<dyn Policy<BlogDomain>> {
 }
 
 pub fn build_permission_checker() -> PermissionChecker<BlogDomain> {
-    let mut checker = PermissionChecker::named("BlogPostChecker");
-    checker.add_policy(admin_override_policy());
-    checker.add_policy(author_can_edit_policy());
-    checker.add_policy(collaborator_policy());
-    checker.add_policy(editors_can_publish_policy());
-    checker.add_policy(published_posts_are_public_policy());
-    checker
+    PermissionChecker::builder()
+        .name("BlogPostChecker")
+        .policy(admin_override_policy())
+        .policy(author_can_edit_policy())
+        .policy(collaborator_policy())
+        .policy(editors_can_publish_policy())
+        .policy(published_posts_are_public_policy())
+        .build()
 }
 
 // -------------------------
@@ -9,7 +9,8 @@
 // widening one checker over a `Resource` enum.
 //
 // Authorization paths:
-//   - an admin may do anything (cross-cutting override),
+//   - an admin may do anything (cross-cutting override), and the grant obliges
+//     the handler to flag the response as an admin override,
 //   - the owner may edit an unlocked invoice that is under 30 days old,
 //   - a user with a `viewer` relationship may view an invoice (the relationship
 //     is loaded through a request-scoped `EvaluationSession` + `FactSource`).
@@ -18,12 +19,13 @@ use async_trait::async_trait;
 use axum::{
     extract::{FromRequestParts, Path, State},
     http::{request::Parts, HeaderMap, StatusCode},
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     routing::{get, post},
     Json, Router,
 };
 use gatehouse::*;
 use serde::Serialize;
+use std::borrow::Cow;
 use std::collections::HashSet;
 use std::fmt;
 use std::sync::Arc;
@@ -225,13 +227,14 @@ impl From<Invoice> for InvoiceSummary {
 // 2) Shared application state
 // --------------------------
 
-/// The long-lived pieces are built once at startup: the checker and fact
-/// registry. Each request derives a fresh `EvaluationSession` from the
-/// registry.
+/// The long-lived pieces are built once at startup: the checker, fact
+/// registry, and obligation handlers. Each request derives a fresh
+/// `EvaluationSession` from the registry.
 #[derive(Clone)]
 pub struct AppState {
     checker: PermissionChecker<InvoiceDomain>,
     fact_registry: FactRegistry,
+    obligations: ObligationHandlers<Response>,
     invoices: Arc<Vec<Invoice>>,
 }
 
@@ -255,6 +258,8 @@ impl AppState {
             fact_registry: FactRegistry::builder()
                 .with_arc::<InvoiceRelationship>(Arc::new(InMemoryRelationshipSource::new(grants)))
                 .build(),
+            // Each obligation id a policy can attach maps to a response change.
+            obligations: ObligationHandlers::<Response>::new().header("audit", "x-access-audit"),
             invoices,
         }
     }
@@ -300,11 +305,28 @@ fn demo_invoices() -> Vec<Invoice> {
 // --------------------------
 // Each policy handles a slice of the logic; the checker ORs them together.
 
-/// (A) Admins may do anything — the cross-cutting override.
+/// (A) Admins may do anything — the cross-cutting override. The grant carries
+/// an `audit` obligation, so responses served through the override say so.
+struct AdminOverridePolicy;
+
+#[async_trait]
+impl Policy<InvoiceDomain> for AdminOverridePolicy {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, InvoiceDomain>) -> PolicyEvalResult {
+        if ctx.subject.roles.iter().any(|role| role == "admin") {
+            ctx.grant("User is an admin")
+                .with_obligation(Obligation::new("audit", "admin-override"))
+        } else {
+            ctx.not_applicable("User is not an admin")
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        Cow::Borrowed("AdminOverridePolicy")
+    }
+}
+
 fn admin_override_policy() -> Box<dyn Policy<InvoiceDomain>> {
-    PolicyBuilder::<InvoiceDomain>::new("AdminOverridePolicy")
-        .when(|user, _action, _invoice, _ctx| user.roles.contains(&"admin".to_string()))
-        .build()
+    Box::new(AdminOverridePolicy)
 }
 
 /// (B) A user with a `viewer` relationship may view the invoice. The
@@ -316,12 +338,12 @@ fn invoice_viewer_policy() -> Box<dyn Policy<InvoiceDomain>> {
             .when(|_user, action, _invoice, _ctx| matches!(action, Action::View))
             .build(),
     );
-    let viewer_relationship: Arc<dyn Policy<InvoiceDomain>> =
-        Arc::new(RebacPolicy::<InvoiceDomain, Uuid, Uuid, Relation>::new(
-            |user: &User| user.id,
-            |invoice: &Invoice| invoice.id,
-            Relation::Viewer,
-        ));
+    let viewer_relationship = RebacPolicy::<InvoiceDomain, Uuid, Uuid, Relation>::new(
+        |user: &User| user.id,
+        |invoice: &Invoice| invoice.id,
+        Relation::Viewer,
+    )
+    .arced();
 
     Box::new(
         AndPolicy::try_new(vec![is_view, viewer_relationship])
@@ -330,52 +352,43 @@ fn invoice_viewer_policy() -> Box<dyn Policy<InvoiceDomain>> {
 }
 
 /// (C) The owner may edit the invoice if it is unlocked and under 30 days old.
-/// Built from small sub-policies AND-ed together, so a denial trace names the
-/// sub-policy that failed.
+/// Each condition is labeled, so a denial trace names the condition that
+/// failed.
 fn invoice_editing_policy() -> Box<dyn Policy<InvoiceDomain>> {
-    let is_edit = PolicyBuilder::<InvoiceDomain>::new("IsEdit")
-        .when(|_user, action, _invoice, _ctx| matches!(action, Action::Edit))
-        .build();
-
-    let is_owner = PolicyBuilder::<InvoiceDomain>::new("IsOwnerOfInvoice")
-        .when(|user, _action, invoice, _ctx| user.id == invoice.owner_id)
-        .build();
-
-    let invoice_not_locked = PolicyBuilder::<InvoiceDomain>::new("InvoiceNotLocked")
-        .when(|_user, _action, invoice, _ctx| !invoice.locked)
-        .build();
-
     const THIRTY_DAYS: u64 = 30 * 24 * 60 * 60;
-    let invoice_age_under_30_days = PolicyBuilder::<InvoiceDomain>::new("InvoiceAgeUnder30Days")
-        .when(move |_user, _action, invoice, ctx| {
-            ctx.current_time
-                .duration_since(invoice.created_at)
-                .unwrap_or_default()
-                .as_secs()
-                <= THIRTY_DAYS
+    PolicyBuilder::<InvoiceDomain>::new("InvoiceEditingPolicy")
+        .and_when("IsEdit", |_user, action, _invoice, _ctx| {
+            matches!(action, Action::Edit)
         })
-        .build();
-
-    Box::new(
-        AndPolicy::try_new(vec![
-            Arc::from(is_edit),
-            Arc::from(is_owner),
-            Arc::from(invoice_not_locked),
-            Arc::from(invoice_age_under_30_days),
-        ])
-        .expect("invoice editing policy has at least one rule"),
-    )
+        .and_when("IsOwnerOfInvoice", |user, _action, invoice, _ctx| {
+            user.id == invoice.owner_id
+        })
+        .and_when("InvoiceNotLocked", |_user, _action, invoice, _ctx| {
+            !invoice.locked
+        })
+        .and_when(
+            "InvoiceAgeUnder30Days",
+            move |_user, _action, invoice, ctx| {
+                ctx.current_time
+                    .duration_since(invoice.created_at)
+                    .unwrap_or_default()
+                    .as_secs()
+                    <= THIRTY_DAYS
+            },
+        )
+        .build()
 }
 
 /// (D) Combine the policies into a single `PermissionChecker`. With no
 /// forbid-effect policies registered, deny-overrides reduces to OR semantics:
 /// if any policy grants, access is allowed (and evaluation short-circuits).
 pub fn build_permission_checker() -> PermissionChecker<InvoiceDomain> {
-    let mut checker = PermissionChecker::named("InvoiceChecker");
-    checker.add_policy(admin_override_policy());
-    checker.add_policy(invoice_viewer_policy());
-    checker.add_policy(invoice_editing_policy());
-    checker
+    PermissionChecker::builder()
+        .name("InvoiceChecker")
+        .policy(admin_override_policy())
+        .policy(invoice_viewer_policy())
+        .policy(invoice_editing_policy())
+        .build()
 }
 
 // ---------------------------------
@@ -393,18 +406,29 @@ pub async fn view_invoice_handler(
     let session = state.request_session();
     let context = RequestContext::now();
 
-    if state
+    let evaluation = state
         .checker
         .bind(&session, &user, &Action::View, &context)
         .check(&invoice)
-        .await
-        .is_granted()
-    {
-        (StatusCode::OK, format!("{invoice:?}")).into_response()
+        .await;
+    if evaluation.is_granted() {
+        // The grant is conditional on its obligations: enforce each on the
+        // response, and fail rather than serve it if one is not understood.
+        let mut response = (StatusCode::OK, format!("{invoice:?}")).into_response();
+        if !state
+            .obligations
+            .apply(&evaluation, &mut response)
+            .is_empty()
+        {
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+        response
     } else {
+        // `ForbiddenResponse` is a fixed `{ code, message, requirements }`
+        // body, so the 403 can be described once in the OpenAPI spec.
         (
             StatusCode::FORBIDDEN,
-            "You are not authorized to view this invoice",
+            Json(ForbiddenResponse::from(&evaluation)),
         )
             .into_response()
     }
@@ -653,10 +677,26 @@ mod integration_tests {
 
     fn test_app() -> Router {
         Router::new()
+            .route("/invoices/{invoice_id}", get(view_invoice_handler))
             .route("/invoices/{invoice_id}/edit", post(edit_invoice_handler))
             .with_state(AppState::demo())
     }
 
+    #[tokio::test]
+    async fn view_invoice_handler_flags_admin_overrides() {
+        let app = test_app();
+
+        let req = Request::builder()
+            .uri("/invoices/11111111-1111-1111-1111-111111111111")
+            .header("x-roles", "admin")
+            .body(Body::empty())
+            .unwrap();
+
+        let response = app.clone().oneshot(req).await.unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()["x-access-audit"], "admin-override");
+    }
+
     #[tokio::test]
     async fn edit_invoice_handler_allows_admin() {
         let app = test_app();
@@ -29,7 +29,8 @@
 //! ```
 
 use gatehouse::{
-    AndPolicy, EvaluationSession, NotPolicy, PermissionChecker, Policy, PolicyBuilder, PolicyDomain,
+    AndPolicy, EvaluationSession, NotPolicy, PermissionChecker, Policy, PolicyBuilder,
+    PolicyDomain, PolicyExt,
 };
 use std::sync::Arc;
 use uuid::Uuid;
@@ -270,11 +271,9 @@ async fn scoped_exclusion_demo() {
         .subjects(|member| member.muted)
         .build();
 
-    let collaborator_unless_muted = AndPolicy::try_new(vec![
-        collaborator_policy,
-        Arc::new(NotPolicy::new(muted)) as Arc<dyn Policy<ThreadDomain>>,
-    ])
-    .expect("gate has the grant arm and the guard");
+    let collaborator_unless_muted =
+        AndPolicy::try_new(vec![collaborator_policy, NotPolicy::new(muted).arced()])
+            .expect("gate has the grant arm and the guard");
 
     let mut checker = PermissionChecker::<ThreadDomain>::named("ThreadChecker");
     checker.add_policy(owner_policy);
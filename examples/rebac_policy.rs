@@ -9,7 +9,8 @@
 //! deduplicates and caches by the typed `RelationshipQuery` key, the compiler
 //! checks relation names, and any backend-specific serialization stays inside
 //! the `FactSource`. Relationship store failures are returned as
-//! `FactLoadResult::Error` and fail closed to denial — asserted at the end.
+//! `FactLoadResult::Error`, reported as an indeterminate result, and fail
+//! closed to denial — asserted at the end.
 //!
 //! To run this example:
 //! ```
@@ -223,4 +224,5 @@ async fn main() {
     println!("{}", decision.display_trace());
     decision.assert_denied();
     decision.assert_trace_contains("simulated relationship store error");
+    decision.assert_trace_contains("INDETERMINATE");
 }
@@ -0,0 +1,142 @@
+//! Policy structure merged with the outcomes of one evaluation.
+//!
+//! An [`AnnotatedTree`] mirrors the checker's static policy tree, expanded
+//! through [`crate::Policy::composition`], and pairs every node with what
+//! happened to it at runtime. Nodes skipped by a short-circuit stay in the
+//! tree as [`NodeOutcome::NotEvaluated`], so a debugger can render the full
+//! structure colored by the evaluation.
+
+use crate::{
+    AccessEvaluation, CombineOp, Policy, PolicyDomain, PolicyEvalResult,
+    PERMISSION_CHECKER_POLICY_TYPE,
+};
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// What happened to one structural node during an evaluation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum NodeOutcome {
+    /// The node was skipped because an earlier sibling or ancestor already
+    /// decided the result.
+    NotEvaluated,
+    /// The node granted.
+    Granted {
+        /// The grant reason, if the node reported one.
+        reason: Option<String>,
+    },
+    /// The node did not grant and did not forbid.
+    NotApplicable {
+        /// The reason, if the node reported one. Combinators do not.
+        reason: Option<String>,
+    },
+    /// The node forbade, directly or through a forbidding descendant.
+    Forbidden {
+        /// The reason, if the node reported one. Combinators do not.
+        reason: Option<String>,
+    },
+    /// The node could not decide, directly or through an undecided
+    /// descendant, and did not grant.
+    Indeterminate {
+        /// The reason, if the node reported one. Combinators do not.
+        reason: Option<String>,
+    },
+}
+
+impl NodeOutcome {
+    fn from_result(result: &PolicyEvalResult) -> Self {
+        let reason = result.reason();
+        if result.is_forbidden() {
+            Self::Forbidden { reason }
+        } else if result.is_granted() {
+            Self::Granted { reason }
+        } else if result.is_indeterminate() {
+            Self::Indeterminate { reason }
+        } else {
+            Self::NotApplicable { reason }
+        }
+    }
+
+    /// Whether the node was evaluated at all.
+    pub fn is_evaluated(&self) -> bool {
+        !matches!(self, Self::NotEvaluated)
+    }
+}
+
+/// One node of an [`AnnotatedTree`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AnnotatedNode {
+    /// The node's [`Policy::policy_type`].
+    pub policy_type: Cow<'static, str>,
+    /// The combining operation for combinator nodes, `None` for leaves.
+    pub operation: Option<CombineOp>,
+    /// The node's runtime outcome.
+    pub outcome: NodeOutcome,
+    /// Structural children in evaluation order, each annotated.
+    pub children: Vec<AnnotatedNode>,
+}
+
+/// A checker's policy tree annotated with the outcome of one evaluation.
+///
+/// Returned by [`crate::PermissionChecker::evaluate_annotated`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct AnnotatedTree {
+    /// The evaluation the annotations were taken from.
+    pub evaluation: AccessEvaluation,
+    /// The checker root. Its children are the checker's policies in
+    /// evaluation order.
+    pub root: AnnotatedNode,
+}
+
+pub(crate) fn annotate_checker<D: PolicyDomain>(
+    policies: &[Arc<dyn Policy<D>>],
+    operation: CombineOp,
+    evaluation: AccessEvaluation,
+) -> AnnotatedTree {
+    let root_result = evaluation.trace().root();
+    let root = AnnotatedNode {
+        policy_type: Cow::Borrowed(PERMISSION_CHECKER_POLICY_TYPE),
+        operation: Some(operation),
+        outcome: root_result.map_or(NodeOutcome::NotEvaluated, NodeOutcome::from_result),
+        children: annotate_children(policies, root_result),
+    };
+    AnnotatedTree { evaluation, root }
+}
+
+fn annotate_children<D: PolicyDomain>(
+    policies: &[Arc<dyn Policy<D>>],
+    result: Option<&PolicyEvalResult>,
+) -> Vec<AnnotatedNode> {
+    // Evaluation is sequential, so the evaluated children are always a prefix
+    // of the structural children.
+    let child_results = match result {
+        Some(PolicyEvalResult::Combined { children, .. }) => children.as_slice(),
+        _ => &[],
+    };
+    policies
+        .iter()
+        .enumerate()
+        .map(|(index, policy)| annotate(policy, child_results.get(index)))
+        .collect()
+}
+
+fn annotate<D: PolicyDomain>(
+    policy: &Arc<dyn Policy<D>>,
+    result: Option<&PolicyEvalResult>,
+) -> AnnotatedNode {
+    let composition = policy.composition();
+    AnnotatedNode {
+        policy_type: policy.policy_type(),
+        operation: composition
+            .as_ref()
+            .map(|composition| composition.operation),
+        outcome: result.map_or(NodeOutcome::NotEvaluated, NodeOutcome::from_result),
+        children: composition
+            .map(|composition| annotate_children(composition.children, result))
+            .unwrap_or_default(),
+    }
+}
@@ -1,6 +1,16 @@
-use crate::{BatchEvalCtx, Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult};
+use crate::{
+    BatchEvalCtx, CombineOp, Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult,
+    ProvidesAuthTime,
+};
 use async_trait::async_trait;
+use std::borrow::Cow;
 use std::marker::PhantomData;
+use std::time::{Duration, SystemTime};
+
+const DEFAULT_GRANT_REASON: &str = "Policy allowed access";
+const NOT_MATCHED_REASON: &str = "Policy predicate did not match";
+const NOT_APPLICABLE_REASON: &str = "Policy does not apply to this request";
+const FRESH_AUTH_POLICY_TYPE: &str = "FreshAuth";
 
 type SubjectPredicate<D> = Box<dyn Fn(&<D as PolicyDomain>::Subject) -> bool + Send + Sync>;
 type ActionPredicate<D> = Box<dyn Fn(&<D as PolicyDomain>::Action) -> bool + Send + Sync>;
@@ -16,6 +26,68 @@ type WhenPredicate<D> = Box<
         + Send
         + Sync,
 >;
+type DenyReason<D> = Box<
+    dyn Fn(
+            &<D as PolicyDomain>::Subject,
+            &<D as PolicyDomain>::Action,
+            &<D as PolicyDomain>::Resource,
+            &<D as PolicyDomain>::Context,
+        ) -> String
+        + Send
+        + Sync,
+>;
+type AuthedAt<D> = Box<
+    dyn Fn(&<D as PolicyDomain>::Subject, &<D as PolicyDomain>::Context) -> Option<SystemTime>
+        + Send
+        + Sync,
+>;
+
+/// The authentication freshness check added with
+/// [`PolicyBuilder::require_fresh_auth`].
+struct FreshAuth<D: PolicyDomain> {
+    max_age: Duration,
+    authed_at: AuthedAt<D>,
+}
+
+impl<D: PolicyDomain> FreshAuth<D> {
+    fn evaluate(&self, subject: &D::Subject, context: &D::Context) -> PolicyEvalResult {
+        let Some(authed_at) = (self.authed_at)(subject, context) else {
+            return PolicyEvalResult::not_applicable(
+                FRESH_AUTH_POLICY_TYPE,
+                "Authentication time is unknown",
+            );
+        };
+        // A timestamp slightly in the future is clock skew, not a stale session.
+        let age = SystemTime::now()
+            .duration_since(authed_at)
+            .unwrap_or_default();
+        let age = Duration::from_secs(age.as_secs());
+        if age <= self.max_age {
+            PolicyEvalResult::granted(
+                FRESH_AUTH_POLICY_TYPE,
+                Some(format!(
+                    "Authenticated {age:?} ago, within {:?}",
+                    self.max_age
+                )),
+            )
+        } else {
+            PolicyEvalResult::not_applicable(
+                FRESH_AUTH_POLICY_TYPE,
+                format!(
+                    "Authenticated {age:?} ago; re-authentication required after {:?}",
+                    self.max_age
+                ),
+            )
+        }
+    }
+}
+
+/// A labeled predicate added with [`PolicyBuilder::and_when`] or
+/// [`PolicyBuilder::or_when`].
+struct Condition<D: PolicyDomain> {
+    label: Cow<'static, str>,
+    pred: WhenPredicate<D>,
+}
 
 /// An internal policy type constructed by [`PolicyBuilder`].
 struct InternalPolicy<D: PolicyDomain> {
@@ -25,7 +97,12 @@ struct InternalPolicy<D: PolicyDomain> {
     action_pred: Option<ActionPredicate<D>>,
     resource_pred: Option<ResourcePredicate<D>>,
     context_pred: Option<ContextPredicate<D>>,
+    applies_pred: Option<WhenPredicate<D>>,
     when_pred: Option<WhenPredicate<D>>,
+    alternatives: Vec<Vec<Condition<D>>>,
+    grant_reason: Cow<'static, str>,
+    deny_reason: Option<DenyReason<D>>,
+    fresh_auth: Option<FreshAuth<D>>,
     _domain: PhantomData<D>,
 }
 
@@ -35,21 +112,126 @@ impl<D: PolicyDomain> InternalPolicy<D> {
             match self.effect {
                 Effect::Allow | Effect::AllowOrForbid => PolicyEvalResult::granted(
                     self.name.clone(),
-                    Some("Policy allowed access".into()),
+                    Some(self.grant_reason.to_string()),
                 ),
                 Effect::Forbid => {
                     PolicyEvalResult::forbidden(self.name.clone(), "Policy forbids access")
                 }
             }
         } else {
-            PolicyEvalResult::not_applicable(self.name.clone(), "Policy predicate did not match")
+            PolicyEvalResult::not_applicable(self.name.clone(), NOT_MATCHED_REASON)
         }
     }
-}
 
-#[async_trait]
-impl<D: PolicyDomain> Policy<D> for InternalPolicy<D> {
-    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+    /// Returns the not-applicable result when the
+    /// [`PolicyBuilder::applies_when`] guard rejects the item.
+    fn abstain(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> Option<PolicyEvalResult> {
+        let applies = self.applies_pred.as_ref()?;
+        (!applies(subject, action, resource, context))
+            .then(|| PolicyEvalResult::not_applicable(self.name.clone(), NOT_APPLICABLE_REASON))
+    }
+
+    /// ANDs the freshness check into a granted result. Other results pass
+    /// through, so a stale session never blocks a forbid.
+    fn require_fresh_auth(
+        &self,
+        subject: &D::Subject,
+        context: &D::Context,
+        result: PolicyEvalResult,
+    ) -> PolicyEvalResult {
+        let Some(fresh_auth) = &self.fresh_auth else {
+            return result;
+        };
+        if !result.is_granted() {
+            return result;
+        }
+        let freshness = fresh_auth.evaluate(subject, context);
+        PolicyEvalResult::Combined {
+            policy_type: Cow::Owned(self.name.clone()),
+            policy_id: None,
+            operation: CombineOp::And,
+            outcome: freshness.is_granted(),
+            children: vec![result, freshness],
+        }
+    }
+
+    /// Builds the not-applicable result for one item, using the
+    /// [`PolicyBuilder::deny_reason`] callback when one is set.
+    fn not_matched(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        default_reason: impl FnOnce() -> String,
+    ) -> PolicyEvalResult {
+        let reason = match &self.deny_reason {
+            Some(deny_reason) => deny_reason(subject, action, resource, context),
+            None => default_reason(),
+        };
+        PolicyEvalResult::not_applicable(self.name.clone(), reason)
+    }
+
+    /// Evaluates the labeled conditions for one item whose other predicates
+    /// already matched.
+    fn conditions_result(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> PolicyEvalResult {
+        if self.alternatives.is_empty() {
+            return self.build_result(true);
+        }
+        let mut unmet = Vec::with_capacity(self.alternatives.len());
+        for conditions in &self.alternatives {
+            match conditions
+                .iter()
+                .find(|condition| !(condition.pred)(subject, action, resource, context))
+            {
+                Some(condition) => unmet.push(condition.label.as_ref()),
+                None => {
+                    let met = conditions
+                        .iter()
+                        .map(|condition| condition.label.as_ref())
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    return match self.effect {
+                        Effect::Allow | Effect::AllowOrForbid => PolicyEvalResult::granted(
+                            self.name.clone(),
+                            Some(format!("{}; conditions met: {met}", self.grant_reason)),
+                        ),
+                        Effect::Forbid => PolicyEvalResult::forbidden(
+                            self.name.clone(),
+                            format!("Policy forbids access; conditions met: {met}"),
+                        ),
+                    };
+                }
+            }
+        }
+        self.not_matched(subject, action, resource, context, || {
+            match unmet.as_slice() {
+                [label] => format!("Condition not met: {label}"),
+                labels => format!(
+                    "No alternative matched; conditions not met: {}",
+                    labels.join(", ")
+                ),
+            }
+        })
+    }
+
+    /// Decides one request, before the result is tagged with the name.
+    fn decide(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        if let Some(result) = self.abstain(ctx.subject, ctx.action, ctx.resource, ctx.context) {
+            return result;
+        }
         let pass = self.subject_pred.as_ref().is_none_or(|f| f(ctx.subject))
             && self.action_pred.as_ref().is_none_or(|f| f(ctx.action))
             && self.resource_pred.as_ref().is_none_or(|f| f(ctx.resource))
@@ -58,38 +240,86 @@ impl<D: PolicyDomain> Policy<D> for InternalPolicy<D> {
                 .when_pred
                 .as_ref()
                 .is_none_or(|f| f(ctx.subject, ctx.action, ctx.resource, ctx.context));
-        self.build_result(pass)
+        if !pass {
+            return self.not_matched(ctx.subject, ctx.action, ctx.resource, ctx.context, || {
+                NOT_MATCHED_REASON.to_string()
+            });
+        }
+        let result = self.conditions_result(ctx.subject, ctx.action, ctx.resource, ctx.context);
+        self.require_fresh_auth(ctx.subject, ctx.context, result)
     }
 
-    async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
+    /// Decides every item of a batch, before the results are tagged with the
+    /// name.
+    fn decide_batch(&self, ctx: &BatchEvalCtx<'_, D>) -> Vec<PolicyEvalResult> {
         let n = ctx.items.len();
 
         let subject_ok = self.subject_pred.as_ref().is_none_or(|f| f(ctx.subject));
         let action_ok = self.action_pred.as_ref().is_none_or(|f| f(ctx.action));
         let context_ok = self.context_pred.as_ref().is_none_or(|f| f(ctx.context));
 
-        if !subject_ok || !action_ok || !context_ok {
+        if (!subject_ok || !action_ok || !context_ok)
+            && self.deny_reason.is_none()
+            && self.applies_pred.is_none()
+        {
             let result = self.build_result(false);
             return std::iter::repeat_with(|| result.clone()).take(n).collect();
         }
 
-        if self.resource_pred.is_none() && self.when_pred.is_none() {
-            let result = self.build_result(true);
+        if subject_ok
+            && action_ok
+            && context_ok
+            && self.resource_pred.is_none()
+            && self.applies_pred.is_none()
+            && self.when_pred.is_none()
+            && self.alternatives.is_empty()
+        {
+            let result = self.require_fresh_auth(ctx.subject, ctx.context, self.build_result(true));
             return std::iter::repeat_with(|| result.clone()).take(n).collect();
         }
 
         ctx.items
             .iter()
             .map(|item| {
-                let resource_ok = self.resource_pred.as_ref().is_none_or(|f| f(item.resource));
-                let when_ok = self
-                    .when_pred
-                    .as_ref()
-                    .is_none_or(|f| f(ctx.subject, ctx.action, item.resource, ctx.context));
-                self.build_result(resource_ok && when_ok)
+                if let Some(result) =
+                    self.abstain(ctx.subject, ctx.action, item.resource, ctx.context)
+                {
+                    return result;
+                }
+                let pass = subject_ok
+                    && action_ok
+                    && context_ok
+                    && self.resource_pred.as_ref().is_none_or(|f| f(item.resource))
+                    && self
+                        .when_pred
+                        .as_ref()
+                        .is_none_or(|f| f(ctx.subject, ctx.action, item.resource, ctx.context));
+                if pass {
+                    let result =
+                        self.conditions_result(ctx.subject, ctx.action, item.resource, ctx.context);
+                    self.require_fresh_auth(ctx.subject, ctx.context, result)
+                } else {
+                    self.not_matched(ctx.subject, ctx.action, item.resource, ctx.context, || {
+                        NOT_MATCHED_REASON.to_string()
+                    })
+                }
             })
             .collect()
     }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for InternalPolicy<D> {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        self.decide(ctx).with_policy_id(self.name.clone())
+    }
+
+    async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
+        self.decide_batch(ctx)
+            .into_iter()
+            .map(|result| result.with_policy_id(self.name.clone()))
+            .collect()
+    }
 
     fn policy_type(&self) -> std::borrow::Cow<'static, str> {
         std::borrow::Cow::Owned(self.name.clone())
@@ -129,12 +359,20 @@ pub struct PolicyBuilder<D: PolicyDomain> {
     action_pred: Option<ActionPredicate<D>>,
     resource_pred: Option<ResourcePredicate<D>>,
     context_pred: Option<ContextPredicate<D>>,
+    applies_pred: Option<WhenPredicate<D>>,
     when_pred: Option<WhenPredicate<D>>,
+    alternatives: Vec<Vec<Condition<D>>>,
+    grant_reason: Cow<'static, str>,
+    deny_reason: Option<DenyReason<D>>,
+    fresh_auth: Option<FreshAuth<D>>,
     _domain: PhantomData<D>,
 }
 
 impl<D: PolicyDomain> PolicyBuilder<D> {
     /// Creates a new policy builder with the given policy name.
+    ///
+    /// The name is both the built policy's [`Policy::policy_type`] and the
+    /// [`PolicyEvalResult::policy_id`] of its results.
     pub fn new(name: impl Into<String>) -> Self {
         Self {
             name: name.into(),
@@ -143,7 +381,12 @@ impl<D: PolicyDomain> PolicyBuilder<D> {
             action_pred: None,
             resource_pred: None,
             context_pred: None,
+            applies_pred: None,
             when_pred: None,
+            alternatives: Vec::new(),
+            grant_reason: Cow::Borrowed(DEFAULT_GRANT_REASON),
+            deny_reason: None,
+            fresh_auth: None,
             _domain: PhantomData,
         }
     }
@@ -190,6 +433,47 @@ impl<D: PolicyDomain> PolicyBuilder<D> {
         self
     }
 
+    /// Adds a guard that decides whether the policy has anything to say about
+    /// a request at all.
+    ///
+    /// When the guard is false the policy returns
+    /// [`PolicyEvalResult::NotApplicable`] with a fixed "does not apply"
+    /// reason before any other predicate runs, and [`Self::deny_reason`] is
+    /// not called. Use it for scoping, such as the resource kind, so the
+    /// other predicates only describe the rule itself. Checkers and
+    /// combinators already treat `NotApplicable` as "no opinion": it never
+    /// overrides a sibling's grant. Calling it again replaces the guard.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// # struct User { id: u64 }
+    /// # enum Action { Pay }
+    /// # enum Resource { Invoice { owner_id: u64 }, Report }
+    /// # struct Ctx;
+    /// # struct Billing;
+    /// # impl PolicyDomain for Billing {
+    /// #     type Subject = User;
+    /// #     type Action = Action;
+    /// #     type Resource = Resource;
+    /// #     type Context = Ctx;
+    /// # }
+    /// let invoice_owner = PolicyBuilder::<Billing>::new("InvoiceOwner")
+    ///     .applies_when(|_user, _action, resource, _ctx| {
+    ///         matches!(resource, Resource::Invoice { .. })
+    ///     })
+    ///     .when(|user, _action, resource, _ctx| {
+    ///         matches!(resource, Resource::Invoice { owner_id } if *owner_id == user.id)
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn applies_when<F>(mut self, guard: F) -> Self
+    where
+        F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> bool + Send + Sync + 'static,
+    {
+        self.applies_pred = Some(Box::new(guard));
+        self
+    }
+
     /// Adds a predicate that compares multiple input axes.
     ///
     /// Prefer [`Self::subjects`], [`Self::actions`], [`Self::resources`], or
@@ -201,6 +485,195 @@ impl<D: PolicyDomain> PolicyBuilder<D> {
         F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> bool + Send + Sync + 'static,
     {
         self.when_pred = Some(Box::new(pred));
+        self.grant_reason = Cow::Borrowed(DEFAULT_GRANT_REASON);
+        self
+    }
+
+    /// Adds a labeled condition that must hold alongside the other conditions
+    /// of the current alternative.
+    ///
+    /// Conditions from [`Self::and_when`] and [`Self::or_when`] form
+    /// alternatives: each `or_when` starts a new alternative, and the policy
+    /// matches when every condition of any one alternative holds, so
+    /// `.and_when(a).and_when(b).or_when(c)` means `(a && b) || c`. These
+    /// conditions are checked after, and in addition to, the other
+    /// predicates. A denial names the first unmet condition of each
+    /// alternative, and a grant names the conditions that held.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// # struct User { id: u64, is_admin: bool }
+    /// # enum Action { Read }
+    /// # struct Doc { owner_id: u64, locked: bool }
+    /// # struct Ctx;
+    /// # struct Documents;
+    /// # impl PolicyDomain for Documents {
+    /// #     type Subject = User;
+    /// #     type Action = Action;
+    /// #     type Resource = Doc;
+    /// #     type Context = Ctx;
+    /// # }
+    /// let editor = PolicyBuilder::<Documents>::new("Editor")
+    ///     .and_when("IsOwner", |user, _action, doc, _ctx| user.id == doc.owner_id)
+    ///     .and_when("NotLocked", |_user, _action, doc, _ctx| !doc.locked)
+    ///     .or_when("IsAdmin", |user, _action, _doc, _ctx| user.is_admin)
+    ///     .build();
+    /// ```
+    pub fn and_when<F>(mut self, label: impl Into<Cow<'static, str>>, pred: F) -> Self
+    where
+        F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> bool + Send + Sync + 'static,
+    {
+        let condition = Condition {
+            label: label.into(),
+            pred: Box::new(pred),
+        };
+        match self.alternatives.last_mut() {
+            Some(conditions) => conditions.push(condition),
+            None => self.alternatives.push(vec![condition]),
+        }
+        self
+    }
+
+    /// Starts a new alternative with one labeled condition.
+    ///
+    /// See [`Self::and_when`] for how alternatives combine.
+    pub fn or_when<F>(mut self, label: impl Into<Cow<'static, str>>, pred: F) -> Self
+    where
+        F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> bool + Send + Sync + 'static,
+    {
+        self.alternatives.push(vec![Condition {
+            label: label.into(),
+            pred: Box::new(pred),
+        }]);
+        self
+    }
+
+    /// Sets a callback that writes the reason when the policy does not match.
+    ///
+    /// The callback runs only when the policy does not match, receives the
+    /// same inputs as [`Self::when`], and its message replaces the generic
+    /// not-applicable reason, including the unmet-condition reason from
+    /// [`Self::and_when`]. Without a callback the generic reason stays.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// # struct User;
+    /// # enum Action { Edit }
+    /// # struct Invoice { locked_since: Option<String> }
+    /// # struct Ctx;
+    /// # struct Invoices;
+    /// # impl PolicyDomain for Invoices {
+    /// #     type Subject = User;
+    /// #     type Action = Action;
+    /// #     type Resource = Invoice;
+    /// #     type Context = Ctx;
+    /// # }
+    /// let unlocked = PolicyBuilder::<Invoices>::new("InvoiceNotLocked")
+    ///     .resources(|invoice| invoice.locked_since.is_none())
+    ///     .deny_reason(|_user, _action, invoice, _ctx| {
+    ///         let since = invoice.locked_since.as_deref().unwrap_or("an unknown date");
+    ///         format!("invoice locked since {since}")
+    ///     })
+    ///     .build();
+    /// ```
+    pub fn deny_reason<F>(mut self, reason: F) -> Self
+    where
+        F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> String + Send + Sync + 'static,
+    {
+        self.deny_reason = Some(Box::new(reason));
+        self
+    }
+
+    /// Also requires the subject to have authenticated within `max_age`.
+    ///
+    /// `authed_at` returns when the subject last authenticated, usually from
+    /// the session claims in the context; `None` counts as stale. When the
+    /// policy would otherwise grant, the check runs as its own `FreshAuth`
+    /// node, and the policy reports an `AND` of its predicate result and that
+    /// node, so a stale session shows up in the trace as the reason for the
+    /// denial. Forbids are not affected.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// # use std::time::{Duration, SystemTime};
+    /// # struct User { is_admin: bool }
+    /// # enum Action { Delete }
+    /// # struct Doc;
+    /// # struct Ctx { authed_at: Option<SystemTime> }
+    /// # struct Documents;
+    /// # impl PolicyDomain for Documents {
+    /// #     type Subject = User;
+    /// #     type Action = Action;
+    /// #     type Resource = Doc;
+    /// #     type Context = Ctx;
+    /// # }
+    /// let admin_delete = PolicyBuilder::<Documents>::new("AdminDelete")
+    ///     .subjects(|user| user.is_admin)
+    ///     .require_fresh_auth(Duration::from_secs(15 * 60), |_user, ctx| ctx.authed_at)
+    ///     .build();
+    /// ```
+    pub fn require_fresh_auth<F>(mut self, max_age: Duration, authed_at: F) -> Self
+    where
+        F: Fn(&D::Subject, &D::Context) -> Option<SystemTime> + Send + Sync + 'static,
+    {
+        self.fresh_auth = Some(FreshAuth {
+            max_age,
+            authed_at: Box::new(authed_at),
+        });
+        self
+    }
+
+    /// Like [`Self::require_fresh_auth`], reading the authentication time
+    /// from a context that implements [`ProvidesAuthTime`].
+    pub fn require_fresh_auth_from_context(self, max_age: Duration) -> Self
+    where
+        D::Context: ProvidesAuthTime,
+    {
+        self.require_fresh_auth(max_age, |_subject, context: &D::Context| {
+            context.authed_at()
+        })
+    }
+
+    /// Matches when a subject field equals a resource field, the common
+    /// ownership and same-tenant shape.
+    ///
+    /// The labels name the compared fields in the grant reason. Like
+    /// [`Self::when`], this sets the builder's multi-axis predicate,
+    /// replacing any earlier one.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// # struct User { id: u64 }
+    /// # enum Action { Read }
+    /// # struct Doc { owner_id: u64 }
+    /// # struct Ctx;
+    /// # struct Documents;
+    /// # impl PolicyDomain for Documents {
+    /// #     type Subject = User;
+    /// #     type Action = Action;
+    /// #     type Resource = Doc;
+    /// #     type Context = Ctx;
+    /// # }
+    /// let owner = PolicyBuilder::<Documents>::new("Owner")
+    ///     .field_eq("user.id", |user| user.id, "doc.owner_id", |doc| doc.owner_id)
+    ///     .build();
+    /// ```
+    pub fn field_eq<T, FS, FR>(
+        mut self,
+        subject_label: &'static str,
+        subject_field: FS,
+        resource_label: &'static str,
+        resource_field: FR,
+    ) -> Self
+    where
+        T: PartialEq,
+        FS: Fn(&D::Subject) -> T + Send + Sync + 'static,
+        FR: Fn(&D::Resource) -> T + Send + Sync + 'static,
+    {
+        self.when_pred = Some(Box::new(move |subject, _action, resource, _context| {
+            subject_field(subject) == resource_field(resource)
+        }));
+        self.grant_reason = Cow::Owned(format!("{subject_label} equals {resource_label}"));
         self
     }
 
@@ -213,7 +686,12 @@ impl<D: PolicyDomain> PolicyBuilder<D> {
             action_pred: self.action_pred,
             resource_pred: self.resource_pred,
             context_pred: self.context_pred,
+            applies_pred: self.applies_pred,
             when_pred: self.when_pred,
+            alternatives: self.alternatives,
+            grant_reason: self.grant_reason,
+            deny_reason: self.deny_reason,
+            fresh_auth: self.fresh_auth,
             _domain: PhantomData,
         })
     }
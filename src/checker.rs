@@ -1,42 +1,234 @@
+use crate::annotated::annotate_checker;
+use crate::memo::{EvaluationMemo, MemoCache};
+use crate::policies::delegated_evaluation_to_result;
+use crate::policy::evaluate_in_span;
 use crate::{
-    AccessEvaluation, BatchEvalCtx, CombineOp, Effect, EvalCtx, EvalTrace, EvaluationSession,
-    Hydrator, LookupAuthorizedError, LookupAuthorizedPage, LookupSource, Policy, PolicyBatchItem,
-    PolicyDomain, PolicyEvalResult, DEFAULT_SECURITY_RULE_CATEGORY, PERMISSION_CHECKER_POLICY_TYPE,
+    AccessDenied, AccessEvaluation, AnnotatedTree, BatchEvalCtx, CombineOp, Decision,
+    DeclaredFacts, Effect, EvalCtx, EvalStats, EvalTrace, EvaluationSession, FieldDecision,
+    FieldPolicy, Hydrator, LookupAuthorizedError, LookupAuthorizedPage, LookupSource, Policy,
+    PolicyBatchItem, PolicyDomain, PolicyEvalResult, PolicyObserver,
+    DEFAULT_SECURITY_RULE_CATEGORY, PERMISSION_CHECKER_POLICY_TYPE,
 };
+use async_trait::async_trait;
+use futures_util::future::join_all;
+use futures_util::stream::{self, StreamExt};
+use futures_util::FutureExt;
 use std::borrow::{Borrow, Cow};
+use std::future::Future;
+use std::hash::Hash;
 use std::num::NonZeroUsize;
+use std::panic::AssertUnwindSafe;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 use tracing::Instrument;
 
-fn forbid_summary(policy_type: &str, reason: Option<&str>) -> String {
+pub(crate) fn forbid_summary(policy_type: &str, reason: Option<&str>) -> String {
     match reason {
         Some(reason) => format!("Forbidden by {policy_type}: {reason}"),
         None => format!("Forbidden by {policy_type}"),
     }
 }
 
+/// Awaits `future`, or returns `None` if the ambient deadline set with
+/// [`crate::with_deadline`] passes first.
+async fn before_deadline<F: Future>(future: F) -> Option<F::Output> {
+    #[cfg(feature = "tokio")]
+    if let Some(deadline) = crate::deadline::current() {
+        return tokio::time::timeout_at(deadline, future).await.ok();
+    }
+    Some(future.await)
+}
+
+/// Whether the ambient deadline has passed. Checked between policies, since
+/// a policy that never yields finishes before a timer can interrupt it.
+fn deadline_passed() -> bool {
+    #[cfg(feature = "tokio")]
+    return crate::deadline::current()
+        .is_some_and(|deadline| tokio::time::Instant::now() >= deadline);
+    #[cfg(not(feature = "tokio"))]
+    false
+}
+
 const FORBID_EFFECT_GRANT_REASON: &str =
     "Forbid-effect policy returned a grant; treated as not applicable";
 
+#[cfg(feature = "tokio")]
+const BATCH_DEADLINE_EXCEEDED_REASON: &str = "batch deadline exceeded";
+
+const POLICY_PANICKED_REASON: &str = "Policy panicked during evaluation";
+
+const MULTIPLE_APPLICABLE_REASON: &str = "multiple applicable policies";
+
+const NO_MATCH_ALLOW_REASON: &str = "No policy matched; allowed by default";
+
+const NO_MATCH_DENY_REASON: &str = "No policy matched; denied by default";
+
 const ALLOW_EFFECT_FORBID_REASON: &str =
     "Allow-effect policy returned a forbid; the veto is honored but only where observed, so declare Effect::Forbid or Effect::AllowOrForbid to schedule it ahead of grants";
 
-fn checker_root(children: Vec<PolicyEvalResult>, outcome: bool) -> PolicyEvalResult {
-    PolicyEvalResult::Combined {
-        policy_type: std::borrow::Cow::Borrowed(PERMISSION_CHECKER_POLICY_TYPE),
-        operation: CombineOp::DenyOverrides,
-        children,
-        outcome,
+pub(crate) fn not_granted_summary(policy_type: &str, reason: Option<&str>) -> String {
+    match reason {
+        Some(reason) => format!("Not granted by {policy_type}: {reason}"),
+        None => format!("Not granted by {policy_type}"),
+    }
+}
+
+/// How a [`PermissionChecker`] combines the decisions of its policies.
+///
+/// Every strategy except [`Self::FirstMatch`] honors forbids: veto-capable
+/// policies run first and a forbid denies regardless of grants. The
+/// strategies differ in which grants are required and in the root operation
+/// recorded in the trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum CombineStrategy {
+    /// A forbid denies; otherwise the first grant allows. The default, and
+    /// the checker's behavior before strategies existed. The trace root is
+    /// [`CombineOp::DenyOverrides`].
+    #[default]
+    DenyOverrides,
+    /// A deprecated alias of [`Self::DenyOverrides`]. It decides exactly
+    /// like it, forbids included, and differs only in recording the trace
+    /// root as [`CombineOp::Or`].
+    #[deprecated(note = "decides exactly like `DenyOverrides`; use that instead")]
+    AnyAllow,
+    /// Every policy that can grant must grant, and the first one that does
+    /// not denies. Forbid-only policies are still evaluated as vetoes but do
+    /// not need to grant. The trace root is [`CombineOp::And`].
+    AllAllow,
+    /// Exactly one policy may apply, XACML's only-one-applicable. Every
+    /// policy is evaluated; a policy that grants, forbids, or is
+    /// indeterminate counts as applicable. With none applicable the request
+    /// is denied as unhandled, with exactly one its decision stands, and with
+    /// more than one the request is indeterminate with the reason
+    /// `"multiple applicable policies"`. The trace root is
+    /// [`CombineOp::OnlyOneApplicable`].
+    OnlyOneApplicable,
+    /// Firewall-style rules: policies run in the order they were added, and
+    /// the first to grant or forbid decides. A policy abstains by returning
+    /// [`PolicyEvalResult::NotApplicable`]. When every policy abstains,
+    /// `default` decides: [`Effect::Allow`] grants, and any other effect
+    /// denies.
+    ///
+    /// Unlike the other strategies, a forbid added after a granting policy
+    /// is never reached, so rule order is part of the policy. An
+    /// indeterminate result stops evaluation and denies, since the skipped
+    /// rule might have forbidden. A checker with no policies still denies,
+    /// and [`PermissionChecker::with_default_allow`] does not apply. The
+    /// trace root is [`CombineOp::FirstApplicable`].
+    FirstMatch {
+        /// The decision when no policy grants or forbids.
+        default: Effect,
+    },
+}
+
+impl CombineStrategy {
+    /// The operation recorded on the checker's root trace node.
+    pub fn operation(self) -> CombineOp {
+        match self {
+            Self::DenyOverrides => CombineOp::DenyOverrides,
+            #[allow(deprecated)]
+            Self::AnyAllow => CombineOp::Or,
+            Self::AllAllow => CombineOp::And,
+            Self::OnlyOneApplicable => CombineOp::OnlyOneApplicable,
+            Self::FirstMatch { .. } => CombineOp::FirstApplicable,
+        }
     }
 }
 
+/// The broad class of an action, used to pick a default decision.
+///
+/// A checker derives the class from each action through the classifier set
+/// with [`PermissionChecker::with_action_classifier`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum ActionClass {
+    /// Reads that do not change state.
+    Read,
+    /// Writes that change state.
+    Write,
+    /// Administrative actions, such as changing permissions.
+    Admin,
+}
+
+impl std::fmt::Display for ActionClass {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Read => "read",
+            Self::Write => "write",
+            Self::Admin => "admin",
+        })
+    }
+}
+
+/// Which policies engaged with one request, from
+/// [`PermissionChecker::coverage`].
+///
+/// Each list holds policy types in evaluation order.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Coverage {
+    /// Policies that granted or forbade.
+    pub decided: Vec<String>,
+    /// Policies that returned [`PolicyEvalResult::NotApplicable`].
+    pub abstained: Vec<String>,
+    /// Policies that returned [`PolicyEvalResult::Indeterminate`].
+    pub indeterminate: Vec<String>,
+}
+
+impl Coverage {
+    /// Whether no policy granted or forbade the request.
+    pub fn is_gap(&self) -> bool {
+        self.decided.is_empty()
+    }
+}
+
+/// A decision together with every policy's result, from
+/// [`PermissionChecker::explain`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Explanation {
+    /// The decision, identical to the one the short-circuiting path returns.
+    pub decision: AccessEvaluation,
+    /// The policy that granted, or the policy that forbade a denial. `None`
+    /// when nothing decided the request.
+    pub winning_policy: Option<String>,
+    /// Every policy's result in evaluation order, including policies the
+    /// short-circuiting path would have skipped.
+    pub results: Vec<PolicyEvalResult>,
+}
+
+type ActionClassifier<D> = Arc<dyn Fn(&<D as PolicyDomain>::Action) -> ActionClass + Send + Sync>;
+
+/// How [`PermissionChecker::evaluate_one`] schedules policy evaluations.
+#[derive(Clone, Copy)]
+enum EvaluationMode {
+    /// One policy at a time, short-circuiting on the decision.
+    Sequential,
+    /// Every policy at once, then combined in registration order.
+    Concurrent,
+}
+
 /// A policy stack for one [`PolicyDomain`].
 pub struct PermissionChecker<D: PolicyDomain> {
     name: Option<std::borrow::Cow<'static, str>>,
     policies: Vec<Arc<dyn Policy<D>>>,
     effects: Vec<Effect>,
+    /// When each policy was added, counting from zero; scheduling moves
+    /// veto-capable policies ahead, but [`CombineStrategy::FirstMatch`]
+    /// runs them in this order.
+    added: Vec<usize>,
     veto_capable_count: usize,
     max_batch_size: Option<NonZeroUsize>,
+    field_policies: Vec<Arc<dyn FieldPolicy<D>>>,
+    strategy: CombineStrategy,
+    action_classifier: Option<ActionClassifier<D>>,
+    default_allow: Vec<ActionClass>,
+    max_trace_children: Option<usize>,
+    observer: Option<Arc<dyn PolicyObserver>>,
+    memo: Option<Arc<dyn EvaluationMemo<D>>>,
 }
 
 impl<D: PolicyDomain> Clone for PermissionChecker<D> {
@@ -45,12 +237,43 @@ impl<D: PolicyDomain> Clone for PermissionChecker<D> {
             name: self.name.clone(),
             policies: self.policies.clone(),
             effects: self.effects.clone(),
+            added: self.added.clone(),
             veto_capable_count: self.veto_capable_count,
             max_batch_size: self.max_batch_size,
+            field_policies: self.field_policies.clone(),
+            strategy: self.strategy,
+            action_classifier: self.action_classifier.clone(),
+            default_allow: self.default_allow.clone(),
+            max_trace_children: self.max_trace_children,
+            observer: self.observer.clone(),
+            memo: self.memo.clone(),
         }
     }
 }
 
+/// Shows the checker's configuration: name, strategy, and policy types in
+/// evaluation order.
+impl<D: PolicyDomain> std::fmt::Debug for PermissionChecker<D> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("PermissionChecker")
+            .field("name", &self.name)
+            .field("strategy", &self.strategy)
+            .field(
+                "policies",
+                &self
+                    .policies
+                    .iter()
+                    .map(|policy| policy.policy_type())
+                    .collect::<Vec<_>>(),
+            )
+            .field("max_batch_size", &self.max_batch_size)
+            .field("field_policy_count", &self.field_policies.len())
+            .field("default_allow", &self.default_allow)
+            .field("max_trace_children", &self.max_trace_children)
+            .finish()
+    }
+}
+
 impl<D: PolicyDomain> Default for PermissionChecker<D> {
     fn default() -> Self {
         Self::new()
@@ -64,8 +287,36 @@ impl<D: PolicyDomain> PermissionChecker<D> {
             name: None,
             policies: Vec::new(),
             effects: Vec::new(),
+            added: Vec::new(),
             veto_capable_count: 0,
             max_batch_size: None,
+            field_policies: Vec::new(),
+            strategy: CombineStrategy::default(),
+            action_classifier: None,
+            default_allow: Vec::new(),
+            max_trace_children: None,
+            observer: None,
+            memo: None,
+        }
+    }
+
+    /// Creates a new checker that starts with the policies registered for
+    /// this domain in [`crate::global_policies`].
+    ///
+    /// See that module for how the global policies are ordered and for the
+    /// security implications of relying on a process-wide registry.
+    pub fn new_with_globals() -> Self {
+        let mut checker = Self::new();
+        for policy in crate::global_policies::policies::<D>() {
+            checker.add_policy(policy);
+        }
+        checker
+    }
+
+    /// Starts a [`PermissionCheckerBuilder`] for a checker with no policies.
+    pub fn builder() -> PermissionCheckerBuilder<D> {
+        PermissionCheckerBuilder {
+            checker: Self::new(),
         }
     }
 
@@ -75,11 +326,38 @@ impl<D: PolicyDomain> PermissionChecker<D> {
             name: Some(name.into()),
             policies: Vec::new(),
             effects: Vec::new(),
+            added: Vec::new(),
             veto_capable_count: 0,
             max_batch_size: None,
+            field_policies: Vec::new(),
+            strategy: CombineStrategy::default(),
+            action_classifier: None,
+            default_allow: Vec::new(),
+            max_trace_children: None,
+            observer: None,
+            memo: None,
         }
     }
 
+    /// Creates a new checker with no policies that combines policy decisions
+    /// with `strategy`.
+    pub fn with_strategy(strategy: CombineStrategy) -> Self {
+        let mut checker = Self::new();
+        checker.strategy = strategy;
+        checker
+    }
+
+    /// Replaces the strategy used to combine policy decisions.
+    pub fn set_strategy(&mut self, strategy: CombineStrategy) {
+        self.strategy = strategy;
+        self.reset_memo();
+    }
+
+    /// Returns the strategy used to combine policy decisions.
+    pub fn strategy(&self) -> CombineStrategy {
+        self.strategy
+    }
+
     /// Returns the checker name if set.
     pub fn name(&self) -> Option<&str> {
         self.name.as_deref()
@@ -91,33 +369,258 @@ impl<D: PolicyDomain> PermissionChecker<D> {
         self
     }
 
+    /// Caps how many non-deciding policy results the root trace node keeps.
+    ///
+    /// Past the first `max_children` results, not-applicable children are
+    /// replaced by one summary node reading `"... and K more denied"`.
+    /// Grants, forbids, and indeterminate results are always kept, so the
+    /// trace still names the deciding policy and
+    /// [`AccessEvaluation::is_indeterminate`] still sees a policy that could
+    /// not decide. Decisions are unaffected.
+    pub fn with_max_trace_children(mut self, max_children: usize) -> Self {
+        self.max_trace_children = Some(max_children);
+        self.reset_memo();
+        self
+    }
+
+    /// Installs an observer that receives every policy's result and
+    /// evaluation time, on the sequential, parallel, and batch paths.
+    pub fn with_observer(mut self, observer: Arc<dyn PolicyObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Sets the classifier that maps each action to its [`ActionClass`].
+    ///
+    /// The class only matters for classes opened with
+    /// [`Self::with_default_allow`].
+    pub fn with_action_classifier<F>(mut self, classifier: F) -> Self
+    where
+        F: Fn(&D::Action) -> ActionClass + Send + Sync + 'static,
+    {
+        self.action_classifier = Some(Arc::new(classifier));
+        self.reset_memo();
+        self
+    }
+
+    /// Grants actions of `class` when no policy grants or forbids them.
+    ///
+    /// Every class is denied by default. A forbid still denies, and so does
+    /// an indeterminate result, so an unavailable backend never falls back to
+    /// the open default. A checker with no policies still denies. Has no
+    /// effect without [`Self::with_action_classifier`].
+    ///
+    /// ```rust,ignore
+    /// let checker = checker
+    ///     .with_action_classifier(|action: &Action| match action {
+    ///         Action::View => ActionClass::Read,
+    ///         Action::Edit => ActionClass::Write,
+    ///     })
+    ///     .with_default_allow(ActionClass::Read);
+    /// ```
+    pub fn with_default_allow(mut self, class: ActionClass) -> Self {
+        if !self.default_allow.contains(&class) {
+            self.default_allow.push(class);
+        }
+        self.reset_memo();
+        self
+    }
+
     /// Adds a policy to the checker.
     ///
     /// Veto-capable policies are scheduled ahead of allow-only policies so a
     /// forbid is always observed before the grant short-circuit.
     pub fn add_policy<P: Policy<D> + 'static>(&mut self, policy: P) {
+        self.reset_memo();
         let effect = policy.effect();
+        let added = self.policies.len();
         if effect.can_forbid() {
             self.policies
                 .insert(self.veto_capable_count, Arc::new(policy));
             self.effects.insert(self.veto_capable_count, effect);
+            self.added.insert(self.veto_capable_count, added);
             self.veto_capable_count += 1;
         } else {
             self.policies.push(Arc::new(policy));
             self.effects.push(effect);
+            self.added.push(added);
         }
     }
 
     /// Adds a hand-written policy that can actively forbid access even if it
     /// does not override [`Policy::effect`].
     pub fn add_forbid_policy<P: Policy<D> + 'static>(&mut self, policy: P) {
+        self.reset_memo();
         self.policies
             .insert(self.veto_capable_count, Arc::new(policy));
         self.effects
             .insert(self.veto_capable_count, Effect::AllowOrForbid);
+        self.added
+            .insert(self.veto_capable_count, self.policies.len() - 1);
         self.veto_capable_count += 1;
     }
 
+    /// Reorders the policies by [`Policy::policy_type`], so traces do not
+    /// depend on the order policies were added in.
+    ///
+    /// Useful when policies are added from an unordered source such as a
+    /// `HashMap` and traces are compared across runs, as in golden-file
+    /// tests. The sort is stable, so policies sharing a type keep their
+    /// relative order, and veto-capable policies stay ahead of allow-only
+    /// ones. Policies added afterwards are not sorted.
+    ///
+    /// This changes which policy a short-circuit stops at: the first
+    /// granting policy by name, rather than by insertion, becomes the one
+    /// reported as the granter, and later policies go unevaluated. The
+    /// decision itself is unchanged, but anything read from the deciding
+    /// grant, such as its reason or obligations, may now come from a
+    /// different policy. Under [`CombineStrategy::FirstMatch`], where order
+    /// decides, policies keep running in the order they were added.
+    pub fn sort_policies_by_type(&mut self) {
+        self.reset_memo();
+        let veto_capable_count = self.veto_capable_count;
+        let mut entries = self
+            .policies
+            .drain(..)
+            .zip(self.effects.drain(..))
+            .zip(self.added.drain(..))
+            .map(|((policy, effect), added)| (policy.policy_type(), policy, effect, added))
+            .collect::<Vec<_>>();
+        entries[..veto_capable_count].sort_by(|a, b| a.0.cmp(&b.0));
+        entries[veto_capable_count..].sort_by(|a, b| a.0.cmp(&b.0));
+        for (_, policy, effect, added) in entries {
+            self.policies.push(policy);
+            self.effects.push(effect);
+            self.added.push(added);
+        }
+    }
+
+    /// Drops every evaluation memoized by [`Self::with_memoization`], for
+    /// example after a permission change that must take effect before the
+    /// TTL runs out. Clones of the checker share the memo, so they are
+    /// cleared too.
+    pub fn clear_memoized(&self) {
+        if let Some(memo) = &self.memo {
+            memo.clear();
+        }
+    }
+
+    /// Gives the checker an empty memo after a change that affects its
+    /// evaluations. Clones keep the old memo, which still matches their
+    /// policies.
+    fn reset_memo(&mut self) {
+        if let Some(memo) = &self.memo {
+            self.memo = Some(memo.emptied());
+        }
+    }
+
+    /// Returns the number of policies in the checker.
+    pub fn len(&self) -> usize {
+        self.policies.len()
+    }
+
+    /// Returns whether the checker has no policies, in which case every
+    /// request is denied.
+    pub fn is_empty(&self) -> bool {
+        self.policies.is_empty()
+    }
+
+    /// Returns each policy's [`Policy::policy_type`], in evaluation order.
+    ///
+    /// Veto-capable policies come first, as they are scheduled; under
+    /// [`CombineStrategy::FirstMatch`] the order is the order policies were
+    /// added in.
+    pub fn policy_types(&self) -> Vec<String> {
+        self.evaluation_order()
+            .into_iter()
+            .map(|index| self.policies[index].policy_type().into_owned())
+            .collect()
+    }
+
+    /// Removes every policy whose [`Policy::policy_type`] is `policy_type`,
+    /// returning whether any was removed.
+    ///
+    /// The policy type is the only identifier a checker keeps, so give
+    /// policies that must be removed individually distinct types, such as
+    /// distinct [`crate::PolicyBuilder`] names. The remaining policies keep
+    /// their order. Clones of the checker made earlier are not affected.
+    pub fn remove_policy_by_type(&mut self, policy_type: &str) -> bool {
+        let before = self.policies.len();
+        let mut index = 0;
+        while index < self.policies.len() {
+            if self.policies[index].policy_type() != policy_type {
+                index += 1;
+                continue;
+            }
+            self.policies.remove(index);
+            self.effects.remove(index);
+            self.added.remove(index);
+            if index < self.veto_capable_count {
+                self.veto_capable_count -= 1;
+            }
+        }
+        if self.policies.len() == before {
+            return false;
+        }
+        self.reset_memo();
+        // Renumber so policies added later continue the sequence.
+        let mut by_age = (0..self.added.len()).collect::<Vec<_>>();
+        by_age.sort_by_key(|&index| self.added[index]);
+        for (rank, index) in by_age.into_iter().enumerate() {
+            self.added[index] = rank;
+        }
+        true
+    }
+
+    /// Mounts `sub_checker` as one policy that only engages for resources
+    /// matching `routes`.
+    ///
+    /// Lets an application split its rules by feature area (billing, posts,
+    /// admin) into separate checkers and compose them into one root. For a
+    /// resource `routes` rejects, the mount is
+    /// [`PolicyEvalResult::NotApplicable`] and the sub-checker does not run.
+    /// Otherwise the sub-checker evaluates the request in the same session,
+    /// with its own strategy, and its trace is nested under a
+    /// [`CombineOp::Delegate`] node named after the sub-checker, as
+    /// with [`crate::DelegatingPolicy`]. The mount can forbid when the
+    /// sub-checker has a veto-capable policy, so a billing freeze inside the
+    /// sub-checker still vetoes the root.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// # struct User;
+    /// # enum Action { Pay }
+    /// # enum Resource { Payment, Post }
+    /// # struct Shop;
+    /// # impl PolicyDomain for Shop {
+    /// #     type Subject = User;
+    /// #     type Action = Action;
+    /// #     type Resource = Resource;
+    /// #     type Context = ();
+    /// # }
+    /// let mut billing = PermissionChecker::<Shop>::named("billing");
+    /// billing.add_policy(PolicyBuilder::<Shop>::new("AnyonePays").build());
+    ///
+    /// let mut root = PermissionChecker::<Shop>::new();
+    /// root.mount(|resource| matches!(resource, Resource::Payment), billing);
+    /// ```
+    pub fn mount<F>(&mut self, routes: F, sub_checker: PermissionChecker<D>)
+    where
+        F: Fn(&D::Resource) -> bool + Send + Sync + 'static,
+    {
+        self.add_policy(MountedChecker {
+            routes: Box::new(routes),
+            checker: sub_checker,
+        });
+    }
+
+    /// Adds a field policy consulted by [`BoundEvaluator::authorized_fields`].
+    ///
+    /// Field policies do not take part in resource-level decisions.
+    pub fn add_field_policy<P: FieldPolicy<D> + 'static>(&mut self, policy: P) {
+        self.field_policies.push(Arc::new(policy));
+    }
+
     /// Binds a request-scoped evaluation session and shared inputs to this
     /// checker.
     ///
@@ -140,6 +643,511 @@ impl<D: PolicyDomain> PermissionChecker<D> {
         }
     }
 
+    /// Evaluates one resource for each of `subjects`, answering "who can
+    /// access this resource" for a list of candidates.
+    ///
+    /// Returns one evaluation per subject, tagged with the subject's index
+    /// in `subjects` and in input order. Each evaluation is the one
+    /// [`BoundEvaluator::check`] returns for that subject. Facts that
+    /// policies declare through [`Policy::declared_facts`] are prefetched
+    /// for every subject up front, with one load per key type, so a policy
+    /// that declares its lookups pays for one round trip rather than one per
+    /// subject. Subjects are then evaluated concurrently, at most
+    /// `concurrency` at a time when it is set.
+    pub async fn evaluate_subjects(
+        &self,
+        session: &EvaluationSession,
+        subjects: &[D::Subject],
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        concurrency: Option<NonZeroUsize>,
+    ) -> Vec<(usize, AccessEvaluation)> {
+        let mut declared = DeclaredFacts::new();
+        for subject in subjects {
+            self.declare_facts(subject, action, resource, context, &mut declared);
+        }
+        Self::prefetch_declared_facts(session, declared).await;
+
+        let limit = concurrency.map_or(subjects.len(), NonZeroUsize::get).max(1);
+        stream::iter(subjects.iter().enumerate())
+            .map(|(index, subject)| async move {
+                let evaluation = self
+                    .evaluate_one(
+                        session,
+                        subject,
+                        action,
+                        resource,
+                        context,
+                        EvaluationMode::Sequential,
+                    )
+                    .await;
+                (index, evaluation)
+            })
+            .buffered(limit)
+            .collect()
+            .await
+    }
+
+    /// Evaluates one resource and returns the policy tree annotated with the
+    /// outcome of every node.
+    ///
+    /// The tree expands combinators through [`Policy::composition`]; nodes the
+    /// evaluation short-circuited past are marked
+    /// [`crate::NodeOutcome::NotEvaluated`]. The decision is the same one
+    /// [`BoundEvaluator::check`] returns.
+    pub async fn evaluate_annotated(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> AnnotatedTree {
+        let evaluation = self
+            .evaluate_one(
+                session,
+                subject,
+                action,
+                resource,
+                context,
+                EvaluationMode::Sequential,
+            )
+            .await;
+        let policies = self
+            .evaluation_order()
+            .into_iter()
+            .map(|index| Arc::clone(&self.policies[index]))
+            .collect::<Vec<_>>();
+        annotate_checker(&policies, self.strategy.operation(), evaluation)
+    }
+
+    /// Runs every policy against one request without short-circuiting and
+    /// sorts them by whether they decided.
+    ///
+    /// Use this to audit policy coverage: a request where
+    /// [`Coverage::is_gap`] holds was denied only because nothing engaged
+    /// with it. The result does not depend on the [`CombineStrategy`], and no
+    /// access decision is made.
+    pub async fn coverage(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> Coverage {
+        let mut declared = DeclaredFacts::new();
+        self.declare_facts(subject, action, resource, context, &mut declared);
+        Self::prefetch_declared_facts(session, declared).await;
+
+        let mut coverage = Coverage::default();
+        for policy in &self.policies {
+            let ctx = EvalCtx {
+                session,
+                subject,
+                action,
+                resource,
+                context,
+                policy_type: policy.policy_type(),
+            };
+            let result = policy.evaluate(&ctx).await;
+            let bucket = if result.is_granted() || result.is_forbidden() {
+                &mut coverage.decided
+            } else if result.is_indeterminate() {
+                &mut coverage.indeterminate
+            } else {
+                &mut coverage.abstained
+            };
+            bucket.push(ctx.policy_type.into_owned());
+        }
+        coverage
+    }
+
+    /// Runs every policy against one request without short-circuiting and
+    /// reports the decision alongside each policy's result.
+    ///
+    /// Use this for debugging endpoints that need to show which policies
+    /// *would* have granted. Each policy is evaluated once; the decision is
+    /// then reached by replaying the recorded results through this checker's
+    /// [`CombineStrategy`], so it matches [`BoundEvaluator::check`]. The
+    /// regular paths keep short-circuiting. Explaining has no side effects:
+    /// it neither reads nor fills the memo from [`Self::with_memoization`],
+    /// and the observer is not notified.
+    pub async fn explain(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> Explanation {
+        let mut declared = DeclaredFacts::new();
+        self.declare_facts(subject, action, resource, context, &mut declared);
+        Self::prefetch_declared_facts(session, declared).await;
+
+        let order = self.evaluation_order();
+        let mut results_by_policy = vec![None; self.policies.len()];
+        for &index in &order {
+            let policy = &self.policies[index];
+            let ctx = EvalCtx {
+                session,
+                subject,
+                action,
+                resource,
+                context,
+                policy_type: policy.policy_type(),
+            };
+            results_by_policy[index] = Some(policy.evaluate(&ctx).await);
+        }
+        let results_by_policy = results_by_policy
+            .into_iter()
+            .map(|result| result.expect("the evaluation order covers every policy"))
+            .collect::<Vec<_>>();
+
+        let replays = self
+            .policies
+            .iter()
+            .zip(&self.effects)
+            .zip(&results_by_policy)
+            .map(|((policy, &effect), result)| {
+                Arc::new(ReplayPolicy {
+                    policy_type: policy.policy_type(),
+                    effect,
+                    result: result.clone(),
+                }) as Arc<dyn Policy<D>>
+            })
+            .collect();
+        // The replay must neither read nor write the memo, and observers
+        // must not see its timings as evaluations.
+        let replay = Self {
+            policies: replays,
+            field_policies: Vec::new(),
+            observer: None,
+            memo: None,
+            ..self.clone()
+        };
+        let decision = replay
+            .evaluate_one(
+                session,
+                subject,
+                action,
+                resource,
+                context,
+                EvaluationMode::Sequential,
+            )
+            .await;
+        let winning_policy = match &decision {
+            AccessEvaluation::Granted { policy_type, .. } => Some(policy_type.to_string()),
+            AccessEvaluation::Denied { .. } => decision.forbidden_by().map(str::to_owned),
+        };
+        Explanation {
+            decision,
+            winning_policy,
+            results: order
+                .into_iter()
+                .map(|index| results_by_policy[index].clone())
+                .collect(),
+        }
+    }
+
+    /// Evaluates one request and returns only the [`Decision`].
+    ///
+    /// Equivalent to [`BoundEvaluator::check`] followed by
+    /// [`AccessEvaluation::decision`], for callers that do not need the
+    /// trace. The trace is still built during evaluation, so this is an
+    /// ergonomic shortcut rather than a faster path.
+    pub async fn decide(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> Decision {
+        self.evaluate_one(
+            session,
+            subject,
+            action,
+            resource,
+            context,
+            EvaluationMode::Sequential,
+        )
+        .await
+        .into()
+    }
+
+    /// Evaluates one request and returns whether it was granted.
+    ///
+    /// Equivalent to [`BoundEvaluator::check`] followed by
+    /// [`AccessEvaluation::is_granted`].
+    pub async fn is_allowed(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> bool {
+        self.evaluate_one(
+            session,
+            subject,
+            action,
+            resource,
+            context,
+            EvaluationMode::Sequential,
+        )
+        .await
+        .is_granted()
+    }
+
+    /// Evaluates one request, returning [`AccessDenied`] on denial.
+    ///
+    /// Lets handlers propagate a denial with `?`; the error displays the
+    /// denial reason and keeps the trace.
+    pub async fn authorize(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> Result<(), AccessDenied> {
+        self.evaluate_one(
+            session,
+            subject,
+            action,
+            resource,
+            context,
+            EvaluationMode::Sequential,
+        )
+        .await
+        .into()
+    }
+
+    /// Asserts that this checker denies when every policy is indeterminate.
+    ///
+    /// Each registered policy is replaced by a stand-in with the same name
+    /// and declared [`Effect`] that always returns
+    /// [`PolicyEvalResult::Indeterminate`]; the resulting checker keeps this
+    /// checker's [`CombineStrategy`] and batch settings. Both the single and
+    /// the batch path must deny. Use this in tests to catch a strategy or
+    /// configuration that fails open when every backend is down.
+    ///
+    /// # Panics
+    ///
+    /// Panics with the evaluation trace if either path grants.
+    pub async fn assert_fail_closed(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) {
+        let stand_ins = self
+            .policies
+            .iter()
+            .zip(&self.effects)
+            .map(|(policy, &effect)| {
+                Arc::new(IndeterminatePolicy {
+                    policy_type: policy.policy_type(),
+                    effect,
+                }) as Arc<dyn Policy<D>>
+            })
+            .collect();
+        let indeterminate = Self {
+            policies: stand_ins,
+            field_policies: Vec::new(),
+            memo: None,
+            ..self.clone()
+        };
+
+        let single = indeterminate
+            .evaluate_one(
+                session,
+                subject,
+                action,
+                resource,
+                context,
+                EvaluationMode::Sequential,
+            )
+            .await;
+        let batch = indeterminate
+            .evaluate_batch_by(
+                session,
+                subject,
+                action,
+                context,
+                std::slice::from_ref(resource),
+                |resource| *resource,
+            )
+            .await;
+        for (path, evaluation) in std::iter::once(("single", &single))
+            .chain(batch.iter().map(|(_, evaluation)| ("batch", evaluation)))
+        {
+            assert!(
+                !evaluation.is_granted(),
+                "expected {:?} checker to fail closed on the {path} path when every policy is indeterminate; got:\n{}",
+                self.strategy,
+                evaluation.display_trace()
+            );
+        }
+    }
+
+    fn root(&self, mut children: Vec<PolicyEvalResult>, outcome: bool) -> PolicyEvalResult {
+        if let Some(max_children) = self.max_trace_children {
+            if children.len() > max_children {
+                let mut kept = 0;
+                let mut dropped = 0;
+                children.retain(|child| {
+                    let keep = child.is_granted()
+                        || child.is_forbidden()
+                        || child.is_indeterminate()
+                        || kept < max_children;
+                    if keep {
+                        kept += 1;
+                    } else {
+                        dropped += 1;
+                    }
+                    keep
+                });
+                if dropped > 0 {
+                    children.push(PolicyEvalResult::not_applicable(
+                        PERMISSION_CHECKER_POLICY_TYPE,
+                        format!("... and {dropped} more denied"),
+                    ));
+                }
+            }
+        }
+        PolicyEvalResult::Combined {
+            policy_type: std::borrow::Cow::Borrowed(PERMISSION_CHECKER_POLICY_TYPE),
+            policy_id: None,
+            operation: self.strategy.operation(),
+            children,
+            outcome,
+        }
+    }
+
+    /// Decides a request that no policy granted or forbade, applying the
+    /// default for the action's class.
+    fn undecided(
+        &self,
+        action: &D::Action,
+        mut children: Vec<PolicyEvalResult>,
+    ) -> AccessEvaluation {
+        let open_class = self
+            .action_classifier
+            .as_ref()
+            .map(|classify| classify(action))
+            .filter(|class| self.default_allow.contains(class))
+            .filter(|_| !children.iter().any(PolicyEvalResult::is_indeterminate));
+        match open_class {
+            Some(class) => {
+                let reason = format!("No policy applied; {class} actions are allowed by default");
+                children.push(PolicyEvalResult::granted(
+                    PERMISSION_CHECKER_POLICY_TYPE,
+                    Some(reason.clone()),
+                ));
+                AccessEvaluation::Granted {
+                    policy_type: Cow::Borrowed(PERMISSION_CHECKER_POLICY_TYPE),
+                    reason: Some(reason),
+                    trace: EvalTrace::with_root(self.root(children, true)),
+                }
+            }
+            None => AccessEvaluation::Denied {
+                trace: EvalTrace::with_root(self.root(children, false)),
+                reason: "All policies denied access".to_string(),
+            },
+        }
+    }
+
+    /// Decides a request under [`CombineStrategy::OnlyOneApplicable`] from
+    /// the results of every policy, in registration order.
+    fn only_one_applicable(
+        &self,
+        action: &D::Action,
+        mut children: Vec<PolicyEvalResult>,
+    ) -> AccessEvaluation {
+        let mut applicable = children.iter().enumerate().filter(|(_, result)| {
+            result.is_granted() || result.is_forbidden() || result.is_indeterminate()
+        });
+        let Some((index, result)) = applicable.next() else {
+            return self.undecided(action, children);
+        };
+        if applicable.next().is_some() {
+            children.push(PolicyEvalResult::indeterminate(
+                PERMISSION_CHECKER_POLICY_TYPE,
+                MULTIPLE_APPLICABLE_REASON,
+            ));
+            return AccessEvaluation::Denied {
+                trace: EvalTrace::with_root(self.root(children, false)),
+                reason: MULTIPLE_APPLICABLE_REASON.to_string(),
+            };
+        }
+
+        let policy_type = self.policies[index].policy_type();
+        let reason = result.reason();
+        if result.is_granted() {
+            return AccessEvaluation::Granted {
+                policy_type,
+                reason,
+                trace: EvalTrace::with_root(self.root(children, true)),
+            };
+        }
+        let reason = match result.forbidden_leaf() {
+            Some((forbid_policy_type, forbid_reason)) => {
+                forbid_summary(forbid_policy_type, forbid_reason)
+            }
+            None if result.is_forbidden() => forbid_summary(&policy_type, reason.as_deref()),
+            None => not_granted_summary(&policy_type, reason.as_deref()),
+        };
+        AccessEvaluation::Denied {
+            trace: EvalTrace::with_root(self.root(children, false)),
+            reason,
+        }
+    }
+
+    /// Decides a request under [`CombineStrategy::FirstMatch`] that no
+    /// policy granted or forbade.
+    fn unmatched(&self, default: Effect, mut children: Vec<PolicyEvalResult>) -> AccessEvaluation {
+        if default != Effect::Allow {
+            return AccessEvaluation::Denied {
+                trace: EvalTrace::with_root(self.root(children, false)),
+                reason: NO_MATCH_DENY_REASON.to_string(),
+            };
+        }
+        children.push(PolicyEvalResult::granted(
+            PERMISSION_CHECKER_POLICY_TYPE,
+            Some(NO_MATCH_ALLOW_REASON.to_string()),
+        ));
+        AccessEvaluation::Granted {
+            policy_type: Cow::Borrowed(PERMISSION_CHECKER_POLICY_TYPE),
+            reason: Some(NO_MATCH_ALLOW_REASON.to_string()),
+            trace: EvalTrace::with_root(self.root(children, true)),
+        }
+    }
+
+    /// The default effect when the strategy is
+    /// [`CombineStrategy::FirstMatch`].
+    fn first_match_default(&self) -> Option<Effect> {
+        match self.strategy {
+            CombineStrategy::FirstMatch { default } => Some(default),
+            _ => None,
+        }
+    }
+
+    /// Indices into `policies` in the order they are evaluated: scheduling
+    /// order, or the order they were added under
+    /// [`CombineStrategy::FirstMatch`].
+    fn evaluation_order(&self) -> Vec<usize> {
+        let mut order = (0..self.policies.len()).collect::<Vec<_>>();
+        if self.first_match_default().is_some() {
+            order.sort_by_key(|&index| self.added[index]);
+        }
+        order
+    }
+
     fn declared_effect(&self, policy_index: usize) -> Effect {
         self.effects
             .get(policy_index)
@@ -153,7 +1161,38 @@ impl<D: PolicyDomain> PermissionChecker<D> {
         Effect::from_capabilities(can_grant, can_forbid)
     }
 
-    #[tracing::instrument(skip_all, fields(checker.name = tracing::field::Empty, policy_count = self.policies.len(), outcome = tracing::field::Empty, policy.type = tracing::field::Empty))]
+    fn declare_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        declared: &mut DeclaredFacts,
+    ) {
+        for policy in &self.policies {
+            policy.declared_facts(subject, action, resource, context, declared);
+        }
+    }
+
+    async fn prefetch_declared_facts(session: &EvaluationSession, declared: DeclaredFacts) {
+        if declared.is_empty() {
+            return;
+        }
+        let prefetch_span = tracing::debug_span!(
+            "gatehouse.prefetch_facts",
+            fact.declared_count = declared.len(),
+        );
+        declared.prefetch(session).instrument(prefetch_span).await;
+    }
+
+    /// Evaluates one request in the `evaluate_one` span.
+    ///
+    /// A denial marks the span as failed using the OpenTelemetry conventions
+    /// understood by `tracing-opentelemetry`: `otel.status_code` is `ERROR`,
+    /// `otel.status_description` is the denial reason, and `error.type` is
+    /// `indeterminate` when a policy could not decide and `access_denied`
+    /// otherwise. Grants leave the status unset.
+    #[tracing::instrument(name = "evaluate_one", skip_all, fields(checker.name = tracing::field::Empty, policy_count = self.policies.len(), outcome = tracing::field::Empty, policy.type = tracing::field::Empty, otel.status_code = tracing::field::Empty, otel.status_description = tracing::field::Empty, error.type = tracing::field::Empty))]
     async fn evaluate_one(
         &self,
         session: &EvaluationSession,
@@ -161,7 +1200,72 @@ impl<D: PolicyDomain> PermissionChecker<D> {
         action: &D::Action,
         resource: &D::Resource,
         context: &D::Context,
+        mode: EvaluationMode,
     ) -> AccessEvaluation {
+        if let Some(memo) = &self.memo {
+            if let Some(evaluation) = memo.lookup(subject, action, resource, context) {
+                return evaluation.with_stats(EvalStats::memoized(self.policies.len()));
+            }
+        }
+        let (evaluation, evaluated) = self
+            .decide_one(session, subject, action, resource, context, mode)
+            .await;
+        let evaluation = evaluation.with_stats(EvalStats::new(evaluated, self.policies.len()));
+        if let Some(memo) = &self.memo {
+            memo.store(subject, action, resource, context, &evaluation);
+        }
+        if let AccessEvaluation::Denied { reason, .. } = &evaluation {
+            let error_type = if evaluation.is_indeterminate() {
+                "indeterminate"
+            } else {
+                "access_denied"
+            };
+            let span = tracing::Span::current();
+            span.record("otel.status_code", "ERROR");
+            span.record("otel.status_description", reason.as_str());
+            span.record("error.type", error_type);
+        }
+        evaluation
+    }
+
+    /// Fails closed because the ambient deadline set with
+    /// [`crate::with_deadline`] passed after `evaluated` policies had
+    /// decided. The reason counts the evaluated and skipped policies.
+    fn deadline_exceeded(&self, evaluated: usize) -> AccessEvaluation {
+        let evaluation = self.deadline_exceeded_evaluation(evaluated);
+        if let AccessEvaluation::Denied { reason, .. } = &evaluation {
+            tracing::warn!(checker.name = self.name.as_deref(), "{reason}");
+        }
+        tracing::Span::current().record("outcome", "denied");
+        evaluation
+    }
+
+    /// [`Self::deadline_exceeded`] without the log line, for batches, which
+    /// log once for all of their expired items.
+    fn deadline_exceeded_evaluation(&self, evaluated: usize) -> AccessEvaluation {
+        let total = self.policies.len();
+        let reason = format!(
+            "deadline exceeded: {evaluated} of {total} policies evaluated, {} skipped",
+            total - evaluated
+        );
+        AccessEvaluation::Denied {
+            trace: EvalTrace::with_root(PolicyEvalResult::indeterminate(
+                PERMISSION_CHECKER_POLICY_TYPE,
+                reason.clone(),
+            )),
+            reason,
+        }
+    }
+
+    async fn decide_one(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        mode: EvaluationMode,
+    ) -> (AccessEvaluation, usize) {
         if let Some(name) = self.name.as_deref() {
             tracing::Span::current().record("checker.name", name);
         }
@@ -172,16 +1276,52 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                 "No policies configured",
             );
 
-            return AccessEvaluation::Denied {
+            let evaluation = AccessEvaluation::Denied {
                 trace: EvalTrace::with_root(result),
                 reason: "No policies configured".to_string(),
             };
+            return (evaluation, 0);
+        }
+
+        let mut declared = DeclaredFacts::new();
+        self.declare_facts(subject, action, resource, context, &mut declared);
+        if before_deadline(Self::prefetch_declared_facts(session, declared))
+            .await
+            .is_none()
+        {
+            return (self.deadline_exceeded(0), 0);
         }
 
+        let mut concurrent_results = match mode {
+            EvaluationMode::Sequential => None,
+            EvaluationMode::Concurrent => {
+                let Some((results, panicked)) = before_deadline(
+                    self.evaluate_concurrently(session, subject, action, resource, context),
+                )
+                .await
+                else {
+                    return (self.deadline_exceeded(0), 0);
+                };
+                if let Some(policy_type) = panicked {
+                    tracing::Span::current().record("outcome", "denied");
+                    tracing::Span::current().record("policy.type", policy_type.as_ref());
+                    let evaluation = AccessEvaluation::Denied {
+                        trace: EvalTrace::with_root(self.root(results, false)),
+                        reason: format!("Policy {policy_type} panicked during evaluation"),
+                    };
+                    return (evaluation, self.policies.len());
+                }
+                Some(results.into_iter().map(Some).collect::<Vec<_>>())
+            }
+        };
+        let ran_all = concurrent_results.is_some();
         let mut policy_results = Vec::with_capacity(self.policies.len());
         let mut first_grant: Option<(Cow<'static, str>, Option<String>)> = None;
+        let only_one = self.strategy == CombineStrategy::OnlyOneApplicable;
+        let first_match = self.first_match_default();
 
-        for (policy_index, policy) in self.policies.iter().enumerate() {
+        for policy_index in self.evaluation_order() {
+            let policy = &self.policies[policy_index];
             let declared_effect = self.declared_effect(policy_index);
             let ctx = EvalCtx {
                 session,
@@ -191,7 +1331,27 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                 context,
                 policy_type: policy.policy_type(),
             };
-            let mut result = policy.evaluate(&ctx).await;
+            let mut result = match concurrent_results
+                .as_mut()
+                .and_then(|results| results[policy_index].take())
+            {
+                Some(result) => result,
+                None => {
+                    if deadline_passed() {
+                        let evaluated = policy_results.len();
+                        return (self.deadline_exceeded(evaluated), evaluated);
+                    }
+                    let evaluation =
+                        self.observe(&ctx.policy_type, evaluate_in_span(policy.as_ref(), &ctx));
+                    match before_deadline(evaluation).await {
+                        Some(result) => result,
+                        None => {
+                            let evaluated = policy_results.len();
+                            return (self.deadline_exceeded(evaluated), evaluated);
+                        }
+                    }
+                }
+            };
             if declared_effect == Effect::Forbid && result.is_granted() {
                 tracing::warn!(
                     policy.type = ctx.policy_type.as_ref(),
@@ -222,7 +1382,13 @@ impl<D: PolicyDomain> PermissionChecker<D> {
             let ruleset_name = metadata
                 .ruleset_name()
                 .unwrap_or(PERMISSION_CHECKER_POLICY_TYPE);
-            let event_outcome = if result_passes { "success" } else { "failure" };
+            let event_outcome = if result_passes {
+                "success"
+            } else if result.is_indeterminate() {
+                "unknown"
+            } else {
+                "failure"
+            };
             let policy_effect = declared_effect.telemetry_label();
 
             tracing::trace!(
@@ -244,7 +1410,7 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                 "Security rule evaluated"
             );
 
-            let forbidden = result_forbids.then(|| {
+            let forbidden = (result_forbids && !only_one).then(|| {
                 result
                     .forbidden_leaf()
                     .map(|(policy_type, reason)| {
@@ -252,43 +1418,156 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                     })
                     .unwrap_or_else(|| (policy_type_str.to_string(), reason.clone()))
             });
+            let result_indeterminate = result.is_indeterminate();
 
             policy_results.push(result);
+            let evaluated = if ran_all {
+                self.policies.len()
+            } else {
+                policy_results.len()
+            };
 
             if let Some((forbid_policy_type, forbid_reason)) = forbidden {
                 tracing::Span::current().record("outcome", "denied");
                 tracing::Span::current().record("policy.type", forbid_policy_type.as_str());
-                let combined = checker_root(policy_results, false);
-                return AccessEvaluation::Denied {
+                let combined = self.root(policy_results, false);
+                let evaluation = AccessEvaluation::Denied {
                     trace: EvalTrace::with_root(combined),
                     reason: forbid_summary(&forbid_policy_type, forbid_reason.as_deref()),
                 };
+                return (evaluation, evaluated);
+            }
+
+            let all_allow = self.strategy == CombineStrategy::AllAllow;
+            if all_allow && !result_passes && declared_effect.can_grant() {
+                tracing::Span::current().record("outcome", "denied");
+                tracing::Span::current().record("policy.type", policy_type_str);
+                let reason = not_granted_summary(policy_type_str, reason.as_deref());
+                let combined = self.root(policy_results, false);
+                let evaluation = AccessEvaluation::Denied {
+                    trace: EvalTrace::with_root(combined),
+                    reason,
+                };
+                return (evaluation, evaluated);
+            }
+
+            if first_match.is_some() && result_indeterminate {
+                tracing::Span::current().record("outcome", "denied");
+                tracing::Span::current().record("policy.type", policy_type_str);
+                let reason = not_granted_summary(policy_type_str, reason.as_deref());
+                let combined = self.root(policy_results, false);
+                let evaluation = AccessEvaluation::Denied {
+                    trace: EvalTrace::with_root(combined),
+                    reason,
+                };
+                return (evaluation, evaluated);
             }
 
             if result_passes {
                 first_grant.get_or_insert_with(|| (ctx.policy_type.clone(), reason));
             }
 
-            if policy_index + 1 >= self.veto_capable_count {
+            if !all_allow
+                && !only_one
+                && (first_match.is_some() || policy_index + 1 >= self.veto_capable_count)
+            {
                 if let Some((policy_type, reason)) = first_grant.take() {
                     tracing::Span::current().record("outcome", "granted");
                     tracing::Span::current().record("policy.type", policy_type.as_ref());
-                    let combined = checker_root(policy_results, true);
-                    return AccessEvaluation::Granted {
+                    let combined = self.root(policy_results, true);
+                    let evaluation = AccessEvaluation::Granted {
                         policy_type,
                         reason,
                         trace: EvalTrace::with_root(combined),
                     };
+                    return (evaluation, evaluated);
                 }
             }
         }
 
-        tracing::Span::current().record("outcome", "denied");
-        let combined = checker_root(policy_results, false);
-        AccessEvaluation::Denied {
-            trace: EvalTrace::with_root(combined),
-            reason: "All policies denied access".to_string(),
-        }
+        let evaluation = match first_grant {
+            _ if only_one => self.only_one_applicable(action, policy_results),
+            Some((policy_type, reason)) => {
+                tracing::Span::current().record("policy.type", policy_type.as_ref());
+                AccessEvaluation::Granted {
+                    policy_type,
+                    reason,
+                    trace: EvalTrace::with_root(self.root(policy_results, true)),
+                }
+            }
+            None => match first_match {
+                Some(default) => self.unmatched(default, policy_results),
+                None => self.undecided(action, policy_results),
+            },
+        };
+        let outcome = if evaluation.is_granted() {
+            "granted"
+        } else {
+            "denied"
+        };
+        tracing::Span::current().record("outcome", outcome);
+        (evaluation, self.policies.len())
+    }
+
+    /// Awaits one policy evaluation, reporting it to the observer if one is
+    /// installed.
+    async fn observe(
+        &self,
+        policy_type: &str,
+        evaluation: impl Future<Output = PolicyEvalResult>,
+    ) -> PolicyEvalResult {
+        let Some(observer) = &self.observer else {
+            return evaluation.await;
+        };
+        let started = Instant::now();
+        let result = evaluation.await;
+        observer.on_policy_evaluated(policy_type, &result, started.elapsed());
+        result
+    }
+
+    /// Evaluates every policy at once, returning results in registration
+    /// order and the type of the first policy that panicked, if any. A
+    /// panicking policy's result is indeterminate.
+    async fn evaluate_concurrently(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> (Vec<PolicyEvalResult>, Option<Cow<'static, str>>) {
+        let contexts = self
+            .policies
+            .iter()
+            .map(|policy| EvalCtx {
+                session,
+                subject,
+                action,
+                resource,
+                context,
+                policy_type: policy.policy_type(),
+            })
+            .collect::<Vec<_>>();
+        let evaluations = self.policies.iter().zip(&contexts).map(|(policy, ctx)| {
+            AssertUnwindSafe(self.observe(&ctx.policy_type, evaluate_in_span(policy.as_ref(), ctx)))
+                .catch_unwind()
+        });
+
+        let mut panicked = None;
+        let results = join_all(evaluations)
+            .await
+            .into_iter()
+            .zip(&contexts)
+            .map(|(result, ctx)| {
+                result.unwrap_or_else(|_| {
+                    let policy_type = ctx.policy_type.as_ref();
+                    tracing::error!(policy.type = policy_type, "{POLICY_PANICKED_REASON}");
+                    panicked.get_or_insert_with(|| ctx.policy_type.clone());
+                    ctx.indeterminate(POLICY_PANICKED_REASON)
+                })
+            })
+            .collect();
+        (results, panicked)
     }
 
     #[tracing::instrument(name = "evaluate_batch", skip_all, fields(checker.name = tracing::field::Empty, item_count, granted_count, denied_count, max_batch_size, policy_count = self.policies.len()))]
@@ -316,6 +1595,7 @@ impl<D: PolicyDomain> PermissionChecker<D> {
         }
 
         let mut traces = vec![Vec::new(); item_count];
+        let mut evaluated = vec![0usize; item_count];
         let mut evaluations: Vec<Option<AccessEvaluation>> = vec![None; item_count];
 
         if self.policies.is_empty() {
@@ -326,13 +1606,11 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                         PERMISSION_CHECKER_POLICY_TYPE,
                         "No policies configured",
                     );
-                    (
-                        item,
-                        AccessEvaluation::Denied {
-                            trace: EvalTrace::with_root(result),
-                            reason: "No policies configured".to_string(),
-                        },
-                    )
+                    let evaluation = AccessEvaluation::Denied {
+                        trace: EvalTrace::with_root(result),
+                        reason: "No policies configured".to_string(),
+                    };
+                    (item, evaluation.with_stats(EvalStats::new(0, 0)))
                 })
                 .collect();
             tracing::Span::current().record("granted_count", 0usize);
@@ -347,14 +1625,25 @@ impl<D: PolicyDomain> PermissionChecker<D> {
             })
             .collect::<Vec<_>>();
 
+        let mut declared = DeclaredFacts::new();
+        for item in &item_parts {
+            self.declare_facts(subject, action, item.resource, context, &mut declared);
+        }
+        let mut expired = before_deadline(Self::prefetch_declared_facts(session, declared))
+            .await
+            .is_none();
+
+        let only_one = self.strategy == CombineStrategy::OnlyOneApplicable;
+        let first_match = self.first_match_default();
         let mut pending: Vec<usize> = (0..item_count).collect();
         let mut first_grants: Vec<Option<(Cow<'static, str>, Option<String>)>> =
             vec![None; item_count];
 
-        for (policy_index, policy) in self.policies.iter().enumerate() {
-            if pending.is_empty() {
+        'policies: for policy_index in self.evaluation_order() {
+            if pending.is_empty() || expired {
                 break;
             }
+            let policy = &self.policies[policy_index];
 
             let declared_effect = self.declared_effect(policy_index);
             let policy_type = policy.policy_type();
@@ -398,10 +1687,27 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                     items: &batch_items,
                     policy_type: policy_type.clone(),
                 };
-                let policy_results = policy
-                    .evaluate_batch(&batch_ctx)
-                    .instrument(policy_span.clone())
-                    .await;
+                if deadline_passed() {
+                    expired = true;
+                    break 'policies;
+                }
+                let started = self.observer.as_ref().map(|_| Instant::now());
+                let Some(policy_results) = before_deadline(
+                    policy
+                        .evaluate_batch(&batch_ctx)
+                        .instrument(policy_span.clone()),
+                )
+                .await
+                else {
+                    expired = true;
+                    break 'policies;
+                };
+                if let (Some(observer), Some(started)) = (&self.observer, started) {
+                    let duration = started.elapsed();
+                    for result in &policy_results {
+                        observer.on_policy_evaluated(policy_type_str, result, duration);
+                    }
+                }
 
                 if policy_results.len() != pending_chunk.len() {
                     for &index in pending_chunk {
@@ -411,7 +1717,8 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                             "Policy batch result count did not match input count",
                         );
                         traces[index].push(policy_result);
-                        let combined = checker_root(std::mem::take(&mut traces[index]), false);
+                        evaluated[index] += 1;
+                        let combined = self.root(std::mem::take(&mut traces[index]), false);
                         evaluations[index] = Some(AccessEvaluation::Denied {
                             trace: EvalTrace::with_root(combined),
                             reason: "Policy batch result count did not match input count"
@@ -439,7 +1746,7 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                         allow_forbid_violation_count += 1;
                     }
                     let reason = result.reason();
-                    let forbidden = result_forbids.then(|| {
+                    let forbidden = (result_forbids && !only_one).then(|| {
                         result
                             .forbidden_leaf()
                             .map(|(policy_type, reason)| {
@@ -447,16 +1754,29 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                             })
                             .unwrap_or_else(|| (policy_type_str.to_string(), reason.clone()))
                     });
+                    let result_indeterminate = result.is_indeterminate();
 
                     traces[index].push(result);
+                    evaluated[index] += 1;
 
                     if let Some((forbid_policy_type, forbid_reason)) = forbidden {
                         policy_forbidden_count += 1;
-                        let combined = checker_root(std::mem::take(&mut traces[index]), false);
+                        let combined = self.root(std::mem::take(&mut traces[index]), false);
                         evaluations[index] = Some(AccessEvaluation::Denied {
                             trace: EvalTrace::with_root(combined),
                             reason: forbid_summary(&forbid_policy_type, forbid_reason.as_deref()),
                         });
+                    } else if (self.strategy == CombineStrategy::AllAllow
+                        && !result_passes
+                        && declared_effect.can_grant())
+                        || (first_match.is_some() && result_indeterminate)
+                    {
+                        policy_denied_count += 1;
+                        let combined = self.root(std::mem::take(&mut traces[index]), false);
+                        evaluations[index] = Some(AccessEvaluation::Denied {
+                            trace: EvalTrace::with_root(combined),
+                            reason: not_granted_summary(policy_type_str, reason.as_deref()),
+                        });
                     } else {
                         if result_passes {
                             policy_granted_count += 1;
@@ -466,12 +1786,15 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                             policy_denied_count += 1;
                         }
 
-                        if policy_index + 1 >= self.veto_capable_count {
+                        if self.strategy != CombineStrategy::AllAllow
+                            && !only_one
+                            && (first_match.is_some()
+                                || policy_index + 1 >= self.veto_capable_count)
+                        {
                             if let Some((grant_policy_type, grant_reason)) =
                                 first_grants[index].take()
                             {
-                                let combined =
-                                    checker_root(std::mem::take(&mut traces[index]), true);
+                                let combined = self.root(std::mem::take(&mut traces[index]), true);
                                 evaluations[index] = Some(AccessEvaluation::Granted {
                                     policy_type: grant_policy_type,
                                     reason: grant_reason,
@@ -506,21 +1829,52 @@ impl<D: PolicyDomain> PermissionChecker<D> {
             pending = still_pending;
         }
 
+        if expired {
+            let mut expired_count = 0usize;
+            for (evaluation, &evaluated) in evaluations.iter_mut().zip(&evaluated) {
+                if evaluation.is_none() {
+                    *evaluation = Some(self.deadline_exceeded_evaluation(evaluated));
+                    expired_count += 1;
+                }
+            }
+            tracing::warn!(
+                checker.name = self.name.as_deref(),
+                expired_count,
+                "deadline exceeded"
+            );
+            pending.clear();
+        }
+
         for index in pending {
-            let combined = checker_root(std::mem::take(&mut traces[index]), false);
-            evaluations[index] = Some(AccessEvaluation::Denied {
-                trace: EvalTrace::with_root(combined),
-                reason: "All policies denied access".to_string(),
+            evaluations[index] = Some(match first_grants[index].take() {
+                _ if only_one => {
+                    self.only_one_applicable(action, std::mem::take(&mut traces[index]))
+                }
+                Some((grant_policy_type, grant_reason)) => AccessEvaluation::Granted {
+                    policy_type: grant_policy_type,
+                    reason: grant_reason,
+                    trace: EvalTrace::with_root(
+                        self.root(std::mem::take(&mut traces[index]), true),
+                    ),
+                },
+                None => {
+                    let children = std::mem::take(&mut traces[index]);
+                    match first_match {
+                        Some(default) => self.unmatched(default, children),
+                        None => self.undecided(action, children),
+                    }
+                }
             });
         }
 
         drop(item_parts);
 
         let mut granted_count = 0usize;
+        let total = self.policies.len();
         let results = items
             .into_iter()
-            .zip(evaluations.into_iter())
-            .map(|(item, evaluation)| {
+            .zip(evaluations.into_iter().zip(evaluated))
+            .map(|(item, (evaluation, evaluated))| {
                 let evaluation = evaluation.unwrap_or_else(|| {
                     let result = PolicyEvalResult::not_applicable(
                         PERMISSION_CHECKER_POLICY_TYPE,
@@ -531,6 +1885,7 @@ impl<D: PolicyDomain> PermissionChecker<D> {
                         reason: "Batch item was not evaluated".to_string(),
                     }
                 });
+                let evaluation = evaluation.with_stats(EvalStats::new(evaluated, total));
                 if evaluation.is_granted() {
                     granted_count += 1;
                 }
@@ -562,6 +1917,189 @@ impl<D: PolicyDomain> PermissionChecker<D> {
     }
 }
 
+impl<D> PermissionChecker<D>
+where
+    D: PolicyDomain,
+    D::Subject: Hash + Eq + Clone,
+    D::Action: Hash + Eq + Clone,
+    D::Resource: Hash + Eq + Clone,
+    D::Context: Hash + Eq + Clone,
+{
+    /// Memoizes whole evaluations, keyed by the `(subject, action, resource,
+    /// context)` of each request, for hot checks repeated within one
+    /// process.
+    ///
+    /// Single-resource checks ([`BoundEvaluator::check`],
+    /// [`BoundEvaluator::check_parallel`], [`Self::decide`], and the other
+    /// paths built on them) return a memoized evaluation without running any
+    /// policy; its trace reasons carry a `" (cached)"` suffix, as with
+    /// [`crate::CachedPolicy`], and its [`AccessEvaluation::stats`] report
+    /// `memoized` with no policies evaluated. Batch evaluation is not
+    /// memoized.
+    ///
+    /// Entries expire after `ttl`, or at a grant's
+    /// [`valid_until`](AccessEvaluation::valid_until) if that is sooner, and
+    /// the memo holds at most `capacity` of them, evicting as
+    /// [`crate::CachedPolicy`] does. Indeterminate evaluations are never
+    /// stored. Adding, removing, or reordering policies, or changing the
+    /// strategy, starts an empty memo.
+    ///
+    /// The key does not include the [`EvaluationSession`], so facts loaded
+    /// into a later session are not consulted for a memoized request: a
+    /// revoked relationship keeps granting until its entry expires or
+    /// [`Self::clear_memoized`] is called. Choose `ttl` accordingly.
+    pub fn with_memoization(mut self, capacity: usize, ttl: Duration) -> Self {
+        self.memo = Some(Arc::new(MemoCache::<D>::new(capacity, ttl)));
+        self
+    }
+}
+
+/// Stand-in used by [`PermissionChecker::assert_fail_closed`].
+struct IndeterminatePolicy {
+    policy_type: Cow<'static, str>,
+    effect: Effect,
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for IndeterminatePolicy {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        ctx.indeterminate("Policy could not decide")
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.policy_type.clone()
+    }
+
+    fn effect(&self) -> Effect {
+        self.effect
+    }
+}
+
+/// Stand-in used by [`PermissionChecker::explain`] to replay a recorded
+/// result.
+struct ReplayPolicy {
+    policy_type: Cow<'static, str>,
+    effect: Effect,
+    result: PolicyEvalResult,
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for ReplayPolicy {
+    async fn evaluate(&self, _ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        self.result.clone()
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.policy_type.clone()
+    }
+
+    fn effect(&self) -> Effect {
+        self.effect
+    }
+}
+
+/// A sub-checker added with [`PermissionChecker::mount`].
+struct MountedChecker<D: PolicyDomain> {
+    routes: Box<dyn Fn(&D::Resource) -> bool + Send + Sync>,
+    checker: PermissionChecker<D>,
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for MountedChecker<D> {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        if !(self.routes)(ctx.resource) {
+            return ctx.not_applicable("Resource is not routed to this checker");
+        }
+        let evaluation = self
+            .checker
+            .bind(ctx.session, ctx.subject, ctx.action, ctx.context)
+            .check(ctx.resource)
+            .await;
+        delegated_evaluation_to_result(ctx.policy_type.clone(), evaluation)
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.checker
+            .name
+            .clone()
+            .unwrap_or(Cow::Borrowed(PERMISSION_CHECKER_POLICY_TYPE))
+    }
+
+    fn effect(&self) -> Effect {
+        self.checker.aggregate_effect()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        if (self.routes)(resource) {
+            self.checker
+                .declare_facts(subject, action, resource, context, facts);
+        }
+    }
+}
+
+/// Assembles a [`PermissionChecker`] in one expression.
+///
+/// Built with [`PermissionChecker::builder`]. Each method does what the
+/// matching checker method does; policies are scheduled exactly as
+/// [`PermissionChecker::add_policy`] schedules them.
+///
+/// ```rust,ignore
+/// let checker = PermissionChecker::builder()
+///     .name("InvoiceChecker")
+///     .strategy(CombineStrategy::AllAllow)
+///     .observer(Arc::new(metrics))
+///     .policy(admin_override_policy())
+///     .policy(invoice_viewer_policy())
+///     .build();
+/// ```
+pub struct PermissionCheckerBuilder<D: PolicyDomain> {
+    checker: PermissionChecker<D>,
+}
+
+impl<D: PolicyDomain> PermissionCheckerBuilder<D> {
+    /// Names the checker for telemetry, like [`PermissionChecker::named`].
+    pub fn name(mut self, name: impl Into<Cow<'static, str>>) -> Self {
+        self.checker.name = Some(name.into());
+        self
+    }
+
+    /// Sets the strategy used to combine policy decisions.
+    pub fn strategy(mut self, strategy: CombineStrategy) -> Self {
+        self.checker.set_strategy(strategy);
+        self
+    }
+
+    /// Installs an observer; see [`PermissionChecker::with_observer`].
+    pub fn observer(mut self, observer: Arc<dyn PolicyObserver>) -> Self {
+        self.checker = self.checker.with_observer(observer);
+        self
+    }
+
+    /// Adds a policy; see [`PermissionChecker::add_policy`].
+    pub fn policy<P: Policy<D> + 'static>(mut self, policy: P) -> Self {
+        self.checker.add_policy(policy);
+        self
+    }
+
+    /// Adds a field policy; see [`PermissionChecker::add_field_policy`].
+    pub fn field_policy<P: FieldPolicy<D> + 'static>(mut self, policy: P) -> Self {
+        self.checker.add_field_policy(policy);
+        self
+    }
+
+    /// Returns the configured checker.
+    pub fn build(self) -> PermissionChecker<D> {
+        self.checker
+    }
+}
+
 /// A request-bound evaluator for one checker, subject, action, context, and
 /// evaluation session.
 pub struct BoundEvaluator<'a, D: PolicyDomain> {
@@ -582,10 +2120,66 @@ impl<'a, D: PolicyDomain> BoundEvaluator<'a, D> {
                 self.action,
                 resource,
                 self.context,
+                EvaluationMode::Sequential,
+            )
+            .await
+    }
+
+    /// Evaluates one resource, driving every policy concurrently.
+    ///
+    /// Use this for checkers with several independent, IO-bound policies
+    /// (separate database or remote calls), where latency is the sum of the
+    /// policies under [`Self::check`]. Every policy is evaluated, so the
+    /// short-circuit savings of [`Self::check`] are traded for latency.
+    ///
+    /// The results are combined exactly as [`Self::check`] combines them, in
+    /// registration order, so the decision and trace are the ones
+    /// [`Self::check`] returns; results of policies after the deciding one are
+    /// discarded. A policy that panics does not unwind the request: it is
+    /// recorded as [`PolicyEvalResult::Indeterminate`] and the request is
+    /// denied regardless of the other results.
+    pub async fn check_parallel(&self, resource: &D::Resource) -> AccessEvaluation {
+        self.checker
+            .evaluate_one(
+                self.session,
+                self.subject,
+                self.action,
+                resource,
+                self.context,
+                EvaluationMode::Concurrent,
             )
             .await
     }
 
+    /// Returns the fields of `resource` the bound subject may access.
+    ///
+    /// The resource itself is checked first: a denied resource yields
+    /// [`FieldDecision::none`]. Otherwise the decisions of every field policy
+    /// are intersected, starting from [`FieldDecision::All`] when none are
+    /// registered.
+    pub async fn authorized_fields(&self, resource: &D::Resource) -> FieldDecision {
+        if !self.check(resource).await.is_granted() {
+            return FieldDecision::none();
+        }
+
+        let mut decision = FieldDecision::All;
+        for policy in &self.checker.field_policies {
+            let ctx = EvalCtx {
+                session: self.session,
+                subject: self.subject,
+                action: self.action,
+                resource,
+                context: self.context,
+                policy_type: policy.policy_type(),
+            };
+            decision = decision.intersect(policy.evaluate_fields(&ctx).await);
+            if decision.is_empty() {
+                break;
+            }
+        }
+        decision
+    }
+
     /// Evaluates a batch of already-loaded resources, preserving input order.
     pub async fn evaluate<I>(&self, resources: I) -> Vec<(I::Item, AccessEvaluation)>
     where
@@ -646,6 +2240,39 @@ impl<'a, D: PolicyDomain> BoundEvaluator<'a, D> {
             .collect()
     }
 
+    /// Returns the first `limit` granted resources, in input order, without
+    /// evaluating the rest of the input.
+    ///
+    /// Resources are pulled from `resources` in rounds. The first round holds
+    /// `limit` resources, or the checker's
+    /// [`PermissionChecker::with_max_batch_size`] if that is larger, and each
+    /// later round is at least twice the previous one, so sparse grants still
+    /// load facts in batches. Evaluation stops after the round that collects
+    /// the `limit`-th grant; later resources in that round are evaluated but
+    /// not returned. Use this for paginated checks over long candidate lists.
+    pub async fn filter_take<I>(&self, resources: I, limit: usize) -> Vec<I::Item>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<D::Resource>,
+    {
+        let min_round_size = self.checker.max_batch_size.map_or(1, NonZeroUsize::get);
+        let mut remaining = resources.into_iter();
+        let mut granted = Vec::new();
+        let mut round_size = 0usize;
+        while granted.len() < limit {
+            round_size = (limit - granted.len())
+                .max(round_size.saturating_mul(2))
+                .max(min_round_size);
+            let round = remaining.by_ref().take(round_size).collect::<Vec<_>>();
+            if round.is_empty() {
+                break;
+            }
+            granted.extend(self.filter(round).await);
+        }
+        granted.truncate(limit);
+        granted
+    }
+
     /// Returns only the caller-owned items granted by [`Self::evaluate_by`].
     ///
     /// The returned values are the original input items, not cloned projected
@@ -662,6 +2289,89 @@ impl<'a, D: PolicyDomain> BoundEvaluator<'a, D> {
             .collect()
     }
 
+    /// Evaluates a batch like [`Self::evaluate`], but stops at `deadline`.
+    ///
+    /// Resources are evaluated in rounds of the checker's
+    /// [`PermissionChecker::with_max_batch_size`], or, when it is unset, in
+    /// rounds of 1, 2, 4, and so on, so a deadline never discards more than
+    /// the round it interrupted. Every resource decided before the deadline
+    /// keeps its decision; every other resource, including those in
+    /// the round the deadline interrupted, is denied with the reason
+    /// `"batch deadline exceeded"` and an [`PolicyEvalResult::Indeterminate`]
+    /// trace. Input order is preserved.
+    ///
+    /// Uses `tokio::time`, so evaluation must run inside a Tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub async fn evaluate_with_deadline<I>(
+        &self,
+        resources: I,
+        deadline: tokio::time::Instant,
+    ) -> Vec<(I::Item, AccessEvaluation)>
+    where
+        I: IntoIterator,
+        I::Item: Borrow<D::Resource>,
+    {
+        let items = resources.into_iter().collect::<Vec<_>>();
+        let mut evaluations = Vec::with_capacity(items.len());
+        let mut remaining = items.as_slice();
+        let mut round_size = 0usize;
+        while !remaining.is_empty() {
+            if tokio::time::Instant::now() >= deadline {
+                break;
+            }
+            round_size = self
+                .checker
+                .max_batch_size
+                .map_or_else(|| round_size.saturating_mul(2).max(1), NonZeroUsize::get);
+            let (round, rest) = remaining.split_at(round_size.min(remaining.len()));
+            remaining = rest;
+            let decided = tokio::time::timeout_at(
+                deadline,
+                self.checker.evaluate_batch_by(
+                    self.session,
+                    self.subject,
+                    self.action,
+                    self.context,
+                    round,
+                    |item| Borrow::<D::Resource>::borrow(*item),
+                ),
+            )
+            .await;
+            match decided {
+                Ok(decided) => {
+                    evaluations.extend(decided.into_iter().map(|(_, evaluation)| evaluation))
+                }
+                Err(_) => break,
+            }
+        }
+
+        let decided_count = evaluations.len();
+        if decided_count < items.len() {
+            tracing::warn!(
+                checker.name = self.checker.name.as_deref(),
+                decided_count,
+                expired_count = items.len() - decided_count,
+                "{BATCH_DEADLINE_EXCEEDED_REASON}"
+            );
+        }
+        let mut evaluations = evaluations.into_iter();
+        items
+            .into_iter()
+            .map(|item| {
+                let evaluation = evaluations
+                    .next()
+                    .unwrap_or_else(|| AccessEvaluation::Denied {
+                        trace: EvalTrace::with_root(PolicyEvalResult::indeterminate(
+                            PERMISSION_CHECKER_POLICY_TYPE,
+                            BATCH_DEADLINE_EXCEEDED_REASON,
+                        )),
+                        reason: BATCH_DEADLINE_EXCEEDED_REASON.to_string(),
+                    });
+                (item, evaluation)
+            })
+            .collect()
+    }
+
     /// Looks up one candidate page, hydrates it, and returns authorized
     /// resources from that page.
     pub async fn lookup_page<L, H>(
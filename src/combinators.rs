@@ -1,8 +1,15 @@
+use crate::checker::{forbid_summary, not_granted_summary};
+use crate::policy::evaluate_in_span;
 use crate::{
-    BatchEvalCtx, CombineOp, Effect, EvalCtx, Policy, PolicyBatchItem, PolicyDomain,
-    PolicyEvalResult,
+    AccessEvaluation, BatchEvalCtx, CombineOp, CompiledPolicy, DeclaredFacts, Effect, EvalCtx,
+    EvalTrace, EvaluationSession, IdentifiedPolicy, MapContext, MapResource, MapSubject, Policy,
+    PolicyBatchItem, PolicyComposition, PolicyDomain, PolicyEvalResult,
 };
 use async_trait::async_trait;
+use std::any::Any;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::num::NonZeroUsize;
 use std::sync::Arc;
 
 fn arc_policy<D, P>(policy: P) -> Arc<dyn Policy<D>>
@@ -13,6 +20,40 @@ where
     Arc::new(policy)
 }
 
+/// The children to splice into an AND for `policy`: its own children when it
+/// is a short-circuiting `AndPolicy`, otherwise the policy itself.
+///
+/// AND is associative and every combinator evaluates its veto-capable
+/// children first, so splicing changes only the shape of the trace.
+fn and_operands<D, P>(policy: P) -> Vec<Arc<dyn Policy<D>>>
+where
+    D: PolicyDomain,
+    P: Policy<D> + 'static,
+{
+    let mut slot = Some(policy);
+    if let Some(slot) = (&mut slot as &mut dyn Any).downcast_mut::<Option<AndPolicy<D>>>() {
+        if let Some(and) = slot.take_if(|and| !and.exhaustive) {
+            return and.policies;
+        }
+    }
+    slot.into_iter().map(arc_policy::<D, _>).collect()
+}
+
+/// Like [`and_operands`], for `OrPolicy`.
+fn or_operands<D, P>(policy: P) -> Vec<Arc<dyn Policy<D>>>
+where
+    D: PolicyDomain,
+    P: Policy<D> + 'static,
+{
+    let mut slot = Some(policy);
+    if let Some(slot) = (&mut slot as &mut dyn Any).downcast_mut::<Option<OrPolicy<D>>>() {
+        if let Some(or) = slot.take() {
+            return or.policies;
+        }
+    }
+    slot.into_iter().map(arc_policy::<D, _>).collect()
+}
+
 fn ordered_policies<D>(policies: Vec<Arc<dyn Policy<D>>>) -> (Vec<Arc<dyn Policy<D>>>, usize)
 where
     D: PolicyDomain,
@@ -46,19 +87,32 @@ where
     D: PolicyDomain,
 {
     /// Requires this policy and `other` to grant.
+    ///
+    /// An operand that is itself an `AndPolicy` contributes its children
+    /// rather than a nested node, so `a.and(b).and(c)` traces as one `AND`
+    /// with three children. Decisions are the same as with nesting. Operands
+    /// wrapped in another policy, such as [`Self::with_id`], and
+    /// [`AndPolicy::exhaustive`] ones stay nested.
     fn and<P>(self, other: P) -> AndPolicy<D>
     where
         P: Policy<D> + 'static,
     {
-        AndPolicy::from_policies(vec![arc_policy::<D, _>(self), arc_policy::<D, _>(other)])
+        let mut policies = and_operands::<D, _>(self);
+        policies.extend(and_operands::<D, _>(other));
+        AndPolicy::from_policies(policies)
     }
 
     /// Grants when this policy or `other` grants.
+    ///
+    /// Like [`Self::and`], an operand that is itself an `OrPolicy`
+    /// contributes its children rather than a nested node.
     fn or<P>(self, other: P) -> OrPolicy<D>
     where
         P: Policy<D> + 'static,
     {
-        OrPolicy::from_policies(vec![arc_policy::<D, _>(self), arc_policy::<D, _>(other)])
+        let mut policies = or_operands::<D, _>(self);
+        policies.extend(or_operands::<D, _>(other));
+        OrPolicy::from_policies(policies)
     }
 
     /// Inverts this policy.
@@ -68,10 +122,135 @@ where
         }
     }
 
+    /// Tags this policy's results with `id`, so traces and audit logs can
+    /// tell apart instances that share a policy type. See
+    /// [`IdentifiedPolicy`].
+    fn with_id(self, id: impl Into<std::borrow::Cow<'static, str>>) -> IdentifiedPolicy<D, Self> {
+        IdentifiedPolicy::new(self, id)
+    }
+
+    /// Adapts this policy to a domain with a wider resource type, abstaining
+    /// when `map` returns `None`. See [`MapResource`].
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// # struct User;
+    /// # #[derive(Clone)]
+    /// # struct Invoice { paid: bool }
+    /// # enum Resource { Invoice(Invoice), Post }
+    /// # struct Invoices;
+    /// # impl PolicyDomain for Invoices {
+    /// #     type Subject = User;
+    /// #     type Action = ();
+    /// #     type Resource = Invoice;
+    /// #     type Context = ();
+    /// # }
+    /// # struct App;
+    /// # impl PolicyDomain for App {
+    /// #     type Subject = User;
+    /// #     type Action = ();
+    /// #     type Resource = Resource;
+    /// #     type Context = ();
+    /// # }
+    /// let paid = PolicyBuilder::<Invoices>::new("PaidInvoices")
+    ///     .resources(|invoice| invoice.paid)
+    ///     .build();
+    /// let mut checker = PermissionChecker::<App>::new();
+    /// checker.add_policy(paid.map_resource(|resource: &Resource| match resource {
+    ///     Resource::Invoice(invoice) => Some(invoice.clone()),
+    ///     _ => None,
+    /// }));
+    /// ```
+    fn map_resource<Outer, F>(self, map: F) -> MapResource<Outer, D, Self>
+    where
+        Outer: PolicyDomain<Subject = D::Subject, Action = D::Action, Context = D::Context>,
+        F: Fn(&Outer::Resource) -> Option<D::Resource> + Send + Sync + 'static,
+    {
+        MapResource::new(self, map)
+    }
+
+    /// Adapts this policy to a domain with a wider subject type, abstaining
+    /// when `map` returns `None`. See [`MapSubject`].
+    fn map_subject<Outer, F>(self, map: F) -> MapSubject<Outer, D, Self>
+    where
+        Outer: PolicyDomain<Action = D::Action, Resource = D::Resource, Context = D::Context>,
+        F: Fn(&Outer::Subject) -> Option<D::Subject> + Send + Sync + 'static,
+    {
+        MapSubject::new(self, map)
+    }
+
+    /// Adapts this policy to a domain with a wider context type, abstaining
+    /// when `map` returns `None`. See [`MapContext`].
+    fn map_context<Outer, F>(self, map: F) -> MapContext<Outer, D, Self>
+    where
+        Outer: PolicyDomain<Subject = D::Subject, Action = D::Action, Resource = D::Resource>,
+        F: Fn(&Outer::Context) -> Option<D::Context> + Send + Sync + 'static,
+    {
+        MapContext::new(self, map)
+    }
+
     /// Boxes this policy as a trait object.
     fn boxed(self) -> Box<dyn Policy<D>> {
         Box::new(self)
     }
+
+    /// Wraps this policy in an `Arc` as a trait object, the form
+    /// [`AndPolicy::try_new`] and [`OrPolicy::try_new`] take.
+    fn arced(self) -> Arc<dyn Policy<D>> {
+        arc_policy::<D, _>(self)
+    }
+
+    /// Lowers this policy tree into a [`CompiledPolicy`] that evaluates the
+    /// whole tree in a single future.
+    fn compile(self) -> CompiledPolicy<D> {
+        CompiledPolicy::new(arc_policy::<D, _>(self))
+    }
+
+    /// Evaluates this policy on its own and reports the decision as an
+    /// [`AccessEvaluation`] whose trace root is the policy's result.
+    ///
+    /// Saves building a one-policy [`crate::PermissionChecker`] for simple
+    /// checks. The result is taken as the policy returns it; a checker
+    /// additionally enforces each policy's declared [`Policy::effect`].
+    fn check<'a>(
+        &'a self,
+        session: &'a EvaluationSession,
+        subject: &'a D::Subject,
+        action: &'a D::Action,
+        resource: &'a D::Resource,
+        context: &'a D::Context,
+    ) -> impl Future<Output = AccessEvaluation> + Send + 'a {
+        async move {
+            let policy_type = self.policy_type();
+            let ctx = EvalCtx {
+                session,
+                subject,
+                action,
+                resource,
+                context,
+                policy_type: policy_type.clone(),
+            };
+            let result = self.evaluate(&ctx).await;
+            let reason = result.reason();
+            if result.is_granted() {
+                return AccessEvaluation::Granted {
+                    policy_type,
+                    reason,
+                    trace: EvalTrace::with_root(result),
+                };
+            }
+            let reason = match result.forbidden_leaf() {
+                Some((forbid_policy_type, forbid_reason)) => {
+                    forbid_summary(forbid_policy_type, forbid_reason)
+                }
+                None => not_granted_summary(&policy_type, reason.as_deref()),
+            };
+            AccessEvaluation::Denied {
+                trace: EvalTrace::with_root(result),
+                reason,
+            }
+        }
+    }
 }
 
 impl<D, P> PolicyExt<D> for P
@@ -82,9 +261,14 @@ where
 }
 
 /// Combines multiple policies with logical AND semantics.
+///
+/// Evaluation stops at the first child that does not grant. Call
+/// [`Self::exhaustive`] to evaluate every child instead, so the trace lists
+/// every failing requirement at once.
 pub struct AndPolicy<D: PolicyDomain> {
     policies: Vec<Arc<dyn Policy<D>>>,
     veto_capable_count: usize,
+    exhaustive: bool,
 }
 
 /// Error returned when no policies are provided to a combinator policy.
@@ -99,12 +283,58 @@ impl std::fmt::Display for EmptyPoliciesError {
 
 impl std::error::Error for EmptyPoliciesError {}
 
+/// Collects policies for an [`AndPolicy`] or [`OrPolicy`] without wrapping
+/// each one in an `Arc` by hand.
+///
+/// Start one with [`AndPolicy::builder`] or [`OrPolicy::builder`].
+pub struct CombinatorBuilder<D: PolicyDomain, C> {
+    policies: Vec<Arc<dyn Policy<D>>>,
+    _combinator: PhantomData<fn() -> C>,
+}
+
+impl<D: PolicyDomain, C> CombinatorBuilder<D, C> {
+    fn new() -> Self {
+        Self {
+            policies: Vec::new(),
+            _combinator: PhantomData,
+        }
+    }
+
+    /// Adds a policy.
+    pub fn with(self, policy: impl Policy<D> + 'static) -> Self {
+        self.with_arc(arc_policy::<D, _>(policy))
+    }
+
+    /// Adds a shared policy.
+    pub fn with_arc(mut self, policy: Arc<dyn Policy<D>>) -> Self {
+        self.policies.push(policy);
+        self
+    }
+}
+
+impl<D: PolicyDomain> CombinatorBuilder<D, AndPolicy<D>> {
+    /// Finishes the `AndPolicy`, failing like [`AndPolicy::try_new`] when no
+    /// policy was added.
+    pub fn build(self) -> Result<AndPolicy<D>, EmptyPoliciesError> {
+        AndPolicy::try_new(self.policies)
+    }
+}
+
+impl<D: PolicyDomain> CombinatorBuilder<D, OrPolicy<D>> {
+    /// Finishes the `OrPolicy`, failing like [`OrPolicy::try_new`] when no
+    /// policy was added.
+    pub fn build(self) -> Result<OrPolicy<D>, EmptyPoliciesError> {
+        OrPolicy::try_new(self.policies)
+    }
+}
+
 impl<D: PolicyDomain> AndPolicy<D> {
     fn from_policies(policies: Vec<Arc<dyn Policy<D>>>) -> Self {
         let (policies, veto_capable_count) = ordered_policies(policies);
         Self {
             policies,
             veto_capable_count,
+            exhaustive: false,
         }
     }
 
@@ -118,6 +348,82 @@ impl<D: PolicyDomain> AndPolicy<D> {
             Ok(Self::from_policies(policies))
         }
     }
+
+    /// Starts an `AndPolicy` that takes policies by value.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// # struct Docs;
+    /// # impl PolicyDomain for Docs {
+    /// #     type Subject = bool;
+    /// #     type Action = ();
+    /// #     type Resource = ();
+    /// #     type Context = ();
+    /// # }
+    /// let policy = AndPolicy::<Docs>::builder()
+    ///     .with(PolicyBuilder::<Docs>::new("Active").subjects(|active| *active).build())
+    ///     .with(PolicyBuilder::<Docs>::new("Everyone").build())
+    ///     .build()
+    ///     .expect("at least one policy");
+    /// ```
+    pub fn builder() -> CombinatorBuilder<D, Self> {
+        CombinatorBuilder::new()
+    }
+
+    /// Evaluates every child instead of stopping at the first that does not
+    /// grant, for form-validation-style feedback that reports all failing
+    /// requirements together.
+    ///
+    /// The decision is unchanged; only the trace grows to include every
+    /// child. An exhaustive `AndPolicy` reports no [`Policy::composition`],
+    /// so [`PolicyExt::compile`] and annotated trees treat it as a single
+    /// node rather than lowering it to a short-circuiting AND.
+    pub fn exhaustive(mut self) -> Self {
+        self.exhaustive = true;
+        self
+    }
+
+    fn exhaustive_result(&self, children: Vec<PolicyEvalResult>) -> PolicyEvalResult {
+        let outcome = children.iter().all(PolicyEvalResult::is_granted);
+        PolicyEvalResult::Combined {
+            policy_type: self.policy_type(),
+            policy_id: None,
+            operation: CombineOp::And,
+            children,
+            outcome,
+        }
+    }
+
+    async fn evaluate_batch_exhaustive(&self, ctx: &BatchEvalCtx<'_, D>) -> Vec<PolicyEvalResult> {
+        let mut children_by_item = vec![Vec::with_capacity(self.policies.len()); ctx.items.len()];
+        for policy in &self.policies {
+            let batch_ctx = BatchEvalCtx {
+                session: ctx.session,
+                subject: ctx.subject,
+                action: ctx.action,
+                context: ctx.context,
+                items: ctx.items,
+                policy_type: policy.policy_type(),
+            };
+            let child_results = policy.evaluate_batch(&batch_ctx).await;
+            if child_results.len() != ctx.items.len() {
+                for children in &mut children_by_item {
+                    children.push(PolicyEvalResult::not_applicable(
+                        policy.policy_type(),
+                        "Policy batch result count did not match input count",
+                    ));
+                }
+                continue;
+            }
+            for (children, child_result) in children_by_item.iter_mut().zip(child_results) {
+                children.push(child_result);
+            }
+        }
+        children_by_item
+            .into_iter()
+            .map(|children| self.exhaustive_result(children))
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -126,6 +432,17 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
         std::borrow::Cow::Borrowed("AndPolicy")
     }
 
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        if self.exhaustive {
+            return None;
+        }
+        Some(PolicyComposition {
+            operation: CombineOp::And,
+            children: &self.policies,
+            policy_id: None,
+        })
+    }
+
     fn effect(&self) -> Effect {
         let can_grant = self
             .policies
@@ -134,10 +451,38 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
         Effect::from_capabilities(can_grant, any_child_can_forbid(&self.policies))
     }
 
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        for policy in &self.policies {
+            policy.declared_facts(subject, action, resource, context, facts);
+        }
+    }
+
     async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
         let mut children_results = Vec::with_capacity(self.policies.len());
         let mut veto_prefix_failed = false;
 
+        if self.exhaustive {
+            for policy in &self.policies {
+                let inner_ctx = EvalCtx {
+                    session: ctx.session,
+                    subject: ctx.subject,
+                    action: ctx.action,
+                    resource: ctx.resource,
+                    context: ctx.context,
+                    policy_type: policy.policy_type(),
+                };
+                children_results.push(evaluate_in_span(policy.as_ref(), &inner_ctx).await);
+            }
+            return self.exhaustive_result(children_results);
+        }
+
         for (policy_index, policy) in self.policies.iter().enumerate() {
             let inner_ctx = EvalCtx {
                 session: ctx.session,
@@ -147,7 +492,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
                 context: ctx.context,
                 policy_type: policy.policy_type(),
             };
-            let result = policy.evaluate(&inner_ctx).await;
+            let result = evaluate_in_span(policy.as_ref(), &inner_ctx).await;
             let is_granted = result.is_granted();
             let is_forbidden = result.is_forbidden();
             children_results.push(result);
@@ -155,6 +500,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
             if is_forbidden {
                 return PolicyEvalResult::Combined {
                     policy_type: self.policy_type(),
+                    policy_id: None,
                     operation: CombineOp::And,
                     children: children_results,
                     outcome: false,
@@ -166,6 +512,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
                 if policy_index + 1 == self.veto_capable_count && veto_prefix_failed {
                     return PolicyEvalResult::Combined {
                         policy_type: self.policy_type(),
+                        policy_id: None,
                         operation: CombineOp::And,
                         children: children_results,
                         outcome: false,
@@ -174,6 +521,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
             } else if !is_granted {
                 return PolicyEvalResult::Combined {
                     policy_type: self.policy_type(),
+                    policy_id: None,
                     operation: CombineOp::And,
                     children: children_results,
                     outcome: false,
@@ -183,6 +531,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
 
         PolicyEvalResult::Combined {
             policy_type: self.policy_type(),
+            policy_id: None,
             operation: CombineOp::And,
             children: children_results,
             outcome: true,
@@ -190,6 +539,9 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
     }
 
     async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
+        if self.exhaustive {
+            return self.evaluate_batch_exhaustive(ctx).await;
+        }
         let mut children_by_item = vec![Vec::new(); ctx.items.len()];
         let mut results = vec![None; ctx.items.len()];
         let mut pending = (0..ctx.items.len()).collect::<Vec<_>>();
@@ -224,6 +576,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
                     ));
                     results[index] = Some(PolicyEvalResult::Combined {
                         policy_type: self.policy_type(),
+                        policy_id: None,
                         operation: CombineOp::And,
                         children: std::mem::take(&mut children_by_item[index]),
                         outcome: false,
@@ -241,6 +594,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
                 if is_forbidden {
                     results[index] = Some(PolicyEvalResult::Combined {
                         policy_type: self.policy_type(),
+                        policy_id: None,
                         operation: CombineOp::And,
                         children: std::mem::take(&mut children_by_item[index]),
                         outcome: false,
@@ -250,6 +604,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
                     if policy_index + 1 == self.veto_capable_count && veto_prefix_failed[index] {
                         results[index] = Some(PolicyEvalResult::Combined {
                             policy_type: self.policy_type(),
+                            policy_id: None,
                             operation: CombineOp::And,
                             children: std::mem::take(&mut children_by_item[index]),
                             outcome: false,
@@ -262,6 +617,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
                 } else {
                     results[index] = Some(PolicyEvalResult::Combined {
                         policy_type: self.policy_type(),
+                        policy_id: None,
                         operation: CombineOp::And,
                         children: std::mem::take(&mut children_by_item[index]),
                         outcome: false,
@@ -274,6 +630,7 @@ impl<D: PolicyDomain> Policy<D> for AndPolicy<D> {
         for index in pending {
             results[index] = Some(PolicyEvalResult::Combined {
                 policy_type: self.policy_type(),
+                policy_id: None,
                 operation: CombineOp::And,
                 children: std::mem::take(&mut children_by_item[index]),
                 outcome: true,
@@ -317,6 +674,12 @@ impl<D: PolicyDomain> OrPolicy<D> {
             Ok(Self::from_policies(policies))
         }
     }
+
+    /// Starts an `OrPolicy` that takes policies by value. See
+    /// [`AndPolicy::builder`].
+    pub fn builder() -> CombinatorBuilder<D, Self> {
+        CombinatorBuilder::new()
+    }
 }
 
 #[async_trait]
@@ -325,6 +688,14 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
         std::borrow::Cow::Borrowed("OrPolicy")
     }
 
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        Some(PolicyComposition {
+            operation: CombineOp::Or,
+            children: &self.policies,
+            policy_id: None,
+        })
+    }
+
     fn effect(&self) -> Effect {
         let can_grant = self
             .policies
@@ -333,6 +704,19 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
         Effect::from_capabilities(can_grant, any_child_can_forbid(&self.policies))
     }
 
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        for policy in &self.policies {
+            policy.declared_facts(subject, action, resource, context, facts);
+        }
+    }
+
     async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
         let mut children_results = Vec::with_capacity(self.policies.len());
         let mut veto_prefix_granted = false;
@@ -346,7 +730,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
                 context: ctx.context,
                 policy_type: policy.policy_type(),
             };
-            let result = policy.evaluate(&inner_ctx).await;
+            let result = evaluate_in_span(policy.as_ref(), &inner_ctx).await;
             let is_granted = result.is_granted();
             let is_forbidden = result.is_forbidden();
             children_results.push(result);
@@ -354,6 +738,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
             if is_forbidden {
                 return PolicyEvalResult::Combined {
                     policy_type: self.policy_type(),
+                    policy_id: None,
                     operation: CombineOp::Or,
                     children: children_results,
                     outcome: false,
@@ -365,6 +750,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
                 if policy_index + 1 == self.veto_capable_count && veto_prefix_granted {
                     return PolicyEvalResult::Combined {
                         policy_type: self.policy_type(),
+                        policy_id: None,
                         operation: CombineOp::Or,
                         children: children_results,
                         outcome: true,
@@ -373,6 +759,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
             } else if is_granted {
                 return PolicyEvalResult::Combined {
                     policy_type: self.policy_type(),
+                    policy_id: None,
                     operation: CombineOp::Or,
                     children: children_results,
                     outcome: true,
@@ -382,6 +769,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
 
         PolicyEvalResult::Combined {
             policy_type: self.policy_type(),
+            policy_id: None,
             operation: CombineOp::Or,
             children: children_results,
             outcome: false,
@@ -423,6 +811,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
                     ));
                     results[index] = Some(PolicyEvalResult::Combined {
                         policy_type: self.policy_type(),
+                        policy_id: None,
                         operation: CombineOp::Or,
                         children: std::mem::take(&mut children_by_item[index]),
                         outcome: false,
@@ -440,6 +829,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
                 if is_forbidden {
                     results[index] = Some(PolicyEvalResult::Combined {
                         policy_type: self.policy_type(),
+                        policy_id: None,
                         operation: CombineOp::Or,
                         children: std::mem::take(&mut children_by_item[index]),
                         outcome: false,
@@ -449,6 +839,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
                     if policy_index + 1 == self.veto_capable_count && veto_prefix_granted[index] {
                         results[index] = Some(PolicyEvalResult::Combined {
                             policy_type: self.policy_type(),
+                            policy_id: None,
                             operation: CombineOp::Or,
                             children: std::mem::take(&mut children_by_item[index]),
                             outcome: true,
@@ -459,6 +850,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
                 } else if is_granted {
                     results[index] = Some(PolicyEvalResult::Combined {
                         policy_type: self.policy_type(),
+                        policy_id: None,
                         operation: CombineOp::Or,
                         children: std::mem::take(&mut children_by_item[index]),
                         outcome: true,
@@ -473,6 +865,7 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
         for index in pending {
             results[index] = Some(PolicyEvalResult::Combined {
                 policy_type: self.policy_type(),
+                policy_id: None,
                 operation: CombineOp::Or,
                 children: std::mem::take(&mut children_by_item[index]),
                 outcome: false,
@@ -494,6 +887,9 @@ impl<D: PolicyDomain> Policy<D> for OrPolicy<D> {
 }
 
 /// Inverts the decision of an inner policy.
+///
+/// An inner forbid or an inner [`PolicyEvalResult::Indeterminate`] does not
+/// invert into a grant: negating "couldn't decide" still fails closed.
 pub struct NotPolicy<D: PolicyDomain> {
     policy: Arc<dyn Policy<D>>,
 }
@@ -513,10 +909,30 @@ impl<D: PolicyDomain> Policy<D> for NotPolicy<D> {
         std::borrow::Cow::Borrowed("NotPolicy")
     }
 
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        Some(PolicyComposition {
+            operation: CombineOp::Not,
+            children: std::slice::from_ref(&self.policy),
+            policy_id: None,
+        })
+    }
+
     fn effect(&self) -> Effect {
         Effect::from_capabilities(true, self.policy.effect().can_forbid())
     }
 
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        self.policy
+            .declared_facts(subject, action, resource, context, facts);
+    }
+
     async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
         let inner_ctx = EvalCtx {
             session: ctx.session,
@@ -526,15 +942,17 @@ impl<D: PolicyDomain> Policy<D> for NotPolicy<D> {
             context: ctx.context,
             policy_type: self.policy.policy_type(),
         };
-        let inner_result = self.policy.evaluate(&inner_ctx).await;
+        let inner_result = evaluate_in_span(self.policy.as_ref(), &inner_ctx).await;
         let is_forbidden = inner_result.is_forbidden();
         let is_granted = inner_result.is_granted();
+        let is_indeterminate = inner_result.is_indeterminate();
 
         PolicyEvalResult::Combined {
             policy_type: Policy::<D>::policy_type(self),
+            policy_id: None,
             operation: CombineOp::Not,
             children: vec![inner_result],
-            outcome: !is_forbidden && !is_granted,
+            outcome: !is_forbidden && !is_granted && !is_indeterminate,
         }
     }
 
@@ -567,13 +985,833 @@ impl<D: PolicyDomain> Policy<D> for NotPolicy<D> {
             .map(|inner_result| {
                 let is_forbidden = inner_result.is_forbidden();
                 let is_granted = inner_result.is_granted();
+                let is_indeterminate = inner_result.is_indeterminate();
                 PolicyEvalResult::Combined {
                     policy_type: self.policy_type(),
+                    policy_id: None,
                     operation: CombineOp::Not,
                     children: vec![inner_result],
-                    outcome: !is_forbidden && !is_granted,
+                    outcome: !is_forbidden && !is_granted && !is_indeterminate,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Grants when at least one and at most `max_grants` inner policies grant.
+///
+/// This is the dual of a threshold ("at least *k* must grant") combinator:
+/// it catches mutually exclusive grants, where more than one privileged path
+/// applying at once is a misconfiguration that should deny rather than
+/// silently allow. Unlike a literal "not more than N" count, it does not grant
+/// when no inner policy grants; a combinator that grants whenever nothing
+/// applies would grant every unrelated request.
+///
+/// A forbidding child denies, and evaluation stops as soon as the grant count
+/// exceeds `max_grants`. When the count is exactly `max_grants` and a child
+/// is [`PolicyEvalResult::Indeterminate`], it denies, since that child's
+/// grant would have exceeded the limit.
+pub struct AtMostPolicy<D: PolicyDomain> {
+    max_grants: NonZeroUsize,
+    policies: Vec<Arc<dyn Policy<D>>>,
+}
+
+impl<D: PolicyDomain> AtMostPolicy<D> {
+    /// Creates a new `AtMostPolicy` from a grant limit and a non-empty list of
+    /// policies.
+    pub fn try_new(
+        max_grants: NonZeroUsize,
+        policies: Vec<Arc<dyn Policy<D>>>,
+    ) -> Result<Self, EmptyPoliciesError> {
+        if policies.is_empty() {
+            return Err(EmptyPoliciesError(
+                "AtMostPolicy must have at least one policy",
+            ));
+        }
+        let (policies, _veto_capable_count) = ordered_policies(policies);
+        Ok(Self {
+            max_grants,
+            policies,
+        })
+    }
+
+    fn combined(&self, children: Vec<PolicyEvalResult>, outcome: bool) -> PolicyEvalResult {
+        PolicyEvalResult::Combined {
+            policy_type: self.policy_type(),
+            policy_id: None,
+            operation: CombineOp::AtMost {
+                max_grants: self.max_grants.get(),
+            },
+            children,
+            outcome,
+        }
+    }
+
+    /// The outcome once every child has run without exceeding the limit.
+    fn final_outcome(&self, grant_count: usize, any_indeterminate: bool) -> bool {
+        grant_count > 0 && !(any_indeterminate && grant_count == self.max_grants.get())
+    }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for AtMostPolicy<D> {
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("AtMostPolicy")
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        Some(PolicyComposition {
+            operation: CombineOp::AtMost {
+                max_grants: self.max_grants.get(),
+            },
+            children: &self.policies,
+            policy_id: None,
+        })
+    }
+
+    fn effect(&self) -> Effect {
+        let can_grant = self
+            .policies
+            .iter()
+            .any(|policy| policy.effect().can_grant());
+        Effect::from_capabilities(can_grant, any_child_can_forbid(&self.policies))
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        for policy in &self.policies {
+            policy.declared_facts(subject, action, resource, context, facts);
+        }
+    }
+
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let mut children_results = Vec::with_capacity(self.policies.len());
+        let mut grant_count = 0usize;
+        let mut any_indeterminate = false;
+
+        for policy in &self.policies {
+            let inner_ctx = EvalCtx {
+                session: ctx.session,
+                subject: ctx.subject,
+                action: ctx.action,
+                resource: ctx.resource,
+                context: ctx.context,
+                policy_type: policy.policy_type(),
+            };
+            let result = evaluate_in_span(policy.as_ref(), &inner_ctx).await;
+            let is_granted = result.is_granted();
+            let is_forbidden = result.is_forbidden();
+            any_indeterminate |= result.is_indeterminate();
+            children_results.push(result);
+
+            if is_forbidden {
+                return self.combined(children_results, false);
+            }
+            if is_granted {
+                grant_count += 1;
+                if grant_count > self.max_grants.get() {
+                    return self.combined(children_results, false);
+                }
+            }
+        }
+
+        let outcome = self.final_outcome(grant_count, any_indeterminate);
+        self.combined(children_results, outcome)
+    }
+
+    async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
+        let mut children_by_item = vec![Vec::new(); ctx.items.len()];
+        let mut grant_counts = vec![0usize; ctx.items.len()];
+        let mut any_indeterminate = vec![false; ctx.items.len()];
+        let mut results = vec![None; ctx.items.len()];
+        let mut pending = (0..ctx.items.len()).collect::<Vec<_>>();
+
+        for policy in &self.policies {
+            if pending.is_empty() {
+                break;
+            }
+
+            let batch_items = pending
+                .iter()
+                .map(|&index| PolicyBatchItem {
+                    resource: ctx.items[index].resource,
+                })
+                .collect::<Vec<_>>();
+            let batch_ctx = BatchEvalCtx {
+                session: ctx.session,
+                subject: ctx.subject,
+                action: ctx.action,
+                context: ctx.context,
+                items: &batch_items,
+                policy_type: policy.policy_type(),
+            };
+            let child_results = policy.evaluate_batch(&batch_ctx).await;
+
+            if child_results.len() != pending.len() {
+                for index in pending.drain(..) {
+                    children_by_item[index].push(PolicyEvalResult::not_applicable(
+                        policy.policy_type(),
+                        "Policy batch result count did not match input count",
+                    ));
+                    results[index] =
+                        Some(self.combined(std::mem::take(&mut children_by_item[index]), false));
                 }
+                break;
+            }
+
+            let mut still_pending = Vec::new();
+            for (index, child_result) in pending.into_iter().zip(child_results) {
+                let is_granted = child_result.is_granted();
+                let is_forbidden = child_result.is_forbidden();
+                any_indeterminate[index] |= child_result.is_indeterminate();
+                children_by_item[index].push(child_result);
+
+                if is_granted {
+                    grant_counts[index] += 1;
+                }
+                if is_forbidden || grant_counts[index] > self.max_grants.get() {
+                    results[index] =
+                        Some(self.combined(std::mem::take(&mut children_by_item[index]), false));
+                } else {
+                    still_pending.push(index);
+                }
+            }
+            pending = still_pending;
+        }
+
+        for index in pending {
+            let outcome = self.final_outcome(grant_counts[index], any_indeterminate[index]);
+            results[index] =
+                Some(self.combined(std::mem::take(&mut children_by_item[index]), outcome));
+        }
+
+        results
+            .into_iter()
+            .map(|result| {
+                result.unwrap_or_else(|| {
+                    PolicyEvalResult::not_applicable(
+                        self.policy_type(),
+                        "Batch item was not evaluated",
+                    )
+                })
             })
             .collect()
     }
 }
+
+/// Evaluates tiers of policies from highest priority to lowest, letting the
+/// first tier that reaches a decision settle the outcome.
+///
+/// Use this for layered governance where organization rules override team
+/// rules, which override personal rules. Precedence is:
+///
+/// 1. Tiers are evaluated in the order given, the first tier being the
+///    highest priority. Lower tiers are not evaluated once a tier decides.
+/// 2. Within a tier, a forbid beats a grant (deny-overrides). A tier that
+///    forbids settles the outcome as a denial, so a lower tier's grant cannot
+///    override it.
+/// 3. Otherwise a tier with any grant settles the outcome as a grant, so a
+///    lower tier's forbid cannot override it.
+/// 4. A tier with neither a grant nor a forbid defers to the next tier,
+///    except that a tier with an [`PolicyEvalResult::Indeterminate`] result
+///    denies instead: its undecided policy might have forbidden.
+/// 5. When every tier defers, the policy does not grant.
+///
+/// Each tier appears in the trace as its own `DENY_OVERRIDES` node named
+/// `Tier 1`, `Tier 2`, and so on, under a `TIERED` node.
+pub struct TieredPolicy<D: PolicyDomain> {
+    tiers: Vec<Arc<dyn Policy<D>>>,
+}
+
+impl<D: PolicyDomain> TieredPolicy<D> {
+    /// Creates a new `TieredPolicy` from a non-empty list of non-empty tiers,
+    /// highest priority first.
+    pub fn try_new(tiers: Vec<Vec<Arc<dyn Policy<D>>>>) -> Result<Self, EmptyPoliciesError> {
+        if tiers.is_empty() || tiers.iter().any(Vec::is_empty) {
+            return Err(EmptyPoliciesError(
+                "TieredPolicy must have at least one tier, and every tier at least one policy",
+            ));
+        }
+        let tiers = tiers
+            .into_iter()
+            .enumerate()
+            .map(|(index, policies)| {
+                let (policies, veto_capable_count) = ordered_policies(policies);
+                Arc::new(Tier {
+                    name: format!("Tier {}", index + 1).into(),
+                    policies,
+                    veto_capable_count,
+                }) as Arc<dyn Policy<D>>
+            })
+            .collect();
+        Ok(Self { tiers })
+    }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for TieredPolicy<D> {
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("TieredPolicy")
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        Some(PolicyComposition {
+            operation: CombineOp::Tiered,
+            children: &self.tiers,
+            policy_id: None,
+        })
+    }
+
+    fn effect(&self) -> Effect {
+        let can_grant = self.tiers.iter().any(|tier| tier.effect().can_grant());
+        Effect::from_capabilities(can_grant, any_child_can_forbid(&self.tiers))
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        for tier in &self.tiers {
+            tier.declared_facts(subject, action, resource, context, facts);
+        }
+    }
+
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let mut children_results = Vec::with_capacity(self.tiers.len());
+        let mut outcome = false;
+
+        for tier in &self.tiers {
+            let inner_ctx = EvalCtx {
+                session: ctx.session,
+                subject: ctx.subject,
+                action: ctx.action,
+                resource: ctx.resource,
+                context: ctx.context,
+                policy_type: tier.policy_type(),
+            };
+            let result = evaluate_in_span(tier.as_ref(), &inner_ctx).await;
+            let is_granted = result.is_granted();
+            let decided = is_granted || result.is_forbidden() || result.is_indeterminate();
+            children_results.push(result);
+
+            if decided {
+                outcome = is_granted;
+                break;
+            }
+        }
+
+        PolicyEvalResult::Combined {
+            policy_type: self.policy_type(),
+            policy_id: None,
+            operation: CombineOp::Tiered,
+            children: children_results,
+            outcome,
+        }
+    }
+}
+
+/// One tier of a [`TieredPolicy`]: deny-overrides over its policies.
+struct Tier<D: PolicyDomain> {
+    name: std::borrow::Cow<'static, str>,
+    policies: Vec<Arc<dyn Policy<D>>>,
+    veto_capable_count: usize,
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for Tier<D> {
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        self.name.clone()
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        Some(PolicyComposition {
+            operation: CombineOp::DenyOverrides,
+            children: &self.policies,
+            policy_id: None,
+        })
+    }
+
+    fn effect(&self) -> Effect {
+        let can_grant = self
+            .policies
+            .iter()
+            .any(|policy| policy.effect().can_grant());
+        Effect::from_capabilities(can_grant, any_child_can_forbid(&self.policies))
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        for policy in &self.policies {
+            policy.declared_facts(subject, action, resource, context, facts);
+        }
+    }
+
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let mut children_results = Vec::with_capacity(self.policies.len());
+        let mut granted = false;
+
+        for (policy_index, policy) in self.policies.iter().enumerate() {
+            let inner_ctx = EvalCtx {
+                session: ctx.session,
+                subject: ctx.subject,
+                action: ctx.action,
+                resource: ctx.resource,
+                context: ctx.context,
+                policy_type: policy.policy_type(),
+            };
+            let result = evaluate_in_span(policy.as_ref(), &inner_ctx).await;
+            let is_forbidden = result.is_forbidden();
+            granted |= result.is_granted();
+            children_results.push(result);
+
+            if is_forbidden {
+                granted = false;
+                break;
+            }
+            if granted && policy_index + 1 >= self.veto_capable_count {
+                break;
+            }
+        }
+
+        PolicyEvalResult::Combined {
+            policy_type: self.name.clone(),
+            policy_id: None,
+            operation: CombineOp::DenyOverrides,
+            children: children_results,
+            outcome: granted,
+        }
+    }
+}
+
+/// Error returned when a [`ThresholdPolicy`] threshold cannot be met by its
+/// policies.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct InvalidThresholdError {
+    /// The requested number of grants.
+    pub threshold: usize,
+    /// The number of policies supplied.
+    pub policy_count: usize,
+}
+
+impl std::fmt::Display for InvalidThresholdError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ThresholdPolicy threshold {} exceeds policy count {}",
+            self.threshold, self.policy_count
+        )
+    }
+}
+
+impl std::error::Error for InvalidThresholdError {}
+
+/// Grants when at least `threshold` of its inner policies grant.
+///
+/// Use this for N-of-M approval flows such as dual control ("at least 2 of
+/// these 3 approvers"). A forbidding child denies. Veto-capable children are
+/// evaluated first; after them, evaluation stops as soon as the threshold is
+/// reached or can no longer be reached. Traces show
+/// [`CombineOp::Threshold`] (`THRESHOLD(n/m)`).
+pub struct ThresholdPolicy<D: PolicyDomain> {
+    threshold: NonZeroUsize,
+    policies: Vec<Arc<dyn Policy<D>>>,
+    veto_capable_count: usize,
+}
+
+impl<D: PolicyDomain> ThresholdPolicy<D> {
+    /// Creates a new `ThresholdPolicy`. Fails when `threshold` is larger than
+    /// the number of policies, since the policy could never grant.
+    pub fn try_new(
+        threshold: NonZeroUsize,
+        policies: Vec<Arc<dyn Policy<D>>>,
+    ) -> Result<Self, InvalidThresholdError> {
+        if threshold.get() > policies.len() {
+            return Err(InvalidThresholdError {
+                threshold: threshold.get(),
+                policy_count: policies.len(),
+            });
+        }
+        let (policies, veto_capable_count) = ordered_policies(policies);
+        Ok(Self {
+            threshold,
+            policies,
+            veto_capable_count,
+        })
+    }
+
+    fn operation(&self) -> CombineOp {
+        CombineOp::Threshold {
+            threshold: self.threshold.get(),
+            of: self.policies.len(),
+        }
+    }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for ThresholdPolicy<D> {
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("ThresholdPolicy")
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        Some(PolicyComposition {
+            operation: self.operation(),
+            children: &self.policies,
+            policy_id: None,
+        })
+    }
+
+    fn effect(&self) -> Effect {
+        let grant_capable = self
+            .policies
+            .iter()
+            .filter(|policy| policy.effect().can_grant())
+            .count();
+        Effect::from_capabilities(
+            grant_capable >= self.threshold.get(),
+            any_child_can_forbid(&self.policies),
+        )
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        for policy in &self.policies {
+            policy.declared_facts(subject, action, resource, context, facts);
+        }
+    }
+
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let threshold = self.threshold.get();
+        let mut children_results = Vec::with_capacity(self.policies.len());
+        let mut grant_count = 0usize;
+        let mut outcome = None;
+
+        for (policy_index, policy) in self.policies.iter().enumerate() {
+            let inner_ctx = EvalCtx {
+                session: ctx.session,
+                subject: ctx.subject,
+                action: ctx.action,
+                resource: ctx.resource,
+                context: ctx.context,
+                policy_type: policy.policy_type(),
+            };
+            let result = evaluate_in_span(policy.as_ref(), &inner_ctx).await;
+            let is_forbidden = result.is_forbidden();
+            if result.is_granted() {
+                grant_count += 1;
+            }
+            children_results.push(result);
+
+            if is_forbidden {
+                outcome = Some(false);
+                break;
+            }
+            if policy_index + 1 < self.veto_capable_count {
+                continue;
+            }
+            let remaining = self.policies.len() - policy_index - 1;
+            if grant_count >= threshold {
+                outcome = Some(true);
+                break;
+            }
+            if grant_count + remaining < threshold {
+                outcome = Some(false);
+                break;
+            }
+        }
+
+        PolicyEvalResult::Combined {
+            policy_type: self.policy_type(),
+            policy_id: None,
+            operation: self.operation(),
+            children: children_results,
+            outcome: outcome.unwrap_or(grant_count >= threshold),
+        }
+    }
+}
+
+/// How many inner grants an [`XorPolicy`] accepts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum XorMode {
+    /// Exactly one inner policy must grant.
+    #[default]
+    ExactlyOne,
+    /// An odd number of inner policies must grant (chained boolean XOR).
+    Odd,
+}
+
+/// Grants when the inner policies' grants are mutually exclusive.
+///
+/// In [`XorMode::ExactlyOne`] it grants when exactly one inner policy grants
+/// and stops as soon as a second grant appears. In [`XorMode::Odd`] it grants
+/// when an odd number of inner policies grant, which needs every child. A
+/// forbidding child denies, and veto-capable children are evaluated first.
+/// A child with a [`PolicyEvalResult::Indeterminate`] result also denies,
+/// since its grant would change the count. Traces show [`CombineOp::Xor`]
+/// (`XOR`, or `XOR(ODD)`) with every evaluated child, so a caller can report
+/// how many grants were seen.
+pub struct XorPolicy<D: PolicyDomain> {
+    mode: XorMode,
+    policies: Vec<Arc<dyn Policy<D>>>,
+    veto_capable_count: usize,
+}
+
+impl<D: PolicyDomain> XorPolicy<D> {
+    /// Creates a new `XorPolicy` from a mode and at least two policies.
+    pub fn try_new(
+        mode: XorMode,
+        policies: Vec<Arc<dyn Policy<D>>>,
+    ) -> Result<Self, EmptyPoliciesError> {
+        if policies.len() < 2 {
+            return Err(EmptyPoliciesError(
+                "XorPolicy must have at least two policies",
+            ));
+        }
+        let (policies, veto_capable_count) = ordered_policies(policies);
+        Ok(Self {
+            mode,
+            policies,
+            veto_capable_count,
+        })
+    }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for XorPolicy<D> {
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("XorPolicy")
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        Some(PolicyComposition {
+            operation: CombineOp::Xor { mode: self.mode },
+            children: &self.policies,
+            policy_id: None,
+        })
+    }
+
+    fn effect(&self) -> Effect {
+        let can_grant = self
+            .policies
+            .iter()
+            .any(|policy| policy.effect().can_grant());
+        Effect::from_capabilities(can_grant, any_child_can_forbid(&self.policies))
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        for policy in &self.policies {
+            policy.declared_facts(subject, action, resource, context, facts);
+        }
+    }
+
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let mut children_results = Vec::with_capacity(self.policies.len());
+        let mut grant_count = 0usize;
+        let mut any_indeterminate = false;
+        let mut outcome = None;
+
+        for (policy_index, policy) in self.policies.iter().enumerate() {
+            let inner_ctx = EvalCtx {
+                session: ctx.session,
+                subject: ctx.subject,
+                action: ctx.action,
+                resource: ctx.resource,
+                context: ctx.context,
+                policy_type: policy.policy_type(),
+            };
+            let result = evaluate_in_span(policy.as_ref(), &inner_ctx).await;
+            let is_forbidden = result.is_forbidden();
+            if result.is_granted() {
+                grant_count += 1;
+            }
+            any_indeterminate |= result.is_indeterminate();
+            children_results.push(result);
+
+            if is_forbidden {
+                outcome = Some(false);
+                break;
+            }
+            if self.mode == XorMode::ExactlyOne
+                && grant_count > 1
+                && policy_index + 1 >= self.veto_capable_count
+            {
+                outcome = Some(false);
+                break;
+            }
+        }
+
+        // One more grant from an undecided child would flip either mode.
+        let outcome = outcome.unwrap_or(match self.mode {
+            _ if any_indeterminate => false,
+            XorMode::ExactlyOne => grant_count == 1,
+            XorMode::Odd => grant_count % 2 == 1,
+        });
+        PolicyEvalResult::Combined {
+            policy_type: self.policy_type(),
+            policy_id: None,
+            operation: CombineOp::Xor { mode: self.mode },
+            children: children_results,
+            outcome,
+        }
+    }
+}
+
+/// Grants on the first inner grant whose weight reaches `min_weight`.
+///
+/// Each inner policy carries a weight, such as the strength of a risk
+/// signal. Unlike [`OrPolicy`], a grant from a policy weighted below
+/// `min_weight` is recorded in the trace but does not decide, and evaluation
+/// moves on. A forbidding child denies regardless of its weight. A `NaN`
+/// weight never reaches the floor. Veto-capable children are evaluated first.
+/// Traces show [`CombineOp::WeightedOr`] (`WEIGHTED_OR`).
+pub struct WeightedOrPolicy<D: PolicyDomain> {
+    policies: Vec<Arc<dyn Policy<D>>>,
+    weights: Vec<f32>,
+    min_weight: f32,
+    veto_capable_count: usize,
+}
+
+impl<D: PolicyDomain> WeightedOrPolicy<D> {
+    /// Creates a new `WeightedOrPolicy` from a non-empty list of weighted
+    /// policies.
+    pub fn try_new(
+        policies: Vec<(Arc<dyn Policy<D>>, f32)>,
+        min_weight: f32,
+    ) -> Result<Self, EmptyPoliciesError> {
+        if policies.is_empty() {
+            return Err(EmptyPoliciesError(
+                "WeightedOrPolicy must have at least one policy",
+            ));
+        }
+        let (veto_capable, allow_only): (Vec<_>, Vec<_>) = policies
+            .into_iter()
+            .partition(|(policy, _)| policy.effect().can_forbid());
+        let veto_capable_count = veto_capable.len();
+        let (policies, weights) = veto_capable.into_iter().chain(allow_only).unzip();
+        Ok(Self {
+            policies,
+            weights,
+            min_weight,
+            veto_capable_count,
+        })
+    }
+
+    fn is_strong(&self, policy_index: usize) -> bool {
+        self.weights[policy_index] >= self.min_weight
+    }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for WeightedOrPolicy<D> {
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("WeightedOrPolicy")
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        Some(PolicyComposition {
+            operation: CombineOp::WeightedOr,
+            children: &self.policies,
+            policy_id: None,
+        })
+    }
+
+    fn effect(&self) -> Effect {
+        let can_grant = self
+            .policies
+            .iter()
+            .enumerate()
+            .any(|(policy_index, policy)| {
+                self.is_strong(policy_index) && policy.effect().can_grant()
+            });
+        Effect::from_capabilities(can_grant, any_child_can_forbid(&self.policies))
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        for policy in &self.policies {
+            policy.declared_facts(subject, action, resource, context, facts);
+        }
+    }
+
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let mut children_results = Vec::with_capacity(self.policies.len());
+        let mut veto_prefix_granted = false;
+        let mut outcome = false;
+
+        for (policy_index, policy) in self.policies.iter().enumerate() {
+            let inner_ctx = EvalCtx {
+                session: ctx.session,
+                subject: ctx.subject,
+                action: ctx.action,
+                resource: ctx.resource,
+                context: ctx.context,
+                policy_type: policy.policy_type(),
+            };
+            let result = evaluate_in_span(policy.as_ref(), &inner_ctx).await;
+            let is_strong_grant = result.is_granted() && self.is_strong(policy_index);
+            let is_forbidden = result.is_forbidden();
+            children_results.push(result);
+
+            if is_forbidden {
+                outcome = false;
+                break;
+            }
+            if policy_index < self.veto_capable_count {
+                veto_prefix_granted |= is_strong_grant;
+                if policy_index + 1 == self.veto_capable_count && veto_prefix_granted {
+                    outcome = true;
+                    break;
+                }
+            } else if is_strong_grant {
+                outcome = true;
+                break;
+            }
+        }
+
+        PolicyEvalResult::Combined {
+            policy_type: self.policy_type(),
+            policy_id: None,
+            operation: CombineOp::WeightedOr,
+            children: children_results,
+            outcome,
+        }
+    }
+}
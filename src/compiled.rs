@@ -0,0 +1,338 @@
+//! Single-future evaluation of static combinator trees.
+//!
+//! Each nested [`crate::AndPolicy`], [`crate::OrPolicy`], [`crate::NotPolicy`],
+//! or [`crate::AtMostPolicy`] boxes its own future per evaluation, so a deep
+//! tree pays one allocation and one dynamic dispatch per level.
+//! [`CompiledPolicy`] lowers the tree once into an arena of nodes and walks it
+//! with an explicit stack inside one future, dispatching only to the leaves.
+
+use crate::policy::{evaluate_in_span, policy_span, record_policy_outcome};
+use crate::{
+    BatchEvalCtx, CombineOp, DeclaredFacts, Effect, EvalCtx, Policy, PolicyComposition,
+    PolicyDomain, PolicyEvalResult, SecurityRuleMetadata,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::sync::Arc;
+use tracing::Instrument;
+
+/// A combinator tree lowered for evaluation in a single future.
+///
+/// Built with [`crate::PolicyExt::compile`]. Every node that exposes a
+/// built-in [`Policy::composition`] becomes an arena node; anything else,
+/// including a [`crate::DelegatingPolicy`], stays an opaque leaf evaluated
+/// through its own [`Policy::evaluate`].
+///
+/// Compilation preserves decisions and traces exactly: each combinator node
+/// still produces its own [`PolicyEvalResult::Combined`], with the same
+/// children, short-circuits, and veto-first ordering as the interpreted tree,
+/// and opens the same nested `gatehouse.policy` spans.
+/// Merging nested same-operation combinators (`AND(AND(a, b), c)`) would be
+/// decision-safe but would change the trace shape, so compilation does not do
+/// it.
+///
+/// Batch evaluation delegates to the original tree so set-oriented leaves keep
+/// their batched fact loads.
+pub struct CompiledPolicy<D: PolicyDomain> {
+    original: Arc<dyn Policy<D>>,
+    nodes: Vec<Node<D>>,
+    root: usize,
+}
+
+enum Node<D: PolicyDomain> {
+    Leaf {
+        policy: Arc<dyn Policy<D>>,
+        policy_type: Cow<'static, str>,
+    },
+    Composite(Composite),
+}
+
+struct Composite {
+    kind: CompositeKind,
+    policy_type: Cow<'static, str>,
+    policy_id: Option<Cow<'static, str>>,
+    children: Vec<usize>,
+    veto_capable_count: usize,
+}
+
+#[derive(Clone, Copy)]
+enum CompositeKind {
+    And,
+    Or,
+    Not,
+    AtMost(usize),
+}
+
+struct Frame<'a> {
+    composite: &'a Composite,
+    /// The node's `gatehouse.policy` span. `None` for the root, whose span
+    /// the caller opened.
+    span: Option<tracing::Span>,
+    children: Vec<PolicyEvalResult>,
+    grant_count: usize,
+    any_indeterminate: bool,
+    veto_prefix_flag: bool,
+}
+
+impl Composite {
+    fn operation(&self) -> CombineOp {
+        match self.kind {
+            CompositeKind::And => CombineOp::And,
+            CompositeKind::Or => CombineOp::Or,
+            CompositeKind::Not => CombineOp::Not,
+            CompositeKind::AtMost(max_grants) => CombineOp::AtMost { max_grants },
+        }
+    }
+
+    /// Records one child result and returns the combined outcome once it is
+    /// decided.
+    fn step(&self, frame: &mut Frame<'_>, result: PolicyEvalResult) -> Option<bool> {
+        let policy_index = frame.children.len();
+        let is_granted = result.is_granted();
+        let is_forbidden = result.is_forbidden();
+        let is_indeterminate = result.is_indeterminate();
+        frame.any_indeterminate |= is_indeterminate;
+        frame.children.push(result);
+
+        match self.kind {
+            CompositeKind::And => {
+                if is_forbidden {
+                    return Some(false);
+                }
+                if policy_index < self.veto_capable_count {
+                    frame.veto_prefix_flag |= !is_granted;
+                    if policy_index + 1 == self.veto_capable_count && frame.veto_prefix_flag {
+                        return Some(false);
+                    }
+                } else if !is_granted {
+                    return Some(false);
+                }
+            }
+            CompositeKind::Or => {
+                if is_forbidden {
+                    return Some(false);
+                }
+                if policy_index < self.veto_capable_count {
+                    frame.veto_prefix_flag |= is_granted;
+                    if policy_index + 1 == self.veto_capable_count && frame.veto_prefix_flag {
+                        return Some(true);
+                    }
+                } else if is_granted {
+                    return Some(true);
+                }
+            }
+            CompositeKind::Not => return Some(!is_forbidden && !is_granted && !is_indeterminate),
+            CompositeKind::AtMost(max_grants) => {
+                if is_forbidden {
+                    return Some(false);
+                }
+                if is_granted {
+                    frame.grant_count += 1;
+                    if frame.grant_count > max_grants {
+                        return Some(false);
+                    }
+                }
+            }
+        }
+
+        if frame.children.len() < self.children.len() {
+            return None;
+        }
+        Some(match self.kind {
+            CompositeKind::And => true,
+            CompositeKind::Or | CompositeKind::Not => false,
+            CompositeKind::AtMost(max_grants) => {
+                frame.grant_count > 0
+                    && !(frame.any_indeterminate && frame.grant_count == max_grants)
+            }
+        })
+    }
+}
+
+impl<D: PolicyDomain> CompiledPolicy<D> {
+    pub(crate) fn new(original: Arc<dyn Policy<D>>) -> Self {
+        let mut nodes = Vec::new();
+        let root = lower(&original, &mut nodes);
+        Self {
+            original,
+            nodes,
+            root,
+        }
+    }
+
+    /// Returns the number of combinator nodes in the compiled tree.
+    pub fn combinator_count(&self) -> usize {
+        self.nodes
+            .iter()
+            .filter(|node| matches!(node, Node::Composite(_)))
+            .count()
+    }
+
+    /// Returns the number of opaque leaf policies in the compiled tree.
+    pub fn leaf_count(&self) -> usize {
+        self.nodes.len() - self.combinator_count()
+    }
+}
+
+fn lower<D: PolicyDomain>(policy: &Arc<dyn Policy<D>>, nodes: &mut Vec<Node<D>>) -> usize {
+    let Some((kind, children, policy_id)) = policy.composition().and_then(composite_kind) else {
+        nodes.push(Node::Leaf {
+            policy: Arc::clone(policy),
+            policy_type: policy.policy_type(),
+        });
+        return nodes.len() - 1;
+    };
+
+    let veto_capable_count = children
+        .iter()
+        .take_while(|child| child.effect().can_forbid())
+        .count();
+    let children = children
+        .iter()
+        .map(|child| lower(child, nodes))
+        .collect::<Vec<_>>();
+    nodes.push(Node::Composite(Composite {
+        kind,
+        policy_type: policy.policy_type(),
+        policy_id,
+        children,
+        veto_capable_count,
+    }));
+    nodes.len() - 1
+}
+
+fn composite_kind<D: PolicyDomain>(
+    composition: PolicyComposition<'_, D>,
+) -> Option<(
+    CompositeKind,
+    &[Arc<dyn Policy<D>>],
+    Option<Cow<'static, str>>,
+)> {
+    let PolicyComposition {
+        operation,
+        children,
+        policy_id,
+    } = composition;
+    let kind = match operation {
+        CombineOp::And => CompositeKind::And,
+        CombineOp::Or => CompositeKind::Or,
+        CombineOp::Not if children.len() == 1 => CompositeKind::Not,
+        CombineOp::AtMost { max_grants } if max_grants > 0 => CompositeKind::AtMost(max_grants),
+        _ => return None,
+    };
+    if children.is_empty() {
+        return None;
+    }
+    Some((kind, children, policy_id))
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for CompiledPolicy<D> {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let mut stack: Vec<Frame<'_>> = Vec::new();
+        let mut node = self.root;
+
+        loop {
+            let mut result = match &self.nodes[node] {
+                Node::Leaf {
+                    policy,
+                    policy_type,
+                } => match stack.last() {
+                    None => policy.evaluate(ctx).await,
+                    Some(parent) => {
+                        let inner_ctx = EvalCtx {
+                            session: ctx.session,
+                            subject: ctx.subject,
+                            action: ctx.action,
+                            resource: ctx.resource,
+                            context: ctx.context,
+                            policy_type: policy_type.clone(),
+                        };
+                        let evaluation = evaluate_in_span(policy.as_ref(), &inner_ctx);
+                        match &parent.span {
+                            Some(span) => evaluation.instrument(span.clone()).await,
+                            None => evaluation.await,
+                        }
+                    }
+                },
+                Node::Composite(composite) => {
+                    let span = stack.last().map(|parent| match &parent.span {
+                        Some(span) => span.in_scope(|| policy_span(&composite.policy_type)),
+                        None => policy_span(&composite.policy_type),
+                    });
+                    stack.push(Frame {
+                        composite,
+                        span,
+                        children: Vec::with_capacity(composite.children.len()),
+                        grant_count: 0,
+                        any_indeterminate: false,
+                        veto_prefix_flag: false,
+                    });
+                    node = composite.children[0];
+                    continue;
+                }
+            };
+
+            loop {
+                let Some(frame) = stack.last_mut() else {
+                    return result;
+                };
+                let composite = frame.composite;
+                match composite.step(frame, result) {
+                    Some(outcome) => {
+                        let (children, span) = stack
+                            .pop()
+                            .map(|frame| (frame.children, frame.span))
+                            .unwrap_or_default();
+                        result = PolicyEvalResult::Combined {
+                            policy_type: composite.policy_type.clone(),
+                            policy_id: composite.policy_id.clone(),
+                            operation: composite.operation(),
+                            children,
+                            outcome,
+                        };
+                        if let Some(span) = span {
+                            record_policy_outcome(&span, &result);
+                        }
+                    }
+                    None => {
+                        node = composite.children[frame.children.len()];
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
+        self.original.evaluate_batch(ctx).await
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.original.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.original.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.original.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        self.original
+            .declared_facts(subject, action, resource, context, facts);
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        self.original.composition()
+    }
+}
@@ -0,0 +1,86 @@
+//! Accessor traits for reading common request inputs from a domain's context.
+//!
+//! Built-in policies that need something from the context — the request
+//! time, the caller's tenant, when the subject authenticated — bound
+//! `D::Context` by exactly the accessor they use, so one rich context type
+//! implementing several accessors works with all of them at once.
+//! [`context_accessors!`](crate::context_accessors) implements the accessors
+//! from named fields.
+
+use std::time::SystemTime;
+
+/// A context that carries the time a request should be evaluated at.
+///
+/// Reading the time from the context rather than the system clock keeps
+/// decisions reproducible in tests and replays.
+pub trait ProvidesTime {
+    /// The time to evaluate the request at.
+    fn now(&self) -> SystemTime;
+}
+
+/// A context that carries the tenant a request is made in.
+pub trait ProvidesTenant {
+    /// The tenant identifier type.
+    type TenantId: PartialEq;
+
+    /// The tenant the request is made in.
+    fn tenant_id(&self) -> &Self::TenantId;
+}
+
+/// A context that carries when the subject last authenticated.
+pub trait ProvidesAuthTime {
+    /// When the subject last authenticated, or `None` if unknown.
+    fn authed_at(&self) -> Option<SystemTime>;
+}
+
+/// Implements context accessor traits from named fields of a struct.
+///
+/// Each entry names an accessor and the field that backs it: `time` reads a
+/// `SystemTime` field for [`ProvidesTime`], `auth_time` reads an
+/// `Option<SystemTime>` field for [`ProvidesAuthTime`], and `tenant` reads a
+/// field of the given type for [`ProvidesTenant`].
+///
+/// ```rust
+/// # use gatehouse::*;
+/// # use std::time::SystemTime;
+/// struct RequestCtx {
+///     received_at: SystemTime,
+///     tenant: u64,
+///     authed_at: Option<SystemTime>,
+/// }
+///
+/// context_accessors!(RequestCtx {
+///     time: received_at,
+///     tenant: tenant as u64,
+///     auth_time: authed_at,
+/// });
+/// ```
+#[macro_export]
+macro_rules! context_accessors {
+    ($context:ty { $($accessor:ident : $field:ident $(as $tenant:ty)?),* $(,)? }) => {
+        $($crate::context_accessors!(@accessor $context, $accessor, $field $(, $tenant)?);)*
+    };
+    (@accessor $context:ty, time, $field:ident) => {
+        impl $crate::ProvidesTime for $context {
+            fn now(&self) -> ::std::time::SystemTime {
+                self.$field
+            }
+        }
+    };
+    (@accessor $context:ty, auth_time, $field:ident) => {
+        impl $crate::ProvidesAuthTime for $context {
+            fn authed_at(&self) -> ::std::option::Option<::std::time::SystemTime> {
+                self.$field
+            }
+        }
+    };
+    (@accessor $context:ty, tenant, $field:ident, $tenant:ty) => {
+        impl $crate::ProvidesTenant for $context {
+            type TenantId = $tenant;
+
+            fn tenant_id(&self) -> &$tenant {
+                &self.$field
+            }
+        }
+    };
+}
@@ -0,0 +1,38 @@
+//! An ambient evaluation deadline scoped to a task.
+
+use std::future::Future;
+use tokio::time::Instant;
+
+tokio::task_local! {
+    static DEADLINE: Instant;
+}
+
+/// Runs `future` with an ambient evaluation deadline.
+///
+/// Every evaluation inside `future` — [`crate::BoundEvaluator::check`],
+/// [`crate::BoundEvaluator::evaluate`], [`crate::BoundEvaluator::filter`], and
+/// the checker's other single and batch entry points — stops at `deadline`
+/// and fails closed with an [`crate::PolicyEvalResult::Indeterminate`] trace,
+/// without the deadline being passed to each call. The deadline is checked
+/// before each policy (each batch round, for batches) as well as while one is
+/// pending, so a run of policies that never yield stops too. The reason reads
+/// `"deadline exceeded: 2 of 5 policies evaluated, 3 skipped"`. A batch keeps
+/// the decisions it reached before the deadline and fails the rest closed
+/// with the reason a single check of that item would give.
+///
+/// The task-local set here is the only source of truth: gatehouse does not
+/// read deadlines from the request context or from HTTP middleware. To honor
+/// a deadline your framework carries (a request extension, a gRPC timeout
+/// header), call this once where the request is handled, around the handler.
+/// Nested scopes keep the earlier deadline, so an inner scope can shorten
+/// but never extend the time available. The deadline follows `future` only;
+/// tasks it spawns do not inherit it.
+pub async fn with_deadline<F: Future>(deadline: Instant, future: F) -> F::Output {
+    let deadline = current().map_or(deadline, |outer| outer.min(deadline));
+    DEADLINE.scope(deadline, future).await
+}
+
+/// The ambient deadline of the current task, if one is set.
+pub(crate) fn current() -> Option<Instant> {
+    DEADLINE.try_with(|deadline| *deadline).ok()
+}
@@ -0,0 +1,50 @@
+//! Decision comparison between two checkers for migration safety.
+
+use crate::{AccessEvaluation, EvaluationSession, PermissionChecker, PolicyDomain};
+
+/// One sample input on which two checkers reached different decisions.
+pub struct Divergence<'a, D: PolicyDomain> {
+    /// Position of the sample in the input slice.
+    pub index: usize,
+    /// The `(subject, action, resource, context)` sample that diverged.
+    pub sample: &'a (D::Subject, D::Action, D::Resource, D::Context),
+    /// The decision of the old checker.
+    pub before: AccessEvaluation,
+    /// The decision of the new checker.
+    pub after: AccessEvaluation,
+}
+
+/// Evaluates every sample against `old` and `new` and returns the inputs
+/// where one checker grants and the other denies, in sample order.
+///
+/// Both checkers share `session`, so fact-backed policies observe the same
+/// loaded facts. Only the grant/deny outcome is compared; differing reasons
+/// or traces for the same outcome are not reported.
+pub async fn diff_checkers<'a, D: PolicyDomain>(
+    old: &PermissionChecker<D>,
+    new: &PermissionChecker<D>,
+    session: &EvaluationSession,
+    samples: &'a [(D::Subject, D::Action, D::Resource, D::Context)],
+) -> Vec<Divergence<'a, D>> {
+    let mut divergences = Vec::new();
+    for (index, sample) in samples.iter().enumerate() {
+        let (subject, action, resource, context) = sample;
+        let before = old
+            .bind(session, subject, action, context)
+            .check(resource)
+            .await;
+        let after = new
+            .bind(session, subject, action, context)
+            .check(resource)
+            .await;
+        if before.is_granted() != after.is_granted() {
+            divergences.push(Divergence {
+                index,
+                sample,
+                before,
+                after,
+            });
+        }
+    }
+    divergences
+}
@@ -0,0 +1,265 @@
+//! Declarative policy documents.
+//!
+//! A [`PolicyDocument`] describes a tree of built-in policies in data, so
+//! RBAC and ABAC rules can be edited in YAML or JSON without recompiling.
+//! Documents never carry code: ABAC conditions either name closures the
+//! application registers in a [`ConditionRegistry`], or are
+//! [`AttributeExpr`] expressions over the attributes the registry's extractor
+//! returns. [`PolicyDocument::compile`] rejects any name the registry does
+//! not know.
+//!
+//! ```yaml
+//! policies:
+//!   - rbac:
+//!       roles: [admin]
+//!   - and:
+//!       - abac:
+//!           condition: is_owner
+//!       - not:
+//!           abac:
+//!             condition: is_archived
+//!   - expr:
+//!       and:
+//!         - eq: [subject.department, finance]
+//!         - lt: [resource.amount, 10000]
+//! ```
+
+use crate::{
+    AbacPolicy, AndPolicy, AttributeExpr, Attributes, EmptyPoliciesError, NotPolicy, OrPolicy,
+    Policy, PolicyBuilder, PolicyDomain, RbacPolicy,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+type Condition<D> = Arc<
+    dyn Fn(
+            &<D as PolicyDomain>::Subject,
+            &<D as PolicyDomain>::Action,
+            &<D as PolicyDomain>::Resource,
+            &<D as PolicyDomain>::Context,
+        ) -> bool
+        + Send
+        + Sync,
+>;
+type SubjectRoles<D> = Arc<dyn Fn(&<D as PolicyDomain>::Subject) -> Vec<String> + Send + Sync>;
+type AttributeExtractor<D> = Arc<
+    dyn Fn(
+            &<D as PolicyDomain>::Subject,
+            &<D as PolicyDomain>::Action,
+            &<D as PolicyDomain>::Resource,
+            &<D as PolicyDomain>::Context,
+        ) -> Attributes
+        + Send
+        + Sync,
+>;
+
+/// A deserializable list of policies for one [`crate::PermissionChecker`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct PolicyDocument {
+    /// The checker's top-level policies, in registration order.
+    pub policies: Vec<PolicyNode>,
+}
+
+/// One node of a [`PolicyDocument`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case", deny_unknown_fields)]
+#[non_exhaustive]
+pub enum PolicyNode {
+    /// Grants when the subject holds any of `roles`, as reported by
+    /// [`ConditionRegistry::with_subject_roles`]. Compiles to a
+    /// [`RbacPolicy`].
+    Rbac {
+        /// The roles that grant access.
+        roles: Vec<String>,
+    },
+    /// Grants when the named condition holds. Compiles to a
+    /// [`PolicyBuilder`] policy whose type is the condition name.
+    Abac {
+        /// The name the condition was registered under.
+        condition: String,
+    },
+    /// Grants when the attribute expression holds over the attributes
+    /// returned by [`ConditionRegistry::with_attributes`]. Compiles to an
+    /// [`AbacPolicy`].
+    Expr(AttributeExpr),
+    /// Grants when every inner policy grants. Compiles to an [`AndPolicy`].
+    And(Vec<PolicyNode>),
+    /// Grants when any inner policy grants. Compiles to an [`OrPolicy`].
+    Or(Vec<PolicyNode>),
+    /// Inverts the inner policy. Compiles to a [`NotPolicy`].
+    Not(Box<PolicyNode>),
+}
+
+/// Named code a [`PolicyDocument`] may reference.
+pub struct ConditionRegistry<D: PolicyDomain> {
+    conditions: HashMap<String, Condition<D>>,
+    subject_roles: Option<SubjectRoles<D>>,
+    attributes: Option<AttributeExtractor<D>>,
+}
+
+impl<D: PolicyDomain> Default for ConditionRegistry<D> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D: PolicyDomain> ConditionRegistry<D> {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            conditions: HashMap::new(),
+            subject_roles: None,
+            attributes: None,
+        }
+    }
+
+    /// Registers an ABAC condition under `name`, replacing any earlier
+    /// condition with the same name.
+    pub fn with_condition<F>(mut self, name: impl Into<String>, condition: F) -> Self
+    where
+        F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> bool + Send + Sync + 'static,
+    {
+        self.conditions.insert(name.into(), Arc::new(condition));
+        self
+    }
+
+    /// Sets the resolver that lists a subject's roles for `rbac` nodes.
+    pub fn with_subject_roles<F>(mut self, resolver: F) -> Self
+    where
+        F: Fn(&D::Subject) -> Vec<String> + Send + Sync + 'static,
+    {
+        self.subject_roles = Some(Arc::new(resolver));
+        self
+    }
+
+    /// Sets the extractor that builds the [`Attributes`] for `expr` nodes.
+    pub fn with_attributes<F>(mut self, extractor: F) -> Self
+    where
+        F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> Attributes
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.attributes = Some(Arc::new(extractor));
+        self
+    }
+}
+
+/// Error returned by [`PolicyDocument::compile`].
+#[derive(Debug, Clone)]
+#[non_exhaustive]
+pub enum PolicyDocumentError {
+    /// An `abac` node named a condition the registry does not contain.
+    UnknownCondition(String),
+    /// The document has an `rbac` node but the registry has no subject-role
+    /// resolver.
+    MissingSubjectRoles,
+    /// The document has an `expr` node but the registry has no attribute
+    /// extractor.
+    MissingAttributes,
+    /// An `and` or `or` node has no inner policies.
+    Empty(EmptyPoliciesError),
+}
+
+impl fmt::Display for PolicyDocumentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownCondition(name) => write!(f, "unknown condition {name:?}"),
+            Self::MissingSubjectRoles => {
+                f.write_str("rbac policy requires a subject-role resolver")
+            }
+            Self::MissingAttributes => f.write_str("expr policy requires an attribute extractor"),
+            Self::Empty(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl std::error::Error for PolicyDocumentError {}
+
+impl From<EmptyPoliciesError> for PolicyDocumentError {
+    fn from(err: EmptyPoliciesError) -> Self {
+        Self::Empty(err)
+    }
+}
+
+impl PolicyDocument {
+    /// Compiles the document into policies, resolving condition names and
+    /// roles through `registry`.
+    ///
+    /// Add the returned policies to a checker in order. Compilation fails on
+    /// the first node that cannot be built, so a document with a typo never
+    /// produces a partial policy set.
+    pub fn compile<D: PolicyDomain>(
+        &self,
+        registry: &ConditionRegistry<D>,
+    ) -> Result<Vec<Arc<dyn Policy<D>>>, PolicyDocumentError> {
+        self.policies
+            .iter()
+            .map(|node| node.compile(registry))
+            .collect()
+    }
+}
+
+impl PolicyNode {
+    /// Compiles this node and its descendants into one policy.
+    pub fn compile<D: PolicyDomain>(
+        &self,
+        registry: &ConditionRegistry<D>,
+    ) -> Result<Arc<dyn Policy<D>>, PolicyDocumentError> {
+        Ok(match self {
+            Self::Rbac { roles } => {
+                let subject_roles = registry
+                    .subject_roles
+                    .clone()
+                    .ok_or(PolicyDocumentError::MissingSubjectRoles)?;
+                let roles = roles.clone();
+                Arc::new(RbacPolicy::<D, _, _>::new(
+                    move |_: &D::Action, _: &D::Resource| roles.clone(),
+                    move |subject: &D::Subject| subject_roles(subject),
+                ))
+            }
+            Self::Abac { condition } => {
+                let predicate = registry
+                    .conditions
+                    .get(condition)
+                    .cloned()
+                    .ok_or_else(|| PolicyDocumentError::UnknownCondition(condition.clone()))?;
+                Arc::from(
+                    PolicyBuilder::<D>::new(condition.clone())
+                        .when(move |subject, action, resource, context| {
+                            predicate(subject, action, resource, context)
+                        })
+                        .build(),
+                )
+            }
+            Self::Expr(expr) => {
+                let attributes = registry
+                    .attributes
+                    .clone()
+                    .ok_or(PolicyDocumentError::MissingAttributes)?;
+                Arc::new(AbacPolicy::<D, _>::from_expr(
+                    expr.clone(),
+                    move |subject: &D::Subject,
+                          action: &D::Action,
+                          resource: &D::Resource,
+                          context: &D::Context| {
+                        attributes(subject, action, resource, context)
+                    },
+                ))
+            }
+            Self::And(nodes) => Arc::new(AndPolicy::try_new(compile_all(nodes, registry)?)?),
+            Self::Or(nodes) => Arc::new(OrPolicy::try_new(compile_all(nodes, registry)?)?),
+            Self::Not(node) => Arc::new(NotPolicy::new(node.compile(registry)?)),
+        })
+    }
+}
+
+fn compile_all<D: PolicyDomain>(
+    nodes: &[PolicyNode],
+    registry: &ConditionRegistry<D>,
+) -> Result<Vec<Arc<dyn Policy<D>>>, PolicyDocumentError> {
+    nodes.iter().map(|node| node.compile(registry)).collect()
+}
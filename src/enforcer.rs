@@ -0,0 +1,110 @@
+//! A `casbin`-style boolean enforcement facade.
+//!
+//! Teams migrating from `casbin` usually have call sites shaped like
+//! `enforcer.enforce(sub, obj, act)`. [`Enforcer`] keeps that surface while the
+//! decision is made by an ordinary [`PermissionChecker`], so policies, traces,
+//! and `gatehouse::security` telemetry behave exactly as they do elsewhere.
+
+use crate::{AccessEvaluation, EvaluationSession, FactRegistry, PermissionChecker, PolicyDomain};
+
+/// A `casbin`-style `enforce(sub, obj, act) -> bool` adapter over a
+/// [`PermissionChecker`].
+///
+/// The domain must use owned `String` subjects, resources (casbin's "object"),
+/// and actions, with a unit context. Each call opens a fresh request session
+/// from the configured [`FactRegistry`], or uses
+/// [`EvaluationSession::shared_empty`] when no registry is set, so fact-backed
+/// policies without a registry fail closed.
+///
+/// ```rust
+/// use gatehouse::*;
+///
+/// struct Casbin;
+/// impl PolicyDomain for Casbin {
+///     type Subject = String;
+///     type Action = String;
+///     type Resource = String;
+///     type Context = ();
+/// }
+///
+/// let mut checker = PermissionChecker::<Casbin>::new();
+/// checker.add_policy(
+///     PolicyBuilder::<Casbin>::new("AliceReadsData1")
+///         .when(|sub: &String, act: &String, obj: &String, _ctx: &()| {
+///             sub == "alice" && obj == "data1" && act == "read"
+///         })
+///         .build(),
+/// );
+/// let enforcer = Enforcer::new(checker);
+///
+/// # tokio_test::block_on(async {
+/// assert!(enforcer.enforce("alice", "data1", "read").await);
+/// assert!(!enforcer.enforce("alice", "data1", "write").await);
+/// # });
+/// ```
+pub struct Enforcer<D: PolicyDomain> {
+    checker: PermissionChecker<D>,
+    registry: Option<FactRegistry>,
+}
+
+impl<D: PolicyDomain> Clone for Enforcer<D> {
+    fn clone(&self) -> Self {
+        Self {
+            checker: self.checker.clone(),
+            registry: self.registry.clone(),
+        }
+    }
+}
+
+impl<D> Enforcer<D>
+where
+    D: PolicyDomain<Subject = String, Action = String, Resource = String, Context = ()>,
+{
+    /// Wraps a checker with no fact registry.
+    pub fn new(checker: PermissionChecker<D>) -> Self {
+        Self {
+            checker,
+            registry: None,
+        }
+    }
+
+    /// Opens each enforcement session from `registry` so fact-backed policies
+    /// can load facts.
+    pub fn with_registry(mut self, registry: FactRegistry) -> Self {
+        self.registry = Some(registry);
+        self
+    }
+
+    /// Returns the wrapped checker.
+    pub fn checker(&self) -> &PermissionChecker<D> {
+        &self.checker
+    }
+
+    /// Returns whether `sub` may perform `act` on `obj`.
+    pub async fn enforce(&self, sub: &str, obj: &str, act: &str) -> bool {
+        self.enforce_ex(sub, obj, act).await.is_granted()
+    }
+
+    /// Like [`Self::enforce`], but returns the full [`AccessEvaluation`] with
+    /// its trace, mirroring casbin's `enforce_ex`.
+    pub async fn enforce_ex(&self, sub: &str, obj: &str, act: &str) -> AccessEvaluation {
+        let subject = sub.to_owned();
+        let resource = obj.to_owned();
+        let action = act.to_owned();
+        match &self.registry {
+            Some(registry) => {
+                let session = registry.session();
+                self.checker
+                    .bind(&session, &subject, &action, &())
+                    .check(&resource)
+                    .await
+            }
+            None => {
+                self.checker
+                    .bind(EvaluationSession::shared_empty(), &subject, &action, &())
+                    .check(&resource)
+                    .await
+            }
+        }
+    }
+}
@@ -0,0 +1,105 @@
+//! Injectable entropy for rollout and sampling policies.
+//!
+//! Probabilistic policies must be reproducible under test. They draw from an
+//! [`EntropySource`] instead of a global RNG: production uses
+//! [`SystemEntropy`], tests use a seeded [`FixedEntropy`].
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hash, Hasher};
+
+/// A source of pseudo-random values keyed by a caller-provided bucket key.
+///
+/// The same key must map to the same value for the lifetime of the source,
+/// so a subject keeps its rollout bucket across requests.
+pub trait EntropySource: Send + Sync {
+    /// Returns a uniformly distributed value derived from `key`.
+    fn sample(&self, key: u64) -> u64;
+}
+
+/// Entropy seeded from the operating system once, at construction.
+///
+/// Samples are stable for the lifetime of the value but differ between
+/// processes. Use [`FixedEntropy`] with a deployment-wide seed when buckets
+/// must agree across processes.
+#[derive(Debug, Clone)]
+pub struct SystemEntropy {
+    seed: u64,
+}
+
+impl SystemEntropy {
+    /// Creates a source with a fresh random seed.
+    pub fn new() -> Self {
+        Self {
+            seed: RandomState::new().hash_one(0u64),
+        }
+    }
+}
+
+impl Default for SystemEntropy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EntropySource for SystemEntropy {
+    fn sample(&self, key: u64) -> u64 {
+        mix(self.seed, key)
+    }
+}
+
+/// Deterministic entropy from a fixed seed, for tests and for buckets that
+/// must be identical across processes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FixedEntropy {
+    seed: u64,
+}
+
+impl FixedEntropy {
+    /// Creates a source from `seed`.
+    pub fn new(seed: u64) -> Self {
+        Self { seed }
+    }
+}
+
+impl EntropySource for FixedEntropy {
+    fn sample(&self, key: u64) -> u64 {
+        mix(self.seed, key)
+    }
+}
+
+/// Hashes `value` with a fixed, process-independent algorithm (FNV-1a), so a
+/// bucket key is stable across runs and releases of the standard library.
+pub(crate) fn stable_hash<T: Hash + ?Sized>(value: &T) -> u64 {
+    let mut hasher = StableHasher::default();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+/// SplitMix64 finalizer over the seed and key.
+fn mix(seed: u64, key: u64) -> u64 {
+    let mut z = seed ^ key.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+struct StableHasher(u64);
+
+impl Default for StableHasher {
+    fn default() -> Self {
+        Self(0xcbf2_9ce4_8422_2325)
+    }
+}
+
+impl Hasher for StableHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= u64::from(*byte);
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
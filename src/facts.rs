@@ -1,8 +1,14 @@
+use crate::EvaluationSession;
 use async_trait::async_trait;
+use std::any::Any;
+use std::collections::HashMap;
 use std::fmt;
+use std::future::Future;
 use std::hash::Hash;
 use std::num::NonZeroUsize;
-use std::sync::Arc;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
 /// A typed fact key that can be loaded through an [`crate::EvaluationSession`].
 ///
@@ -244,6 +250,450 @@ where
     }
 }
 
+/// Wraps a boolean [`FactSource`] and answers the opposite question.
+///
+/// Register `NotSource::new(inner)` for a relationship key to let a
+/// [`RebacPolicy`](crate::RebacPolicy) grant on the *absence* of a
+/// relationship, such as "subject is not blocked from the resource". Wrapping
+/// the policy in [`crate::NotPolicy`] instead would also invert its
+/// not-applicable and indeterminate outcomes.
+///
+/// Only `Found` values are negated. `Missing` and `Error` pass through
+/// unchanged, so an unknown relationship never becomes a grant. Because the
+/// session registry is keyed by fact key type, give negated relationships
+/// their own relation type when the positive relationship is also needed.
+pub struct NotSource<S> {
+    inner: S,
+}
+
+impl<S> NotSource<S> {
+    /// Wraps `inner`.
+    pub fn new(inner: S) -> Self {
+        Self { inner }
+    }
+}
+
+#[async_trait]
+impl<K, S> FactSource<K> for NotSource<S>
+where
+    K: FactKey<Value = bool>,
+    S: FactSource<K>,
+{
+    async fn load_many(&self, keys: &[K]) -> Vec<FactLoadResult<bool>> {
+        self.inner
+            .load_many(keys)
+            .await
+            .into_iter()
+            .map(|result| match result {
+                FactLoadResult::Found(value) => FactLoadResult::Found(!value),
+                other => other,
+            })
+            .collect()
+    }
+
+    fn max_batch_size(&self) -> Option<NonZeroUsize> {
+        self.inner.max_batch_size()
+    }
+}
+
+/// Routes relationship checks to a different [`FactSource`] per relation.
+///
+/// The session holds one source per fact key type, so a resource whose
+/// relationships live in several backends — ownership in the database,
+/// sharing in a graph store — would otherwise need one relation type per
+/// backend. Register a `RelationRouter` for the [`RelationshipQuery`] key
+/// instead, and every [`crate::RebacPolicy`] and
+/// [`crate::TransitiveRebacPolicy`] over that key reaches the right backend.
+///
+/// Each call splits its keys by relation and loads every group from its
+/// source concurrently. A key whose relation has no route loads as
+/// [`FactLoadError::SourceNotRegistered`], so the policy fails closed. The
+/// router's batch size is the smallest of its sources'.
+pub struct RelationRouter<SubjectId, ResourceId, Relation> {
+    routes:
+        HashMap<Relation, Arc<dyn FactSource<RelationshipQuery<SubjectId, ResourceId, Relation>>>>,
+}
+
+impl<SubjectId, ResourceId, Relation> RelationRouter<SubjectId, ResourceId, Relation>
+where
+    Relation: Eq + Hash,
+{
+    /// Creates a router with no routes.
+    pub fn new() -> Self {
+        Self {
+            routes: HashMap::new(),
+        }
+    }
+
+    /// Routes checks of `relation` to `source`, replacing any earlier route.
+    pub fn with<S>(self, relation: Relation, source: S) -> Self
+    where
+        S: FactSource<RelationshipQuery<SubjectId, ResourceId, Relation>> + 'static,
+        RelationshipQuery<SubjectId, ResourceId, Relation>: FactKey,
+    {
+        self.with_arc(relation, Arc::new(source))
+    }
+
+    /// Routes checks of `relation` to a shared `source`, replacing any
+    /// earlier route.
+    pub fn with_arc(
+        mut self,
+        relation: Relation,
+        source: Arc<dyn FactSource<RelationshipQuery<SubjectId, ResourceId, Relation>>>,
+    ) -> Self
+    where
+        RelationshipQuery<SubjectId, ResourceId, Relation>: FactKey,
+    {
+        self.routes.insert(relation, source);
+        self
+    }
+}
+
+impl<SubjectId, ResourceId, Relation> Default for RelationRouter<SubjectId, ResourceId, Relation>
+where
+    Relation: Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<SubjectId, ResourceId, Relation> FactSource<RelationshipQuery<SubjectId, ResourceId, Relation>>
+    for RelationRouter<SubjectId, ResourceId, Relation>
+where
+    SubjectId: Eq + Hash + Clone + Send + Sync + 'static,
+    ResourceId: Eq + Hash + Clone + Send + Sync + 'static,
+    Relation: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    async fn load_many(
+        &self,
+        keys: &[RelationshipQuery<SubjectId, ResourceId, Relation>],
+    ) -> Vec<FactLoadResult<bool>> {
+        let mut groups: HashMap<&Relation, (Vec<usize>, Vec<_>)> = HashMap::new();
+        let mut results = (0..keys.len()).map(|_| None).collect::<Vec<_>>();
+        for (index, key) in keys.iter().enumerate() {
+            if self.routes.contains_key(&key.relation) {
+                let (indices, group) = groups.entry(&key.relation).or_default();
+                indices.push(index);
+                group.push(key.clone());
+            } else {
+                results[index] = Some(FactLoadResult::Error(FactLoadError::SourceNotRegistered {
+                    fact_name: RelationshipQuery::<SubjectId, ResourceId, Relation>::NAME,
+                }));
+            }
+        }
+
+        let loads = groups.into_iter().map(|(relation, (indices, group))| {
+            let source = &self.routes[relation];
+            async move { (indices, source.load_many(&group).await) }
+        });
+        for (indices, loaded) in futures_util::future::join_all(loads).await {
+            if loaded.len() != indices.len() {
+                let error = FactLoadError::SourceContractViolation {
+                    fact_name: RelationshipQuery::<SubjectId, ResourceId, Relation>::NAME,
+                    expected: indices.len(),
+                    actual: loaded.len(),
+                };
+                for index in indices {
+                    results[index] = Some(FactLoadResult::Error(error.clone()));
+                }
+                continue;
+            }
+            for (index, result) in indices.into_iter().zip(loaded) {
+                results[index] = Some(result);
+            }
+        }
+        results
+            .into_iter()
+            .map(|result| result.expect("every key is routed or rejected"))
+            .collect()
+    }
+
+    fn max_batch_size(&self) -> Option<NonZeroUsize> {
+        self.routes
+            .values()
+            .filter_map(|source| source.max_batch_size())
+            .min()
+    }
+}
+
+/// An in-memory relationship store for tests and examples.
+///
+/// Register it for a [`RelationshipQuery`] key in place of a database-backed
+/// source. Relationships added with [`Self::add`] load as `Found(true)` and
+/// every other key as `Found(false)`. [`Self::with_error`] fails every load,
+/// to exercise fail-closed paths. With the `tokio` feature,
+/// `with_delay` holds every load for a while, to exercise timeouts and
+/// deadlines.
+///
+/// The source is keyed by identifiers, so it works with any subject and
+/// resource types; the [`crate::RebacPolicy`] extraction closures decide
+/// which identifiers a request asks about.
+pub struct InMemoryRelationshipSource<SubjectId, ResourceId, Relation> {
+    relationships: std::collections::HashSet<RelationshipQuery<SubjectId, ResourceId, Relation>>,
+    error: Option<String>,
+    #[cfg(feature = "tokio")]
+    delay: Option<Duration>,
+}
+
+impl<SubjectId, ResourceId, Relation> InMemoryRelationshipSource<SubjectId, ResourceId, Relation>
+where
+    SubjectId: Eq + Hash,
+    ResourceId: Eq + Hash,
+    Relation: Eq + Hash,
+{
+    /// Creates a store with no relationships.
+    pub fn new() -> Self {
+        Self {
+            relationships: std::collections::HashSet::new(),
+            error: None,
+            #[cfg(feature = "tokio")]
+            delay: None,
+        }
+    }
+
+    /// Records that `subject_id` has `relation` to `resource_id`.
+    pub fn add(
+        mut self,
+        subject_id: SubjectId,
+        resource_id: ResourceId,
+        relation: Relation,
+    ) -> Self {
+        self.relationships.insert(RelationshipQuery {
+            subject_id,
+            resource_id,
+            relation,
+        });
+        self
+    }
+
+    /// Fails every load with a backend error carrying `message`.
+    pub fn with_error(mut self, message: impl Into<String>) -> Self {
+        self.error = Some(message.into());
+        self
+    }
+
+    /// Waits `delay` before answering each load.
+    #[cfg(feature = "tokio")]
+    pub fn with_delay(mut self, delay: Duration) -> Self {
+        self.delay = Some(delay);
+        self
+    }
+}
+
+impl<SubjectId, ResourceId, Relation> Default
+    for InMemoryRelationshipSource<SubjectId, ResourceId, Relation>
+where
+    SubjectId: Eq + Hash,
+    ResourceId: Eq + Hash,
+    Relation: Eq + Hash,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl<SubjectId, ResourceId, Relation> FactSource<RelationshipQuery<SubjectId, ResourceId, Relation>>
+    for InMemoryRelationshipSource<SubjectId, ResourceId, Relation>
+where
+    SubjectId: Eq + Hash + Clone + Send + Sync + 'static,
+    ResourceId: Eq + Hash + Clone + Send + Sync + 'static,
+    Relation: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    async fn load_many(
+        &self,
+        keys: &[RelationshipQuery<SubjectId, ResourceId, Relation>],
+    ) -> Vec<FactLoadResult<bool>> {
+        #[cfg(feature = "tokio")]
+        if let Some(delay) = self.delay {
+            tokio::time::sleep(delay).await;
+        }
+        keys.iter()
+            .map(|key| match &self.error {
+                Some(message) => {
+                    FactLoadResult::Error(FactLoadError::backend_message(message.clone()))
+                }
+                None => FactLoadResult::Found(self.relationships.contains(key)),
+            })
+            .collect()
+    }
+}
+
+/// Receives the hit and miss counts of each [`CachedSource`] load, for
+/// metrics.
+///
+/// Called once per `load_many` call with the number of keys answered from
+/// the cache and the number passed to the inner source. Implemented for
+/// closures of the same shape.
+pub trait FactCacheObserver: Send + Sync {
+    /// Records that a load of `fact_name` keys found `hits` cached and
+    /// loaded `misses` from the inner source.
+    fn on_cache_lookup(&self, fact_name: &'static str, hits: usize, misses: usize);
+}
+
+impl<F> FactCacheObserver for F
+where
+    F: Fn(&'static str, usize, usize) + Send + Sync,
+{
+    fn on_cache_lookup(&self, fact_name: &'static str, hits: usize, misses: usize) {
+        self(fact_name, hits, misses)
+    }
+}
+
+/// Wraps a [`FactSource`] so its results are reused across sessions for a
+/// short window.
+///
+/// The fact analog of [`crate::CachedPolicy`]. A session already loads each
+/// key once per request; this cache also spans the sessions of a
+/// [`crate::FactRegistry`], so a relationship checked by many requests
+/// reaches the backend once per `ttl`.
+/// Fact keys are hashable, so the key itself is the cache key.
+///
+/// `Found` and `Missing` results are cached for `ttl`; errors never are, so
+/// a failed backend call is retried on the next load. The cache holds at
+/// most `capacity` entries, evicting like [`crate::CachedPolicy`]. As with
+/// that cache, a revoked relationship can still load as `Found` until its
+/// entry expires, so choose `ttl` accordingly.
+pub struct CachedSource<K: FactKey, S> {
+    inner: S,
+    ttl: Duration,
+    capacity: usize,
+    entries: Mutex<HashMap<K, CachedFact<K::Value>>>,
+    observer: Option<Arc<dyn FactCacheObserver>>,
+}
+
+struct CachedFact<V> {
+    expires_at: Instant,
+    result: FactLoadResult<V>,
+}
+
+impl<K: FactKey, S> CachedSource<K, S> {
+    /// Wraps `inner`, caching up to `capacity` results for `ttl` each.
+    pub fn new(inner: S, capacity: usize, ttl: Duration) -> Self {
+        Self {
+            inner,
+            ttl,
+            capacity,
+            entries: Mutex::new(HashMap::new()),
+            observer: None,
+        }
+    }
+
+    /// Reports every load's hit and miss counts to `observer`.
+    pub fn with_observer(mut self, observer: Arc<dyn FactCacheObserver>) -> Self {
+        self.observer = Some(observer);
+        self
+    }
+
+    /// Drops every cached result, for example after a relationship change
+    /// that must take effect before the TTL runs out.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<K, CachedFact<K::Value>>> {
+        self.entries
+            .lock()
+            .expect("fact cache mutex should not be poisoned")
+    }
+
+    fn store(&self, keys: &[K], results: &[FactLoadResult<K::Value>], now: Instant) {
+        if self.capacity == 0 {
+            return;
+        }
+        let expires_at = now + self.ttl;
+        let mut entries = self.lock();
+        for (key, result) in keys.iter().zip(results) {
+            if matches!(result, FactLoadResult::Error(_)) {
+                continue;
+            }
+            if entries.len() >= self.capacity && !entries.contains_key(key) {
+                entries.retain(|_, entry| entry.expires_at > now);
+            }
+            if entries.len() >= self.capacity && !entries.contains_key(key) {
+                let soonest = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.expires_at)
+                    .map(|(key, _)| key.clone());
+                if let Some(soonest) = soonest {
+                    entries.remove(&soonest);
+                }
+            }
+            entries.insert(
+                key.clone(),
+                CachedFact {
+                    expires_at,
+                    result: result.clone(),
+                },
+            );
+        }
+    }
+}
+
+#[async_trait]
+impl<K, S> FactSource<K> for CachedSource<K, S>
+where
+    K: FactKey,
+    S: FactSource<K>,
+{
+    async fn load_many(&self, keys: &[K]) -> Vec<FactLoadResult<K::Value>> {
+        let now = Instant::now();
+        let mut results = Vec::with_capacity(keys.len());
+        let mut missed = Vec::new();
+        let mut missed_keys = Vec::new();
+        {
+            let mut entries = self.lock();
+            for (index, key) in keys.iter().enumerate() {
+                match entries.get(key) {
+                    Some(entry) if entry.expires_at > now => {
+                        results.push(Some(entry.result.clone()))
+                    }
+                    cached => {
+                        if cached.is_some() {
+                            entries.remove(key);
+                        }
+                        results.push(None);
+                        missed.push(index);
+                        missed_keys.push(key.clone());
+                    }
+                }
+            }
+        }
+        if let Some(observer) = &self.observer {
+            observer.on_cache_lookup(K::NAME, keys.len() - missed.len(), missed.len());
+        }
+
+        if !missed_keys.is_empty() {
+            let loaded = self.inner.load_many(&missed_keys).await;
+            if loaded.len() == missed_keys.len() {
+                self.store(&missed_keys, &loaded, Instant::now());
+                for (index, result) in missed.into_iter().zip(loaded) {
+                    results[index] = Some(result);
+                }
+            } else {
+                let error = FactLoadError::SourceContractViolation {
+                    fact_name: K::NAME,
+                    expected: missed_keys.len(),
+                    actual: loaded.len(),
+                };
+                for index in missed {
+                    results[index] = Some(FactLoadResult::Error(error.clone()));
+                }
+            }
+        }
+        results
+            .into_iter()
+            .map(|result| result.expect("every key is cached or loaded"))
+            .collect()
+    }
+
+    fn max_batch_size(&self) -> Option<NonZeroUsize> {
+        self.inner.max_batch_size()
+    }
+}
+
 /// Error raised while installing fact sources into a request session.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub(crate) enum FactSourceRegistrationError {
@@ -342,3 +792,129 @@ where
 
     const NAME: &'static str = "relationship";
 }
+
+/// Fact key listing the objects a subject reaches through one relation.
+///
+/// Used by [`crate::TransitiveRebacPolicy`] to walk relationship chains such
+/// as "user is a member of a group that owns the document". A registered
+/// [`FactSource`] answers with every object `subject_id` has `relation` to —
+/// the groups a user belongs to, say — and each of those objects is then
+/// treated as a subject for the next hop, which is why the objects share the
+/// `SubjectId` type. `Missing` and an empty list both mean "no related
+/// objects".
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RelatedSubjects<SubjectId, Relation> {
+    /// Subject (or intermediate object) identifier.
+    pub subject_id: SubjectId,
+    /// Relationship to follow.
+    pub relation: Relation,
+}
+
+impl<SubjectId, Relation> FactKey for RelatedSubjects<SubjectId, Relation>
+where
+    SubjectId: Eq + Hash + Clone + Send + Sync + 'static,
+    Relation: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    type Value = Vec<SubjectId>;
+
+    const NAME: &'static str = "related_subjects";
+}
+
+type PrefetchFuture<'a> = Pin<Box<dyn Future<Output = ()> + Send + 'a>>;
+
+trait DeclaredKeys: Send + Sync {
+    fn fact_name(&self) -> &'static str;
+    fn len(&self) -> usize;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+    fn prefetch<'a>(&'a self, session: &'a EvaluationSession) -> PrefetchFuture<'a>;
+}
+
+struct TypedDeclaredKeys<K: FactKey>(Vec<K>);
+
+impl<K: FactKey> DeclaredKeys for TypedDeclaredKeys<K> {
+    fn fact_name(&self) -> &'static str {
+        K::NAME
+    }
+
+    fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn prefetch<'a>(&'a self, session: &'a EvaluationSession) -> PrefetchFuture<'a> {
+        Box::pin(async move {
+            session.get_many(&self.0).await;
+        })
+    }
+}
+
+/// Fact keys that policies declare they will load, collected by
+/// [`crate::PermissionChecker`] before any policy is evaluated.
+///
+/// Keys of any [`FactKey`] type can be declared; the checker then loads each
+/// key type with one [`EvaluationSession::get_many`] call, so the policies'
+/// own lookups are served from the session cache. See
+/// [`crate::Policy::declared_facts`].
+#[derive(Default)]
+pub struct DeclaredFacts {
+    groups: Vec<Box<dyn DeclaredKeys>>,
+}
+
+impl DeclaredFacts {
+    /// Creates an empty declaration set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Declares one fact key.
+    pub fn declare<K: FactKey>(&mut self, key: K) {
+        for group in &mut self.groups {
+            if let Some(keys) = group.as_any_mut().downcast_mut::<TypedDeclaredKeys<K>>() {
+                keys.0.push(key);
+                return;
+            }
+        }
+        self.groups.push(Box::new(TypedDeclaredKeys(vec![key])));
+    }
+
+    /// Declares every key in `keys`.
+    pub fn declare_many<K: FactKey>(&mut self, keys: impl IntoIterator<Item = K>) {
+        for key in keys {
+            self.declare(key);
+        }
+    }
+
+    /// Returns the number of declared keys, counting duplicates.
+    pub fn len(&self) -> usize {
+        self.groups.iter().map(|group| group.len()).sum()
+    }
+
+    /// Returns whether no keys were declared.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Loads every declared key through `session`, one `get_many` per key
+    /// type. Results land in the session cache; errors are cached like any
+    /// other load and surface when a policy reads the key.
+    pub(crate) async fn prefetch(&self, session: &EvaluationSession) {
+        for group in &self.groups {
+            group.prefetch(session).await;
+        }
+    }
+}
+
+impl fmt::Debug for DeclaredFacts {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.groups
+                    .iter()
+                    .map(|group| (group.fact_name(), group.len())),
+            )
+            .finish()
+    }
+}
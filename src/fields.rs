@@ -0,0 +1,82 @@
+//! Field-level authorization below resource granularity.
+//!
+//! A [`FieldPolicy`] narrows which fields of an already-authorized resource a
+//! subject may see or touch. [`crate::BoundEvaluator::authorized_fields`]
+//! first runs the checker's ordinary resource decision, then intersects the
+//! [`FieldDecision`] of every registered field policy: each field policy can
+//! only remove fields, never add them back.
+
+use crate::{EvalCtx, PolicyDomain};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::collections::BTreeSet;
+
+/// The set of fields one field policy (or the combined checker) allows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FieldDecision {
+    /// Every field is allowed. This is the identity for intersection.
+    All,
+    /// Only the listed fields are allowed.
+    Only(BTreeSet<Cow<'static, str>>),
+}
+
+impl FieldDecision {
+    /// Allows only the given fields.
+    pub fn only<I, F>(fields: I) -> Self
+    where
+        I: IntoIterator<Item = F>,
+        F: Into<Cow<'static, str>>,
+    {
+        Self::Only(fields.into_iter().map(Into::into).collect())
+    }
+
+    /// Allows no fields.
+    pub fn none() -> Self {
+        Self::Only(BTreeSet::new())
+    }
+
+    /// Returns whether `field` is allowed.
+    pub fn allows(&self, field: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(fields) => fields.contains(field),
+        }
+    }
+
+    /// Returns whether no field is allowed.
+    pub fn is_empty(&self) -> bool {
+        matches!(self, Self::Only(fields) if fields.is_empty())
+    }
+
+    /// Returns the explicit field set, or `None` when every field is allowed.
+    pub fn fields(&self) -> Option<&BTreeSet<Cow<'static, str>>> {
+        match self {
+            Self::All => None,
+            Self::Only(fields) => Some(fields),
+        }
+    }
+
+    /// Returns the fields allowed by both decisions.
+    pub fn intersect(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::All, other) | (other, Self::All) => other,
+            (Self::Only(left), Self::Only(right)) => {
+                Self::Only(left.intersection(&right).cloned().collect())
+            }
+        }
+    }
+}
+
+/// A policy deciding which fields of a resource are visible to a subject.
+///
+/// Field policies run only after the checker has granted access to the
+/// resource itself; they never grant resource access on their own.
+#[async_trait]
+pub trait FieldPolicy<D: PolicyDomain>: Send + Sync {
+    /// Returns the fields this policy allows for the request in `ctx`.
+    async fn evaluate_fields(&self, ctx: &EvalCtx<'_, D>) -> FieldDecision;
+
+    /// Returns a policy type label used in traces and telemetry.
+    fn policy_type(&self) -> Cow<'static, str>;
+}
@@ -0,0 +1,64 @@
+use crate::AccessEvaluation;
+use serde::{Deserialize, Serialize};
+
+/// A stable JSON body for `403 Forbidden` responses.
+///
+/// Serializes as `{ "code": ..., "message": ..., "requirements": [...] }`,
+/// a shape fixed enough to document once in an OpenAPI schema. Build one
+/// from a denial with `ForbiddenResponse::from(&evaluation)`:
+///
+/// - `code` is [`Self::FORBIDDEN`] when a forbid policy vetoed the request
+///   (see [`AccessEvaluation::forbidden_by`]) and [`Self::NOT_PERMITTED`]
+///   when no policy granted it.
+/// - `message` is the summary denial reason.
+/// - `requirements` lists each unmet policy as `"{policy}: {reason}"`, from
+///   [`AccessEvaluation::all_denial_reasons`].
+///
+/// Reasons are copied verbatim, so the body tells the caller which policies
+/// exist and why they did not grant. Keep reasons free of anything the
+/// caller should not see. Converting a grant yields [`Self::GRANTED`] and no
+/// requirements; it is only meant to be sent with a denial.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ForbiddenResponse {
+    /// Machine-readable denial kind.
+    pub code: String,
+    /// Human-readable summary of the denial.
+    pub message: String,
+    /// The unmet policies, one `"{policy}: {reason}"` entry each.
+    pub requirements: Vec<String>,
+}
+
+impl ForbiddenResponse {
+    /// `code` for a denial caused by a forbid policy's veto.
+    pub const FORBIDDEN: &'static str = "forbidden";
+    /// `code` for a denial where no policy granted access.
+    pub const NOT_PERMITTED: &'static str = "not_permitted";
+    /// `code` for a converted grant.
+    pub const GRANTED: &'static str = "granted";
+}
+
+impl From<&AccessEvaluation> for ForbiddenResponse {
+    fn from(evaluation: &AccessEvaluation) -> Self {
+        let code = match evaluation {
+            AccessEvaluation::Granted { .. } => Self::GRANTED,
+            AccessEvaluation::Denied { .. } if evaluation.forbidden_by().is_some() => {
+                Self::FORBIDDEN
+            }
+            AccessEvaluation::Denied { .. } => Self::NOT_PERMITTED,
+        };
+        let message = match evaluation {
+            AccessEvaluation::Granted { .. } => "Access granted".to_string(),
+            AccessEvaluation::Denied { reason, .. } => reason.clone(),
+        };
+        let requirements = evaluation
+            .all_denial_reasons()
+            .into_iter()
+            .map(|(policy, reason)| format!("{policy}: {reason}"))
+            .collect();
+        Self {
+            code: code.to_string(),
+            message,
+            requirements,
+        }
+    }
+}
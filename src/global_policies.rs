@@ -0,0 +1,98 @@
+//! Process-wide default policies for checkers that opt in.
+//!
+//! Some policies are mandatory for every checker of a domain — a
+//! "suspended accounts are denied" forbid, say. Registering them here once
+//! at startup, and building checkers with
+//! [`PermissionChecker::new_with_globals`], saves repeating them at every
+//! call site.
+//!
+//! # Ordering
+//!
+//! [`PermissionChecker::new_with_globals`] adds the registered policies, in
+//! registration order, before any policy added afterwards. Like every
+//! policy, they are then scheduled by [`Policy::effect`]: a global forbid
+//! joins the veto-capable prefix and is always evaluated, while a global
+//! allow-only policy runs ahead of the checker's own allow-only policies and
+//! can grant before they are reached.
+//!
+//! # Security
+//!
+//! The registry is global mutable state, so treat it like any other
+//! process-wide security setting:
+//!
+//! - Register during startup, before building checkers. A checker takes the
+//!   policies registered when it is built; later registrations do not
+//!   reach it.
+//! - Only [`PermissionChecker::new_with_globals`] consults the registry.
+//!   [`PermissionChecker::new`] and the other constructors never do, so a
+//!   checker built with them silently lacks the mandatory policies. Make
+//!   `new_with_globals` the only constructor your application uses for the
+//!   domain.
+//! - Any code in the process can register for any domain it can name,
+//!   including a global allow-only policy that grants broadly. Keep
+//!   registration in the application's own startup code, and prefer
+//!   registering forbid policies: they can only narrow access.
+//!
+//! [`PermissionChecker::new_with_globals`]: crate::PermissionChecker::new_with_globals
+//! [`PermissionChecker::new`]: crate::PermissionChecker::new
+//! [`Policy::effect`]: crate::Policy::effect
+
+use crate::{Policy, PolicyDomain};
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+type Factory<D> = Arc<dyn Fn() -> Box<dyn Policy<D>> + Send + Sync>;
+type Registry = RwLock<HashMap<TypeId, Box<dyn Any + Send + Sync>>>;
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(Registry::default)
+}
+
+/// Registers a policy that every checker of domain `D` built with
+/// [`crate::PermissionChecker::new_with_globals`] starts with.
+///
+/// `factory` is called once per checker built, so each checker owns its own
+/// policy instance.
+pub fn register<D, P, F>(factory: F)
+where
+    D: PolicyDomain,
+    P: Policy<D> + 'static,
+    F: Fn() -> P + Send + Sync + 'static,
+{
+    let factory: Factory<D> = Arc::new(move || Box::new(factory()));
+    registry()
+        .write()
+        .expect("global policy registry should not be poisoned")
+        .entry(TypeId::of::<D>())
+        .or_insert_with(|| Box::new(Vec::<Factory<D>>::new()))
+        .downcast_mut::<Vec<Factory<D>>>()
+        .expect("global policy registry entries are keyed by their domain type")
+        .push(factory);
+}
+
+/// Removes every global policy registered for domain `D`.
+///
+/// Checkers already built keep their policies.
+pub fn clear<D: PolicyDomain>() {
+    registry()
+        .write()
+        .expect("global policy registry should not be poisoned")
+        .remove(&TypeId::of::<D>());
+}
+
+/// Builds one instance of each global policy registered for domain `D`, in
+/// registration order.
+pub(crate) fn policies<D: PolicyDomain>() -> Vec<Box<dyn Policy<D>>> {
+    // Copy the factories out so they run without the lock held; a factory
+    // may itself build a checker.
+    let factories = registry()
+        .read()
+        .expect("global policy registry should not be poisoned")
+        .get(&TypeId::of::<D>())
+        .and_then(|entry| entry.downcast_ref::<Vec<Factory<D>>>())
+        .cloned()
+        .unwrap_or_default();
+    factories.iter().map(|factory| factory()).collect()
+}
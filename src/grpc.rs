@@ -0,0 +1,198 @@
+//! Authorization for [`tonic`](https://docs.rs/tonic) gRPC servers.
+//!
+//! [`GrpcAuthLayer`] checks every call before it reaches the generated
+//! service. The action comes from the fully-qualified method name
+//! (`/package.Service/Method`), the subject and context from the request
+//! metadata, and the resource from an extractor registered for that method.
+//! Denials are answered with `PERMISSION_DENIED`.
+//!
+//! This is a tower layer rather than a `tonic::service::Interceptor`:
+//! interceptors are synchronous and cannot await the checker. Install it
+//! with `Server::builder().layer(...)`. Layers run before the message is
+//! decoded, so extractors only see metadata; carry the resource identifier
+//! in a metadata entry for the methods that need one.
+
+use crate::{AccessEvaluation, FactRegistry, PermissionChecker, PolicyDomain};
+use http::{Request, Response};
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tonic::metadata::MetadataMap;
+use tonic::Status;
+use tower_layer::Layer;
+use tower_service::Service;
+
+type MetadataFn<T> = Arc<dyn Fn(&MetadataMap) -> T + Send + Sync>;
+type ActionFn<A> = Arc<dyn Fn(&str) -> A + Send + Sync>;
+
+/// Authorizes gRPC calls with a [`PermissionChecker`].
+///
+/// Each method that should be reachable needs a resource extractor
+/// registered with [`Self::with_resource`]. Calls to any other method are
+/// denied without evaluating the checker, so a newly added RPC stays closed
+/// until it is given a rule.
+///
+/// A granted call carries its [`AccessEvaluation`] in the request
+/// extensions, where a handler reads it with
+/// `request.extensions().get::<AccessEvaluation>()`. A denied call gets
+/// `Status::permission_denied` with the denial reason; the formatted trace
+/// is appended only after [`Self::with_trace_in_status`], since the status
+/// message goes back to the client.
+pub struct GrpcAuthLayer<D: PolicyDomain> {
+    checker: PermissionChecker<D>,
+    facts: FactRegistry,
+    subject: MetadataFn<D::Subject>,
+    action: ActionFn<D::Action>,
+    context: MetadataFn<D::Context>,
+    resources: Arc<HashMap<String, MetadataFn<D::Resource>>>,
+    trace_in_status: bool,
+}
+
+impl<D: PolicyDomain> GrpcAuthLayer<D> {
+    /// Creates a layer that checks calls with `checker`.
+    ///
+    /// `subject` and `context` read the request metadata; `action` maps the
+    /// fully-qualified method name, such as `/billing.Invoices/Get`, to the
+    /// domain's action.
+    pub fn new<S, A, C>(checker: PermissionChecker<D>, subject: S, action: A, context: C) -> Self
+    where
+        S: Fn(&MetadataMap) -> D::Subject + Send + Sync + 'static,
+        A: Fn(&str) -> D::Action + Send + Sync + 'static,
+        C: Fn(&MetadataMap) -> D::Context + Send + Sync + 'static,
+    {
+        Self {
+            checker,
+            facts: FactRegistry::new(),
+            subject: Arc::new(subject),
+            action: Arc::new(action),
+            context: Arc::new(context),
+            resources: Arc::new(HashMap::new()),
+            trace_in_status: false,
+        }
+    }
+
+    /// Registers the resource extractor for `method`, the fully-qualified
+    /// method name, replacing any earlier one.
+    pub fn with_resource<R>(mut self, method: impl Into<String>, resource: R) -> Self
+    where
+        R: Fn(&MetadataMap) -> D::Resource + Send + Sync + 'static,
+    {
+        Arc::make_mut(&mut self.resources).insert(method.into(), Arc::new(resource));
+        self
+    }
+
+    /// Loads facts for each call from a session of `facts`.
+    pub fn with_facts(mut self, facts: FactRegistry) -> Self {
+        self.facts = facts;
+        self
+    }
+
+    /// Appends the formatted evaluation trace to `PERMISSION_DENIED`
+    /// messages.
+    pub fn with_trace_in_status(mut self) -> Self {
+        self.trace_in_status = true;
+        self
+    }
+}
+
+impl<D: PolicyDomain> Clone for GrpcAuthLayer<D> {
+    fn clone(&self) -> Self {
+        Self {
+            checker: self.checker.clone(),
+            facts: self.facts.clone(),
+            subject: Arc::clone(&self.subject),
+            action: Arc::clone(&self.action),
+            context: Arc::clone(&self.context),
+            resources: Arc::clone(&self.resources),
+            trace_in_status: self.trace_in_status,
+        }
+    }
+}
+
+impl<S, D: PolicyDomain> Layer<S> for GrpcAuthLayer<D> {
+    type Service = GrpcAuthService<S, D>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcAuthService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// The service produced by [`GrpcAuthLayer`].
+pub struct GrpcAuthService<S, D: PolicyDomain> {
+    inner: S,
+    layer: GrpcAuthLayer<D>,
+}
+
+impl<S: Clone, D: PolicyDomain> Clone for GrpcAuthService<S, D> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            layer: self.layer.clone(),
+        }
+    }
+}
+
+impl<S, D, ReqBody, ResBody> Service<Request<ReqBody>> for GrpcAuthService<S, D>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    D: PolicyDomain,
+    ReqBody: Send + 'static,
+    ResBody: Default,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let method = request.uri().path();
+        let Some(resource) = self.layer.resources.get(method) else {
+            let status = Status::permission_denied(format!(
+                "No authorization rule is registered for {method}"
+            ));
+            return Box::pin(async move { Ok(status.into_http()) });
+        };
+        let metadata = MetadataMap::from_headers(request.headers().clone());
+        let resource = resource(&metadata);
+        let subject = (self.layer.subject)(&metadata);
+        let action = (self.layer.action)(method);
+        let context = (self.layer.context)(&metadata);
+        let checker = self.layer.checker.clone();
+        let session = self.layer.facts.session();
+        let trace_in_status = self.layer.trace_in_status;
+        // Call the instance that was driven to readiness, leaving a fresh
+        // clone behind for the next request.
+        let ready = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, ready);
+
+        Box::pin(async move {
+            let evaluation = checker
+                .bind(&session, &subject, &action, &context)
+                .check(&resource)
+                .await;
+            match evaluation {
+                AccessEvaluation::Granted { .. } => {
+                    request.extensions_mut().insert(evaluation);
+                    inner.call(request).await
+                }
+                AccessEvaluation::Denied { reason, trace } => {
+                    let message = if trace_in_status {
+                        format!("{reason}\n\n{}", trace.format())
+                    } else {
+                        reason
+                    };
+                    Ok(Status::permission_denied(message).into_http())
+                }
+            }
+        })
+    }
+}
@@ -0,0 +1,169 @@
+//! A [`tower`](https://docs.rs/tower) middleware that authorizes requests
+//! before they reach the wrapped service.
+//!
+//! [`GatehouseLayer`] extracts the subject, action, resource, and context
+//! from each request, checks them against a [`PermissionChecker`], and
+//! answers `403 Forbidden` itself on denial, so the wrapped handler only
+//! runs for authorized requests. The granting [`AccessEvaluation`] is
+//! inserted into the request's extensions; in axum, read it with
+//! `Extension<AccessEvaluation>` to get at obligations or the trace.
+//!
+//! The layer works with any service over [`http::Request`] whose response
+//! body can be built from a `String`, which covers axum, hyper, and
+//! tower-http stacks.
+
+use crate::{AccessEvaluation, FactRegistry, PermissionChecker, PolicyDomain};
+use http::{header, Request, Response, StatusCode};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// Authorizes every request with a [`PermissionChecker`] before calling the
+/// inner service.
+///
+/// `extract` maps a request to the `(subject, action, resource, context)`
+/// tuple to check. It runs before the body is read, so it sees the method,
+/// URI, headers, and extensions set by earlier layers (an authenticated
+/// user, say). Each request gets a fresh session from the layer's
+/// [`FactRegistry`].
+///
+/// A denied request is answered with `403 Forbidden` and a `text/plain` body
+/// holding the denial reason. The evaluation trace is left out unless
+/// [`Self::with_trace_in_response`] is set, since it names policies and
+/// their reasons to whoever made the request.
+pub struct GatehouseLayer<D: PolicyDomain, F> {
+    checker: PermissionChecker<D>,
+    facts: FactRegistry,
+    extract: Arc<F>,
+    trace_in_response: bool,
+}
+
+impl<D: PolicyDomain, F> GatehouseLayer<D, F> {
+    /// Creates a layer that checks requests with `checker`, using `extract`
+    /// to build each request's inputs.
+    pub fn new(checker: PermissionChecker<D>, extract: F) -> Self {
+        Self {
+            checker,
+            facts: FactRegistry::new(),
+            extract: Arc::new(extract),
+            trace_in_response: false,
+        }
+    }
+
+    /// Loads facts for each request from a session of `facts`.
+    ///
+    /// Without this, requests are checked against an empty registry, so
+    /// fact-backed policies see every fact as unregistered.
+    pub fn with_facts(mut self, facts: FactRegistry) -> Self {
+        self.facts = facts;
+        self
+    }
+
+    /// Appends the formatted evaluation trace to `403` response bodies.
+    ///
+    /// Useful in development; leave it off where callers should not learn
+    /// how the policies are built.
+    pub fn with_trace_in_response(mut self) -> Self {
+        self.trace_in_response = true;
+        self
+    }
+}
+
+impl<D: PolicyDomain, F> Clone for GatehouseLayer<D, F> {
+    fn clone(&self) -> Self {
+        Self {
+            checker: self.checker.clone(),
+            facts: self.facts.clone(),
+            extract: Arc::clone(&self.extract),
+            trace_in_response: self.trace_in_response,
+        }
+    }
+}
+
+impl<S, D: PolicyDomain, F> Layer<S> for GatehouseLayer<D, F> {
+    type Service = GatehouseService<S, D, F>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GatehouseService {
+            inner,
+            layer: self.clone(),
+        }
+    }
+}
+
+/// The service produced by [`GatehouseLayer`].
+pub struct GatehouseService<S, D: PolicyDomain, F> {
+    inner: S,
+    layer: GatehouseLayer<D, F>,
+}
+
+impl<S: Clone, D: PolicyDomain, F> Clone for GatehouseService<S, D, F> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+            layer: self.layer.clone(),
+        }
+    }
+}
+
+impl<S, D, F, ReqBody, ResBody> Service<Request<ReqBody>> for GatehouseService<S, D, F>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>> + Clone + Send + 'static,
+    S::Future: Send,
+    D: PolicyDomain,
+    F: Fn(&Request<ReqBody>) -> (D::Subject, D::Action, D::Resource, D::Context)
+        + Send
+        + Sync
+        + 'static,
+    ReqBody: Send + 'static,
+    ResBody: From<String>,
+{
+    type Response = Response<ResBody>;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let (subject, action, resource, context) = (self.layer.extract)(&request);
+        let checker = self.layer.checker.clone();
+        let session = self.layer.facts.session();
+        let trace_in_response = self.layer.trace_in_response;
+        // Call the instance that was driven to readiness, leaving a fresh
+        // clone behind for the next request.
+        let ready = self.inner.clone();
+        let mut inner = std::mem::replace(&mut self.inner, ready);
+
+        Box::pin(async move {
+            let evaluation = checker
+                .bind(&session, &subject, &action, &context)
+                .check(&resource)
+                .await;
+            match evaluation {
+                AccessEvaluation::Granted { .. } => {
+                    request.extensions_mut().insert(evaluation);
+                    inner.call(request).await
+                }
+                AccessEvaluation::Denied { reason, trace } => {
+                    let body = if trace_in_response {
+                        format!("{reason}\n\n{}", trace.format())
+                    } else {
+                        reason
+                    };
+                    let mut response = Response::new(ResBody::from(body));
+                    *response.status_mut() = StatusCode::FORBIDDEN;
+                    response.headers_mut().insert(
+                        header::CONTENT_TYPE,
+                        header::HeaderValue::from_static("text/plain; charset=utf-8"),
+                    );
+                    Ok(response)
+                }
+            }
+        })
+    }
+}
@@ -119,18 +119,34 @@
 //!   evaluated before allow-only policies so a veto cannot be skipped by grant
 //!   short-circuiting.
 //! - If no policy forbids, the first grant wins.
-//! - If nothing grants, the checker denies with `"All policies denied access"`.
+//! - If nothing grants, the checker denies with `"All policies denied access"`,
+//!   unless the action's [`ActionClass`] was opened with
+//!   [`PermissionChecker::with_default_allow`] and no result was indeterminate.
 //! - An empty checker denies with `"No policies configured"`.
 //! - [`PolicyEvalResult::NotApplicable`] means the policy did not grant.
 //!   [`PolicyEvalResult::Forbidden`] means the policy actively vetoed.
+//!   [`PolicyEvalResult::Indeterminate`] means the policy could not decide; it
+//!   never grants, so the checker fails closed.
 //! - [`PolicyBuilder`] combines configured predicates with AND logic.
 //!   [`PolicyBuilder::forbid`] makes a matching built policy forbid; a
 //!   non-match remains not applicable and does not block.
 //! - [`AndPolicy`] and [`OrPolicy`] evaluate veto-capable children before
 //!   allow-only children, then short-circuit normally. [`NotPolicy`] inverts
-//!   grants and non-grants, but never turns `Forbidden` into a grant.
+//!   grants and non-grants, but never turns `Forbidden` or `Indeterminate`
+//!   into a grant.
+//! - [`AtMostPolicy`] grants when at least one and at most `max_grants`
+//!   children grant, so overlapping privileged paths deny.
+//! - [`ThresholdPolicy`] grants when at least `threshold` children grant, for
+//!   N-of-M approval flows.
+//! - [`XorPolicy`] grants when exactly one child grants, or an odd number of
+//!   children in [`XorMode::Odd`].
+//! - [`WeightedOrPolicy`] grants on the first child grant whose weight reaches
+//!   the floor; weaker grants are recorded but do not decide.
+//! - [`TieredPolicy`] evaluates tiers highest priority first. The first tier
+//!   that forbids or grants settles the outcome, so a higher-tier forbid beats
+//!   a lower-tier grant and a higher-tier grant beats a lower-tier forbid.
 //! - `Forbidden` propagates through [`AndPolicy`], [`OrPolicy`], [`NotPolicy`],
-//!   and [`DelegatingPolicy`].
+//!   [`AtMostPolicy`], and [`DelegatingPolicy`].
 //! - [`NotPolicy`] does not neutralize a veto. `admin.or(blocked.not())` still
 //!   denies when `blocked` returns `Forbidden`. For "grant unless blocked", use
 //!   an allow-only `blocked` predicate under `not()`, or register an explicit
@@ -176,6 +192,8 @@
 //!   roles for the `(action, resource)` pair.
 //! - [`RebacPolicy`]: relationship-based access control backed by
 //!   [`FactSource`] and [`EvaluationSession`].
+//! - [`TransitiveRebacPolicy`]: ReBAC that follows intermediate objects, such
+//!   as group membership, up to a maximum depth.
 //! - [`DelegatingPolicy`]: maps the current inputs into another
 //!   [`PolicyDomain`] and delegates to a child [`PermissionChecker`].
 //!
@@ -225,34 +243,111 @@
 //! single-resource and batch evaluation, and each evaluated policy records a
 //! `trace!` event on the `gatehouse::security` target. Batch evaluation also
 //! records per-policy counts on nested `gatehouse.batch_policy` spans.
+//! Single-resource evaluation wraps each policy, and each child of a built-in
+//! combinator, in a `gatehouse.policy` span with `policy.type`, `outcome`, and
+//! `reason` fields, so the spans nest like the result tree.
 
 #![warn(missing_docs)]
 #![allow(clippy::type_complexity)]
 
+mod annotated;
 mod builder;
 mod checker;
 mod combinators;
+mod compiled;
+mod context;
+#[cfg(feature = "tokio")]
+mod deadline;
+mod diff;
+#[cfg(feature = "serde")]
+mod document;
+mod enforcer;
+mod entropy;
 mod facts;
+mod fields;
+#[cfg(feature = "serde")]
+mod forbidden;
+pub mod global_policies;
+#[cfg(feature = "tonic")]
+mod grpc;
+#[cfg(feature = "tower")]
+mod layer;
 mod lookup;
+mod memo;
 mod metadata;
+mod observer;
 mod policies;
 mod policy;
+#[cfg(feature = "serde")]
+mod recording;
+#[cfg(any(feature = "axum", feature = "actix"))]
+mod response;
 mod results;
+mod scored;
 mod session;
+mod subject_only;
 
+pub use annotated::{AnnotatedNode, AnnotatedTree, NodeOutcome};
 pub use builder::PolicyBuilder;
-pub use checker::{BoundEvaluator, PermissionChecker};
-pub use combinators::{AndPolicy, EmptyPoliciesError, NotPolicy, OrPolicy, PolicyExt};
-pub use facts::{FactKey, FactLoadError, FactLoadResult, FactSource, RelationshipQuery};
+pub use checker::{
+    ActionClass, BoundEvaluator, CombineStrategy, Coverage, Explanation, PermissionChecker,
+    PermissionCheckerBuilder,
+};
+pub use combinators::{
+    AndPolicy, AtMostPolicy, CombinatorBuilder, EmptyPoliciesError, InvalidThresholdError,
+    NotPolicy, OrPolicy, PolicyExt, ThresholdPolicy, TieredPolicy, WeightedOrPolicy, XorMode,
+    XorPolicy,
+};
+pub use compiled::CompiledPolicy;
+pub use context::{ProvidesAuthTime, ProvidesTenant, ProvidesTime};
+#[cfg(feature = "tokio")]
+pub use deadline::with_deadline;
+pub use diff::{diff_checkers, Divergence};
+#[cfg(feature = "serde")]
+pub use document::{ConditionRegistry, PolicyDocument, PolicyDocumentError, PolicyNode};
+pub use enforcer::Enforcer;
+pub use entropy::{EntropySource, FixedEntropy, SystemEntropy};
+pub use facts::{
+    CachedSource, DeclaredFacts, FactCacheObserver, FactKey, FactLoadError, FactLoadResult,
+    FactSource, InMemoryRelationshipSource, NotSource, RelatedSubjects, RelationRouter,
+    RelationshipQuery,
+};
+pub use fields::{FieldDecision, FieldPolicy};
+#[cfg(feature = "serde")]
+pub use forbidden::ForbiddenResponse;
+#[cfg(feature = "tonic")]
+pub use grpc::{GrpcAuthLayer, GrpcAuthService};
+#[cfg(feature = "tower")]
+pub use layer::{GatehouseLayer, GatehouseService};
 pub use lookup::{Hydrator, LookupAuthorizedError, LookupAuthorizedPage, LookupPage, LookupSource};
 pub use metadata::SecurityRuleMetadata;
 pub(crate) use metadata::{DEFAULT_SECURITY_RULE_CATEGORY, PERMISSION_CHECKER_POLICY_TYPE};
-pub use policies::{DelegatingPolicy, RbacPolicy, RebacPolicy};
-pub use policy::{BatchEvalCtx, Effect, EvalCtx, Policy, PolicyBatchItem, PolicyDomain};
+pub use observer::PolicyObserver;
+#[cfg(feature = "tokio")]
+pub use policies::TimeoutPolicy;
+pub use policies::{
+    AbacPolicy, AsyncAbacPolicy, AttributeExpr, AttributeValue, Attributes, AuditOnDeny, AuditSink,
+    CachedDecisionPolicy, CachedPolicy, ConditionFuture, ContextTenantPolicy, DecisionCache,
+    DelegatingPolicy, IdentifiedPolicy, ImpersonationPolicy, MapContext, MapResource, MapSubject,
+    MappedPolicy, RbacPolicy, RebacPolicy, RemoteError, RemoteFuture, RemotePolicy, RolloutPolicy,
+    SchedulePolicy, ScheduleWindow, TenantIsolationPolicy, TimeWindowPolicy, TransitiveRebacPolicy,
+    Weekday,
+};
+pub use policy::{
+    BatchEvalCtx, Effect, EvalCtx, Policy, PolicyBatchItem, PolicyComposition, PolicyDomain,
+};
+#[cfg(feature = "serde")]
+pub use recording::{EvaluationRecord, RecordSink, RecordingChecker};
+#[cfg(any(feature = "axum", feature = "actix"))]
+pub use response::{AccessResponse, DenialBody, ObligationHandlers};
 pub use results::{
-    AccessEvaluation, CombineOp, EvalTrace, FactOutcome, FactProvenance, PolicyEvalResult,
+    AccessDenied, AccessEvaluation, CombineOp, Decision, DecisionSummary, EvalStats, EvalTrace,
+    FactOutcome, FactProvenance, FormatOptions, Obligation, PolicyEvalResult, TraceSymbols,
+    TraceVisitor,
 };
+pub use scored::{ScoreChecker, ScoredPolicy};
 pub use session::{EvaluationSession, FactRegistry, FactRegistryBuilder};
+pub use subject_only::{SubjectOnly, SubjectOnlyChecker};
 
 // The shared unit-test module pulls in tokio-based async tests via dev-deps
 // that are intentionally loom-incompatible (`tokio::net`, axum, hyper, etc.).
@@ -0,0 +1,185 @@
+use crate::policies::mark_cached;
+use crate::{AccessEvaluation, PolicyDomain};
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime};
+
+/// The evaluation memo behind [`crate::PermissionChecker::with_memoization`].
+///
+/// Object-safe so the checker can hold one without requiring `Hash + Eq`
+/// inputs of every domain.
+pub(crate) trait EvaluationMemo<D: PolicyDomain>: Send + Sync {
+    fn lookup(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> Option<AccessEvaluation>;
+
+    fn store(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        evaluation: &AccessEvaluation,
+    );
+
+    fn clear(&self);
+
+    /// An empty memo with the same capacity and TTL, for a checker whose
+    /// policies changed. Clones of the checker keep the old memo.
+    fn emptied(&self) -> Arc<dyn EvaluationMemo<D>>;
+}
+
+type MemoKey<D> = (
+    <D as PolicyDomain>::Subject,
+    <D as PolicyDomain>::Action,
+    <D as PolicyDomain>::Resource,
+    <D as PolicyDomain>::Context,
+);
+
+struct MemoEntry {
+    expires_at: Instant,
+    evaluation: AccessEvaluation,
+}
+
+/// Evaluations keyed by their `(subject, action, resource, context)` inputs.
+///
+/// Expiry and eviction follow [`crate::CachedPolicy`]: each entry lives for
+/// `ttl` or until the grant's `valid_until`, whichever is sooner, and a full
+/// memo drops expired entries and then the entry closest to expiry.
+pub(crate) struct MemoCache<D: PolicyDomain> {
+    capacity: usize,
+    ttl: Duration,
+    entries: Mutex<HashMap<MemoKey<D>, MemoEntry>>,
+}
+
+impl<D> MemoCache<D>
+where
+    D: PolicyDomain,
+    D::Subject: Hash + Eq + Clone,
+    D::Action: Hash + Eq + Clone,
+    D::Resource: Hash + Eq + Clone,
+    D::Context: Hash + Eq + Clone,
+{
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<MemoKey<D>, MemoEntry>> {
+        self.entries
+            .lock()
+            .expect("evaluation memo mutex should not be poisoned")
+    }
+
+    fn key(
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> MemoKey<D> {
+        (
+            subject.clone(),
+            action.clone(),
+            resource.clone(),
+            context.clone(),
+        )
+    }
+}
+
+impl<D> EvaluationMemo<D> for MemoCache<D>
+where
+    D: PolicyDomain,
+    D::Subject: Hash + Eq + Clone,
+    D::Action: Hash + Eq + Clone,
+    D::Resource: Hash + Eq + Clone,
+    D::Context: Hash + Eq + Clone,
+{
+    fn lookup(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> Option<AccessEvaluation> {
+        let key = Self::key(subject, action, resource, context);
+        let mut entries = self.lock();
+        let entry = entries.get(&key)?;
+        if entry.expires_at <= Instant::now() {
+            entries.remove(&key);
+            return None;
+        }
+        let mut evaluation = entry.evaluation.clone();
+        drop(entries);
+        let trace = match &mut evaluation {
+            AccessEvaluation::Granted { trace, .. } | AccessEvaluation::Denied { trace, .. } => {
+                trace
+            }
+        };
+        if let Some(root) = trace.root().cloned() {
+            trace.set_root(mark_cached(root));
+        }
+        Some(evaluation)
+    }
+
+    fn store(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        evaluation: &AccessEvaluation,
+    ) {
+        if self.capacity == 0 || evaluation.is_indeterminate() {
+            return;
+        }
+        let now = Instant::now();
+        let mut expires_at = now + self.ttl;
+        if let Some(valid_until) = evaluation.valid_until() {
+            let remaining = valid_until
+                .duration_since(SystemTime::now())
+                .unwrap_or_default();
+            expires_at = expires_at.min(now + remaining);
+        }
+        if expires_at <= now {
+            return;
+        }
+
+        let key = Self::key(subject, action, resource, context);
+        let mut entries = self.lock();
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            entries.retain(|_, entry| entry.expires_at > now);
+        }
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let soonest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.expires_at)
+                .map(|(key, _)| key.clone());
+            if let Some(soonest) = soonest {
+                entries.remove(&soonest);
+            }
+        }
+        entries.insert(
+            key,
+            MemoEntry {
+                expires_at,
+                evaluation: evaluation.clone(),
+            },
+        );
+    }
+
+    fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn emptied(&self) -> Arc<dyn EvaluationMemo<D>> {
+        Arc::new(Self::new(self.capacity, self.ttl))
+    }
+}
@@ -0,0 +1,37 @@
+//! Per-policy evaluation hooks for metrics.
+
+use crate::PolicyEvalResult;
+use std::time::Duration;
+
+/// Receives each policy's result and evaluation time from a
+/// [`crate::PermissionChecker`].
+///
+/// Install one with [`crate::PermissionChecker::with_observer`] to feed
+/// counters of grants and denials per policy and a latency histogram. The
+/// observer runs synchronously after each evaluation, so it should record and
+/// return rather than block. Checkers without an observer do not read the
+/// clock.
+///
+/// The result is the policy's own, before the checker enforces its declared
+/// [`crate::Effect`]. Policies the checker short-circuits past are not
+/// reported, and a policy that panics is not reported. On the batch path each
+/// item's result is reported with the duration of the batch call that
+/// produced it.
+pub trait PolicyObserver: Send + Sync {
+    /// Records that `policy_type` returned `result` after `duration`.
+    fn on_policy_evaluated(&self, policy_type: &str, result: &PolicyEvalResult, duration: Duration);
+}
+
+impl<F> PolicyObserver for F
+where
+    F: Fn(&str, &PolicyEvalResult, Duration) + Send + Sync,
+{
+    fn on_policy_evaluated(
+        &self,
+        policy_type: &str,
+        result: &PolicyEvalResult,
+        duration: Duration,
+    ) {
+        self(policy_type, result, duration)
+    }
+}
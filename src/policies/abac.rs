@@ -0,0 +1,369 @@
+use crate::{EvalCtx, Policy, PolicyDomain, PolicyEvalResult};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::future::Future;
+use std::marker::PhantomData;
+use std::pin::Pin;
+
+const CONDITION_HOLDS_REASON: &str = "Policy allowed access";
+const CONDITION_FAILS_REASON: &str = "Policy predicate did not match";
+
+/// The future an [`AsyncAbacPolicy`] condition returns.
+pub type ConditionFuture<'a> = Pin<Box<dyn Future<Output = bool> + Send + 'a>>;
+
+type AsyncCondition<D> = Box<
+    dyn for<'a> Fn(
+            &'a <D as PolicyDomain>::Subject,
+            &'a <D as PolicyDomain>::Action,
+            &'a <D as PolicyDomain>::Resource,
+            &'a <D as PolicyDomain>::Context,
+        ) -> ConditionFuture<'a>
+        + Send
+        + Sync,
+>;
+
+/// One attribute value read by an [`AttributeExpr`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+#[non_exhaustive]
+pub enum AttributeValue {
+    /// A string, such as a role or a status.
+    String(String),
+    /// A number. Integers are stored as `f64`.
+    Number(f64),
+    /// A flag.
+    Bool(bool),
+}
+
+impl From<&str> for AttributeValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for AttributeValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+impl From<f64> for AttributeValue {
+    fn from(value: f64) -> Self {
+        Self::Number(value)
+    }
+}
+
+impl From<i64> for AttributeValue {
+    fn from(value: i64) -> Self {
+        Self::Number(value as f64)
+    }
+}
+
+impl From<bool> for AttributeValue {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl fmt::Display for AttributeValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(value) => write!(f, "{value:?}"),
+            Self::Number(value) => write!(f, "{value}"),
+            Self::Bool(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+/// The named attribute values an [`AbacPolicy`] evaluates its expression
+/// against.
+///
+/// Names are free-form; prefixing them with their source, such as
+/// `subject.department` or `resource.amount`, keeps expressions readable.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Attributes {
+    values: BTreeMap<String, AttributeValue>,
+}
+
+impl Attributes {
+    /// Creates an empty attribute map.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets `name` to `value`, replacing any earlier value.
+    pub fn with(mut self, name: impl Into<String>, value: impl Into<AttributeValue>) -> Self {
+        self.insert(name, value);
+        self
+    }
+
+    /// Sets `name` to `value`, replacing any earlier value.
+    pub fn insert(&mut self, name: impl Into<String>, value: impl Into<AttributeValue>) {
+        self.values.insert(name.into(), value.into());
+    }
+
+    /// Returns the value of `name`, if set.
+    pub fn get(&self, name: &str) -> Option<&AttributeValue> {
+        self.values.get(name)
+    }
+}
+
+/// A boolean expression over [`Attributes`].
+///
+/// Comparisons name an attribute and a literal. A comparison whose attribute
+/// is missing, or holds a value of another type (`Gt` and `Lt` need numbers),
+/// is unknown rather than false. Unknown propagates: `Not` of unknown is
+/// unknown, `And` is false if any operand is false and otherwise unknown if
+/// any is, and `Or` is true if any operand is true and otherwise unknown if
+/// any is. An [`AbacPolicy`] grants only when the expression is true, so a
+/// missing attribute can never grant through `Not`.
+///
+/// With the `serde` feature the expression is externally tagged, for example
+/// `{ "and": [{ "eq": ["subject.department", "finance"] }, { "lt":
+/// ["resource.amount", 10000] }] }`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[non_exhaustive]
+pub enum AttributeExpr {
+    /// The attribute equals the value.
+    Eq(String, AttributeValue),
+    /// The numeric attribute is greater than the value.
+    Gt(String, f64),
+    /// The numeric attribute is less than the value.
+    Lt(String, f64),
+    /// The attribute equals one of the values.
+    In(String, Vec<AttributeValue>),
+    /// Every inner expression holds. An empty list is true.
+    And(Vec<AttributeExpr>),
+    /// Any inner expression holds. An empty list is false.
+    Or(Vec<AttributeExpr>),
+    /// The inner expression does not hold.
+    Not(Box<AttributeExpr>),
+}
+
+impl AttributeExpr {
+    /// Evaluates the expression, returning `None` when it is unknown.
+    pub fn evaluate(&self, attributes: &Attributes) -> Option<bool> {
+        match self {
+            Self::Eq(name, expected) => {
+                let value = attributes.get(name)?;
+                same_type(value, expected).then(|| value == expected)
+            }
+            Self::Gt(name, bound) => number(attributes, name).map(|value| value > *bound),
+            Self::Lt(name, bound) => number(attributes, name).map(|value| value < *bound),
+            Self::In(name, allowed) => {
+                let value = attributes.get(name)?;
+                if allowed.contains(value) {
+                    Some(true)
+                } else if allowed.iter().all(|expected| same_type(value, expected)) {
+                    Some(false)
+                } else {
+                    None
+                }
+            }
+            Self::And(exprs) => {
+                let mut known = true;
+                for expr in exprs {
+                    match expr.evaluate(attributes) {
+                        Some(false) => return Some(false),
+                        Some(true) => {}
+                        None => known = false,
+                    }
+                }
+                known.then_some(true)
+            }
+            Self::Or(exprs) => {
+                let mut known = true;
+                for expr in exprs {
+                    match expr.evaluate(attributes) {
+                        Some(true) => return Some(true),
+                        Some(false) => {}
+                        None => known = false,
+                    }
+                }
+                known.then_some(false)
+            }
+            Self::Not(expr) => expr.evaluate(attributes).map(|holds| !holds),
+        }
+    }
+}
+
+fn same_type(a: &AttributeValue, b: &AttributeValue) -> bool {
+    std::mem::discriminant(a) == std::mem::discriminant(b)
+}
+
+fn number(attributes: &Attributes, name: &str) -> Option<f64> {
+    match attributes.get(name)? {
+        AttributeValue::Number(value) => Some(*value),
+        AttributeValue::String(_) | AttributeValue::Bool(_) => None,
+    }
+}
+
+impl fmt::Display for AttributeExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn join(
+            f: &mut fmt::Formatter<'_>,
+            exprs: &[AttributeExpr],
+            separator: &str,
+        ) -> fmt::Result {
+            f.write_str("(")?;
+            for (index, expr) in exprs.iter().enumerate() {
+                if index > 0 {
+                    f.write_str(separator)?;
+                }
+                write!(f, "{expr}")?;
+            }
+            f.write_str(")")
+        }
+
+        match self {
+            Self::Eq(name, value) => write!(f, "{name} == {value}"),
+            Self::Gt(name, bound) => write!(f, "{name} > {bound}"),
+            Self::Lt(name, bound) => write!(f, "{name} < {bound}"),
+            Self::In(name, values) => {
+                write!(f, "{name} in [")?;
+                for (index, value) in values.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(", ")?;
+                    }
+                    write!(f, "{value}")?;
+                }
+                f.write_str("]")
+            }
+            Self::And(exprs) => join(f, exprs, " && "),
+            Self::Or(exprs) => join(f, exprs, " || "),
+            Self::Not(expr) => write!(f, "!({expr})"),
+        }
+    }
+}
+
+/// Attribute-based access control policy over an [`AttributeExpr`].
+///
+/// The extractor receives `(subject, action, resource, context)` and returns
+/// the [`Attributes`] the expression reads. Access is granted when the
+/// expression is true; false and unknown are
+/// [`PolicyEvalResult::NotApplicable`]. Reasons include the expression, so
+/// the trace shows which rule was checked.
+///
+/// Because the expression is data, it can be loaded from configuration or a
+/// [`crate::PolicyDocument`] `expr` node without recompiling; only the
+/// extractor is code.
+pub struct AbacPolicy<D: PolicyDomain, F> {
+    expr: AttributeExpr,
+    extractor: F,
+    _domain: PhantomData<D>,
+}
+
+impl<D: PolicyDomain, F> AbacPolicy<D, F> {
+    /// Creates an ABAC policy that evaluates `expr` against the attributes
+    /// returned by `extractor`.
+    pub fn from_expr(expr: AttributeExpr, extractor: F) -> Self {
+        Self {
+            expr,
+            extractor,
+            _domain: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, F> Policy<D> for AbacPolicy<D, F>
+where
+    D: PolicyDomain,
+    F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> Attributes + Sync + Send,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let attributes = (self.extractor)(ctx.subject, ctx.action, ctx.resource, ctx.context);
+        match self.expr.evaluate(&attributes) {
+            Some(true) => ctx.grant(format!("Attribute expression holds: {}", self.expr)),
+            Some(false) => {
+                ctx.not_applicable(format!("Attribute expression does not hold: {}", self.expr))
+            }
+            None => ctx.not_applicable(format!(
+                "Attribute expression is unknown (missing or mistyped attribute): {}",
+                self.expr
+            )),
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        Cow::Borrowed("AbacPolicy")
+    }
+}
+
+/// Attribute-based access control policy whose condition is async.
+///
+/// The asynchronous counterpart of a [`crate::PolicyBuilder::when`]
+/// predicate, for rules that need IO such as a database lookup. The
+/// condition returns a boxed future resolving to whether access is granted,
+/// and the result reads like a builder policy's: a grant with "Policy
+/// allowed access", or [`PolicyEvalResult::NotApplicable`] with "Policy
+/// predicate did not match", under the given name. For a condition that
+/// must report its own reason or fail closed on errors, use
+/// [`crate::RemotePolicy`], which resolves to a [`PolicyEvalResult`].
+///
+/// ```rust
+/// use gatehouse::*;
+///
+/// struct Documents;
+/// impl PolicyDomain for Documents {
+///     type Subject = u64;
+///     type Action = ();
+///     type Resource = u64;
+///     type Context = ();
+/// }
+///
+/// let shared = AsyncAbacPolicy::<Documents>::new("SharedWithUser", |user, _action, doc, _ctx| {
+///     let (user, doc) = (*user, *doc);
+///     Box::pin(async move {
+///         // Look the share up in the database here.
+///         user == 1 && doc == 7
+///     })
+/// });
+/// # let _ = shared;
+/// ```
+pub struct AsyncAbacPolicy<D: PolicyDomain> {
+    name: Cow<'static, str>,
+    condition: AsyncCondition<D>,
+}
+
+impl<D: PolicyDomain> AsyncAbacPolicy<D> {
+    /// Creates a policy named `name` that grants when `condition` resolves
+    /// to `true`.
+    pub fn new<F>(name: impl Into<Cow<'static, str>>, condition: F) -> Self
+    where
+        F: for<'a> Fn(
+                &'a D::Subject,
+                &'a D::Action,
+                &'a D::Resource,
+                &'a D::Context,
+            ) -> ConditionFuture<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            name: name.into(),
+            condition: Box::new(condition),
+        }
+    }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for AsyncAbacPolicy<D> {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        if (self.condition)(ctx.subject, ctx.action, ctx.resource, ctx.context).await {
+            ctx.grant(CONDITION_HOLDS_REASON)
+        } else {
+            ctx.not_applicable(CONDITION_FAILS_REASON)
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{
+    BatchEvalCtx, DeclaredFacts, Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult,
+    SecurityRuleMetadata,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+/// Receives the non-granting results of a policy wrapped in [`AuditOnDeny`].
+///
+/// The sink runs synchronously inside the wrapped policy's evaluation, so it
+/// should hand the event off (log line, channel, buffer) rather than block.
+pub trait AuditSink<D: PolicyDomain>: Send + Sync {
+    /// Records that the wrapped policy did not grant the request in `ctx`.
+    fn record_denial(&self, ctx: &EvalCtx<'_, D>, result: &PolicyEvalResult);
+}
+
+impl<D, F> AuditSink<D> for F
+where
+    D: PolicyDomain,
+    F: Fn(&EvalCtx<'_, D>, &PolicyEvalResult) + Send + Sync,
+{
+    fn record_denial(&self, ctx: &EvalCtx<'_, D>, result: &PolicyEvalResult) {
+        self(ctx, result)
+    }
+}
+
+/// Wraps a policy so every result that is not a grant (not applicable or
+/// forbidden) is reported to an [`AuditSink`] before it is returned.
+///
+/// The wrapper is otherwise transparent: it returns the inner result
+/// unchanged and reports the inner policy type, effect, and security rule, so
+/// checker decisions are identical with or without it. The audit fires as part
+/// of the wrapped policy's own evaluation, on both the single and batch paths,
+/// and does not depend on what the checker does with later policies.
+///
+/// A checker returns at the first grant once its veto-capable prefix has been
+/// evaluated, so an allow-only policy registered after one that grants is
+/// never evaluated and has nothing to audit. Register audited allow-only
+/// policies ahead of the policies that may grant.
+pub struct AuditOnDeny<D: PolicyDomain, P> {
+    inner: P,
+    sink: Arc<dyn AuditSink<D>>,
+}
+
+impl<D: PolicyDomain, P: Policy<D>> AuditOnDeny<D, P> {
+    /// Wraps `inner`, reporting its non-granting results to `sink`.
+    pub fn new(inner: P, sink: impl AuditSink<D> + 'static) -> Self {
+        Self {
+            inner,
+            sink: Arc::new(sink),
+        }
+    }
+}
+
+#[async_trait]
+impl<D, P> Policy<D> for AuditOnDeny<D, P>
+where
+    D: PolicyDomain,
+    P: Policy<D>,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let result = self.inner.evaluate(ctx).await;
+        if !result.is_granted() {
+            self.sink.record_denial(ctx, &result);
+        }
+        result
+    }
+
+    async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
+        let results = self.inner.evaluate_batch(ctx).await;
+        for (item, result) in ctx.items.iter().zip(&results) {
+            if !result.is_granted() {
+                let item_ctx = EvalCtx {
+                    session: ctx.session,
+                    subject: ctx.subject,
+                    action: ctx.action,
+                    resource: item.resource,
+                    context: ctx.context,
+                    policy_type: ctx.policy_type.clone(),
+                };
+                self.sink.record_denial(&item_ctx, result);
+            }
+        }
+        results
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        self.inner
+            .declared_facts(subject, action, resource, context, facts);
+    }
+}
@@ -0,0 +1,196 @@
+use crate::{
+    DeclaredFacts, Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult, SecurityRuleMetadata,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime};
+
+type KeyFn<D, K> = Box<
+    dyn Fn(
+            &<D as PolicyDomain>::Subject,
+            &<D as PolicyDomain>::Action,
+            &<D as PolicyDomain>::Resource,
+            &<D as PolicyDomain>::Context,
+        ) -> K
+        + Send
+        + Sync,
+>;
+
+/// Wraps an expensive, idempotent policy so its results are reused for a
+/// short window across requests.
+///
+/// `key` maps a request to a cache key; requests with equal keys share one
+/// cached result, so the key must capture everything the inner policy's
+/// decision depends on. On a hit the stored result is returned with
+/// `" (cached)"` appended to each leaf reason, so the trace shows the policy
+/// was not re-evaluated. On a miss the inner policy runs and its result is
+/// stored for `ttl`, or until the grant's
+/// [`valid_until`](PolicyEvalResult::valid_until) if that is sooner.
+/// [`PolicyEvalResult::Indeterminate`] results are never cached, so a failed
+/// backend call is retried on the next request.
+///
+/// The cache holds at most `capacity` entries. When it is full, expired
+/// entries are dropped first and then the entry closest to expiry is evicted;
+/// both scan the cache, so keep the capacity in the thousands rather than the
+/// millions. Unlike the [`crate::EvaluationSession`] cache this one outlives a
+/// request, so a revoked permission can still be granted from cache until its
+/// entry expires — choose `ttl` accordingly.
+///
+/// Like [`crate::AuditOnDeny`], the wrapper reports the inner policy type,
+/// effect, and security rule.
+pub struct CachedPolicy<D: PolicyDomain, P, K> {
+    inner: P,
+    key: KeyFn<D, K>,
+    ttl: Duration,
+    capacity: usize,
+    entries: Mutex<HashMap<K, CacheEntry>>,
+}
+
+struct CacheEntry {
+    expires_at: Instant,
+    result: PolicyEvalResult,
+}
+
+impl<D: PolicyDomain, P: Policy<D>, K: Eq + Hash + Clone> CachedPolicy<D, P, K> {
+    /// Wraps `inner`, caching up to `capacity` results for `ttl` each under
+    /// the key returned by `key`.
+    pub fn new<F>(inner: P, key: F, capacity: usize, ttl: Duration) -> Self
+    where
+        F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> K + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            key: Box::new(key),
+            ttl,
+            capacity,
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Drops every cached result, for example after a permission change that
+    /// must take effect before the TTL runs out.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<K, CacheEntry>> {
+        self.entries
+            .lock()
+            .expect("policy cache mutex should not be poisoned")
+    }
+
+    fn lookup(&self, key: &K, now: Instant) -> Option<PolicyEvalResult> {
+        let mut entries = self.lock();
+        match entries.get(key) {
+            Some(entry) if entry.expires_at > now => Some(entry.result.clone()),
+            Some(_) => {
+                entries.remove(key);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn store(&self, key: K, result: &PolicyEvalResult, now: Instant) {
+        if self.capacity == 0 || matches!(result, PolicyEvalResult::Indeterminate { .. }) {
+            return;
+        }
+        let mut expires_at = now + self.ttl;
+        if let Some(valid_until) = result.valid_until() {
+            let remaining = valid_until
+                .duration_since(SystemTime::now())
+                .unwrap_or_default();
+            expires_at = expires_at.min(now + remaining);
+        }
+        if expires_at <= now {
+            return;
+        }
+
+        let mut entries = self.lock();
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            entries.retain(|_, entry| entry.expires_at > now);
+        }
+        if entries.len() >= self.capacity && !entries.contains_key(&key) {
+            let soonest = entries
+                .iter()
+                .min_by_key(|(_, entry)| entry.expires_at)
+                .map(|(key, _)| key.clone());
+            if let Some(soonest) = soonest {
+                entries.remove(&soonest);
+            }
+        }
+        entries.insert(
+            key,
+            CacheEntry {
+                expires_at,
+                result: result.clone(),
+            },
+        );
+    }
+}
+
+#[async_trait]
+impl<D, P, K> Policy<D> for CachedPolicy<D, P, K>
+where
+    D: PolicyDomain,
+    P: Policy<D>,
+    K: Eq + Hash + Clone + Send + Sync + 'static,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let key = (self.key)(ctx.subject, ctx.action, ctx.resource, ctx.context);
+        if let Some(cached) = self.lookup(&key, Instant::now()) {
+            return mark_cached(cached);
+        }
+        let result = self.inner.evaluate(ctx).await;
+        self.store(key, &result, Instant::now());
+        result
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        self.inner
+            .declared_facts(subject, action, resource, context, facts);
+    }
+}
+
+pub(crate) fn mark_cached(mut result: PolicyEvalResult) -> PolicyEvalResult {
+    match &mut result {
+        PolicyEvalResult::Granted { reason, .. } => {
+            *reason = Some(match reason.take() {
+                Some(reason) => format!("{reason} (cached)"),
+                None => "(cached)".to_string(),
+            });
+        }
+        PolicyEvalResult::NotApplicable { reason, .. }
+        | PolicyEvalResult::Forbidden { reason, .. }
+        | PolicyEvalResult::Indeterminate { reason, .. } => reason.push_str(" (cached)"),
+        PolicyEvalResult::Combined { children, .. } => {
+            *children = std::mem::take(children)
+                .into_iter()
+                .map(mark_cached)
+                .collect();
+        }
+    }
+    result
+}
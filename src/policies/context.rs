@@ -0,0 +1,307 @@
+use crate::{
+    Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult, ProvidesTenant, ProvidesTime,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::fmt;
+use std::marker::PhantomData;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Grants while the context's time is inside `[start, end)`.
+///
+/// The time comes from [`ProvidesTime`], so any context carrying the request
+/// time works. A grant is marked
+/// [`valid_until`](PolicyEvalResult::with_valid_until) `end`, so callers
+/// minting tokens from it can cap their lifetime; outside the window the
+/// result is [`PolicyEvalResult::NotApplicable`].
+pub struct TimeWindowPolicy<D: PolicyDomain> {
+    start: SystemTime,
+    end: SystemTime,
+    _domain: PhantomData<D>,
+}
+
+impl<D: PolicyDomain> TimeWindowPolicy<D> {
+    /// Creates a policy that grants from `start` until just before `end`.
+    pub fn new(start: SystemTime, end: SystemTime) -> Self {
+        Self {
+            start,
+            end,
+            _domain: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D> Policy<D> for TimeWindowPolicy<D>
+where
+    D: PolicyDomain,
+    D::Context: ProvidesTime,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let now = ctx.context.now();
+        if now < self.start {
+            ctx.not_applicable("Time window has not opened")
+        } else if now >= self.end {
+            ctx.not_applicable("Time window has closed")
+        } else {
+            ctx.grant("Inside the time window")
+                .with_valid_until(self.end)
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        Cow::Borrowed("TimeWindowPolicy")
+    }
+}
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A day of the week, for [`ScheduleWindow`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Weekday {
+    /// Monday.
+    Monday,
+    /// Tuesday.
+    Tuesday,
+    /// Wednesday.
+    Wednesday,
+    /// Thursday.
+    Thursday,
+    /// Friday.
+    Friday,
+    /// Saturday.
+    Saturday,
+    /// Sunday.
+    Sunday,
+}
+
+impl Weekday {
+    /// Monday to Friday.
+    pub const WEEKDAYS: [Weekday; 5] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+    ];
+
+    /// Every day of the week, starting on Monday.
+    pub const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// The weekday of `day`, counted in days since 1970-01-01, a Thursday.
+    fn of_day(day: i64) -> Self {
+        Self::ALL[(day + 3).rem_euclid(7) as usize]
+    }
+}
+
+impl fmt::Display for Weekday {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// Recurring local times on chosen days of the week, for [`SchedulePolicy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleWindow {
+    days: Vec<Weekday>,
+    start: i64,
+    end: i64,
+}
+
+impl ScheduleWindow {
+    /// Opens on each of `days` from `start` until just before `end`, both
+    /// `(hour, minute)` local times.
+    ///
+    /// The window ends on the day it starts: an `end` at or before `start`
+    /// never opens, so split an overnight window in two, ending the first at
+    /// `(24, 0)`.
+    pub fn new(days: impl IntoIterator<Item = Weekday>, start: (u8, u8), end: (u8, u8)) -> Self {
+        let seconds = |(hour, minute): (u8, u8)| i64::from(hour) * 3600 + i64::from(minute) * 60;
+        Self {
+            days: days.into_iter().collect(),
+            start: seconds(start),
+            end: seconds(end),
+        }
+    }
+
+    fn contains(&self, day: i64, second_of_day: i64) -> bool {
+        self.days.contains(&Weekday::of_day(day)) && (self.start..self.end).contains(&second_of_day)
+    }
+
+    /// The first local second after `local` at which this window opens.
+    fn next_opening(&self, local: i64) -> Option<i64> {
+        if self.start >= self.end {
+            return None;
+        }
+        let today = local.div_euclid(SECONDS_PER_DAY);
+        (0..=7)
+            .map(|offset| today + offset)
+            .filter(|day| self.days.contains(&Weekday::of_day(*day)))
+            .map(|day| day * SECONDS_PER_DAY + self.start)
+            .find(|opening| *opening > local)
+    }
+}
+
+/// Grants while the context's time falls in one of a set of recurring
+/// weekly windows, such as business hours.
+///
+/// The time comes from [`ProvidesTime`], like [`TimeWindowPolicy`], and is
+/// shifted by a fixed UTC offset before it is compared with the windows'
+/// local times. A grant is marked
+/// [`valid_until`](PolicyEvalResult::with_valid_until) the end of its window.
+/// Outside every window the result is [`PolicyEvalResult::NotApplicable`],
+/// and its reason names the next time access would be permitted.
+///
+/// The offset is fixed, so it does not follow daylight-saving changes;
+/// rebuild the policy when the offset changes.
+pub struct SchedulePolicy<D: PolicyDomain> {
+    windows: Vec<ScheduleWindow>,
+    utc_offset_minutes: i32,
+    _domain: PhantomData<D>,
+}
+
+impl<D: PolicyDomain> SchedulePolicy<D> {
+    /// Creates a policy that grants inside any of `windows`, read in the
+    /// timezone `utc_offset_minutes` east of UTC (for example `600` for
+    /// UTC+10:00, `-300` for UTC-05:00).
+    pub fn new(windows: Vec<ScheduleWindow>, utc_offset_minutes: i32) -> Self {
+        Self {
+            windows,
+            utc_offset_minutes,
+            _domain: PhantomData,
+        }
+    }
+
+    fn offset_seconds(&self) -> i64 {
+        i64::from(self.utc_offset_minutes) * 60
+    }
+
+    /// Describes a local second as `"{weekday} {hh:mm} (UTC{±hh:mm})"`.
+    fn describe(&self, local: i64) -> String {
+        let second_of_day = local.rem_euclid(SECONDS_PER_DAY);
+        let offset = self.utc_offset_minutes;
+        let sign = if offset < 0 { '-' } else { '+' };
+        format!(
+            "{} {:02}:{:02} (UTC{sign}{:02}:{:02})",
+            Weekday::of_day(local.div_euclid(SECONDS_PER_DAY)),
+            second_of_day / 3600,
+            second_of_day % 3600 / 60,
+            offset.unsigned_abs() / 60,
+            offset.unsigned_abs() % 60,
+        )
+    }
+}
+
+/// Whole seconds since the Unix epoch, rounded down.
+fn unix_seconds(time: SystemTime) -> i64 {
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(since) => since.as_secs() as i64,
+        Err(before) => {
+            let before = before.duration();
+            -(before.as_secs() as i64) - i64::from(before.subsec_nanos() > 0)
+        }
+    }
+}
+
+fn from_unix_seconds(seconds: i64) -> SystemTime {
+    if seconds >= 0 {
+        UNIX_EPOCH + Duration::from_secs(seconds as u64)
+    } else {
+        UNIX_EPOCH - Duration::from_secs(seconds.unsigned_abs())
+    }
+}
+
+#[async_trait]
+impl<D> Policy<D> for SchedulePolicy<D>
+where
+    D: PolicyDomain,
+    D::Context: ProvidesTime,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let local = unix_seconds(ctx.context.now()) + self.offset_seconds();
+        let day = local.div_euclid(SECONDS_PER_DAY);
+        let second_of_day = local.rem_euclid(SECONDS_PER_DAY);
+
+        if let Some(window) = self
+            .windows
+            .iter()
+            .find(|window| window.contains(day, second_of_day))
+        {
+            let closes = day * SECONDS_PER_DAY + window.end - self.offset_seconds();
+            return ctx
+                .grant("Inside a scheduled window")
+                .with_valid_until(from_unix_seconds(closes));
+        }
+
+        match self
+            .windows
+            .iter()
+            .filter_map(|window| window.next_opening(local))
+            .min()
+        {
+            Some(opening) => ctx.not_applicable(format!(
+                "Outside the schedule; access is next permitted {}",
+                self.describe(opening)
+            )),
+            None => ctx.not_applicable("Schedule has no open windows"),
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        Cow::Borrowed("SchedulePolicy")
+    }
+}
+
+/// Forbids access whenever the resource's tenant differs from the tenant the
+/// request is made in.
+///
+/// The context-driven counterpart of [`crate::TenantIsolationPolicy`]: the
+/// request's tenant comes from [`ProvidesTenant`] rather than from the
+/// subject. Like that policy it declares [`Effect::Forbid`] and never grants
+/// on its own.
+pub struct ContextTenantPolicy<D: PolicyDomain, F> {
+    resource_tenant: F,
+    _domain: PhantomData<D>,
+}
+
+impl<D: PolicyDomain, F> ContextTenantPolicy<D, F> {
+    /// Creates a tenant isolation policy from a resource tenant resolver.
+    pub fn new(resource_tenant: F) -> Self {
+        Self {
+            resource_tenant,
+            _domain: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, F> Policy<D> for ContextTenantPolicy<D, F>
+where
+    D: PolicyDomain,
+    D::Context: ProvidesTenant,
+    F: Fn(&D::Resource) -> <D::Context as ProvidesTenant>::TenantId + Sync + Send,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        if (self.resource_tenant)(ctx.resource) == *ctx.context.tenant_id() {
+            ctx.not_applicable("Resource belongs to the request's tenant")
+        } else {
+            ctx.forbid("Resource belongs to another tenant")
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        Cow::Borrowed("ContextTenantPolicy")
+    }
+
+    fn effect(&self) -> Effect {
+        Effect::Forbid
+    }
+}
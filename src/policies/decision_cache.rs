@@ -0,0 +1,144 @@
+use super::cached::mark_cached;
+use crate::{
+    DeclaredFacts, Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult, SecurityRuleMetadata,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+/// How long [`CachedDecisionPolicy`] keeps results unless
+/// [`CachedDecisionPolicy::with_ttl`] says otherwise.
+const DEFAULT_TTL: Duration = Duration::from_secs(60);
+
+type KeyFn<D> = Box<
+    dyn Fn(
+            &<D as PolicyDomain>::Subject,
+            &<D as PolicyDomain>::Action,
+            &<D as PolicyDomain>::Resource,
+            &<D as PolicyDomain>::Context,
+        ) -> String
+        + Send
+        + Sync,
+>;
+
+/// An external store of policy results, such as Redis, shared across
+/// processes.
+///
+/// Used by [`CachedDecisionPolicy`]. Implementations own serialization and
+/// expiry. A backend error should read as a miss from
+/// [`DecisionCache::get`] and be dropped by [`DecisionCache::put`], so an
+/// unavailable cache only costs a re-evaluation.
+#[async_trait]
+pub trait DecisionCache: Send + Sync {
+    /// Returns the result stored under `key`, if it has not expired.
+    async fn get(&self, key: &str) -> Option<PolicyEvalResult>;
+
+    /// Stores `result` under `key` for `ttl`.
+    async fn put(&self, key: &str, result: &PolicyEvalResult, ttl: Duration);
+}
+
+/// Reads a policy's results through an external [`DecisionCache`].
+///
+/// The process-local counterpart of [`crate::CachedPolicy`]: `key` maps a
+/// request to a cache key, which must capture everything the inner policy's
+/// decision depends on. On a hit the stored result is returned with
+/// `" (cached)"` appended to each leaf reason. On a miss the inner policy
+/// runs and its result is stored for the TTL, or until the grant's
+/// [`valid_until`](PolicyEvalResult::valid_until) if that is sooner.
+/// [`PolicyEvalResult::Indeterminate`] results are never stored.
+///
+/// A cached grant outlives revocation until its entry expires, and anyone
+/// who can write to the cache can grant access, so secure the cache like the
+/// policy store itself.
+///
+/// Like [`crate::AuditOnDeny`], the wrapper reports the inner policy type,
+/// effect, and security rule.
+pub struct CachedDecisionPolicy<D: PolicyDomain, P> {
+    key: KeyFn<D>,
+    cache: Arc<dyn DecisionCache>,
+    inner: P,
+    ttl: Duration,
+}
+
+impl<D: PolicyDomain, P: Policy<D>> CachedDecisionPolicy<D, P> {
+    /// Wraps `inner`, reading and writing its results in `cache` under the
+    /// key returned by `key`. Results are kept for 60 seconds unless
+    /// [`Self::with_ttl`] is set.
+    pub fn new<F>(key: F, cache: Arc<dyn DecisionCache>, inner: P) -> Self
+    where
+        F: Fn(&D::Subject, &D::Action, &D::Resource, &D::Context) -> String + Send + Sync + 'static,
+    {
+        Self {
+            key: Box::new(key),
+            cache,
+            inner,
+            ttl: DEFAULT_TTL,
+        }
+    }
+
+    /// Sets how long results are kept.
+    pub fn with_ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = ttl;
+        self
+    }
+
+    /// The TTL to store `result` for, or `None` if it must not be stored.
+    fn ttl_for(&self, result: &PolicyEvalResult) -> Option<Duration> {
+        if matches!(result, PolicyEvalResult::Indeterminate { .. }) {
+            return None;
+        }
+        let ttl = match result.valid_until() {
+            Some(valid_until) => self.ttl.min(
+                valid_until
+                    .duration_since(SystemTime::now())
+                    .unwrap_or_default(),
+            ),
+            None => self.ttl,
+        };
+        (!ttl.is_zero()).then_some(ttl)
+    }
+}
+
+#[async_trait]
+impl<D, P> Policy<D> for CachedDecisionPolicy<D, P>
+where
+    D: PolicyDomain,
+    P: Policy<D>,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let key = (self.key)(ctx.subject, ctx.action, ctx.resource, ctx.context);
+        if let Some(cached) = self.cache.get(&key).await {
+            return mark_cached(cached);
+        }
+        let result = self.inner.evaluate(ctx).await;
+        if let Some(ttl) = self.ttl_for(&result) {
+            self.cache.put(&key, &result, ttl).await;
+        }
+        result
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        self.inner
+            .declared_facts(subject, action, resource, context, facts);
+    }
+}
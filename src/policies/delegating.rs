@@ -5,7 +5,9 @@ use crate::{
 use async_trait::async_trait;
 use std::sync::Arc;
 
-fn delegated_evaluation_to_result(
+/// Nests a delegated checker's evaluation under a [`CombineOp::Delegate`]
+/// node named `policy_type`.
+pub(crate) fn delegated_evaluation_to_result(
     policy_type: std::borrow::Cow<'static, str>,
     evaluation: AccessEvaluation,
 ) -> PolicyEvalResult {
@@ -16,6 +18,7 @@ fn delegated_evaluation_to_result(
             trace,
         } => PolicyEvalResult::Combined {
             policy_type,
+            policy_id: None,
             operation: CombineOp::Delegate,
             children: vec![trace
                 .root()
@@ -25,6 +28,7 @@ fn delegated_evaluation_to_result(
         },
         AccessEvaluation::Denied { reason, trace } => PolicyEvalResult::Combined {
             policy_type,
+            policy_id: None,
             operation: CombineOp::Delegate,
             children: vec![trace
                 .root()
@@ -168,3 +172,123 @@ where
         self.security_rule.clone()
     }
 }
+
+type RequestMap<ParentD, ChildD> = Box<
+    dyn Fn(
+            &<ParentD as PolicyDomain>::Subject,
+            &<ParentD as PolicyDomain>::Action,
+            &<ParentD as PolicyDomain>::Resource,
+            &<ParentD as PolicyDomain>::Context,
+        ) -> Option<(
+            <ChildD as PolicyDomain>::Subject,
+            <ChildD as PolicyDomain>::Action,
+            <ChildD as PolicyDomain>::Resource,
+            <ChildD as PolicyDomain>::Context,
+        )> + Send
+        + Sync,
+>;
+
+/// A policy that delegates to another [`PermissionChecker`] only for the
+/// requests it can map into the child domain.
+///
+/// Like [`DelegatingPolicy`], but one mapping converts the whole request and
+/// returns `None` for requests the child checker has nothing to say about,
+/// such as other variants of a resource enum. Those requests are
+/// [`PolicyEvalResult::NotApplicable`] without running the child checker.
+/// Mapped requests are evaluated by the child checker in the same session,
+/// and its trace is nested under a [`CombineOp::Delegate`] node.
+///
+/// ```rust
+/// # use gatehouse::*;
+/// # struct User;
+/// # enum Resource { Invoice(Invoice), Post }
+/// # struct Invoice { paid: bool }
+/// # struct App;
+/// # impl PolicyDomain for App {
+/// #     type Subject = User;
+/// #     type Action = ();
+/// #     type Resource = Resource;
+/// #     type Context = ();
+/// # }
+/// # struct Billing;
+/// # impl PolicyDomain for Billing {
+/// #     type Subject = ();
+/// #     type Action = ();
+/// #     type Resource = bool;
+/// #     type Context = ();
+/// # }
+/// let invoices = PermissionChecker::<Billing>::new();
+/// let policy = MappedPolicy::<App, Billing>::new(
+///     "Invoices",
+///     invoices,
+///     |_user, _action, resource, _ctx| match resource {
+///         Resource::Invoice(invoice) => Some(((), (), invoice.paid, ())),
+///         _ => None,
+///     },
+/// );
+/// # let _ = policy;
+/// ```
+pub struct MappedPolicy<ParentD: PolicyDomain, ChildD: PolicyDomain> {
+    policy_type: std::borrow::Cow<'static, str>,
+    checker: PermissionChecker<ChildD>,
+    map: RequestMap<ParentD, ChildD>,
+}
+
+impl<ParentD: PolicyDomain, ChildD: PolicyDomain> MappedPolicy<ParentD, ChildD> {
+    /// Creates a mapped policy from a child checker and the request mapping.
+    pub fn new<MapFn>(
+        policy_type: impl Into<std::borrow::Cow<'static, str>>,
+        checker: PermissionChecker<ChildD>,
+        map: MapFn,
+    ) -> Self
+    where
+        MapFn: Fn(
+                &ParentD::Subject,
+                &ParentD::Action,
+                &ParentD::Resource,
+                &ParentD::Context,
+            ) -> Option<(
+                ChildD::Subject,
+                ChildD::Action,
+                ChildD::Resource,
+                ChildD::Context,
+            )> + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            policy_type: policy_type.into(),
+            checker,
+            map: Box::new(map),
+        }
+    }
+}
+
+#[async_trait]
+impl<ParentD, ChildD> Policy<ParentD> for MappedPolicy<ParentD, ChildD>
+where
+    ParentD: PolicyDomain,
+    ChildD: PolicyDomain,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, ParentD>) -> PolicyEvalResult {
+        let Some((subject, action, resource, context)) =
+            (self.map)(ctx.subject, ctx.action, ctx.resource, ctx.context)
+        else {
+            return ctx.not_applicable("Request does not map to the delegated checker");
+        };
+        let evaluation = self
+            .checker
+            .bind(ctx.session, &subject, &action, &context)
+            .check(&resource)
+            .await;
+        delegated_evaluation_to_result(self.policy_type.clone(), evaluation)
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        self.policy_type.clone()
+    }
+
+    fn effect(&self) -> Effect {
+        self.checker.aggregate_effect()
+    }
+}
@@ -0,0 +1,92 @@
+use crate::{
+    BatchEvalCtx, DeclaredFacts, Effect, EvalCtx, Policy, PolicyComposition, PolicyDomain,
+    PolicyEvalResult, SecurityRuleMetadata,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+/// Tags a policy's results with a caller-supplied instance identifier.
+///
+/// Built with [`crate::PolicyExt::with_id`]. Built-in policies report a fixed
+/// policy type, so two [`crate::RbacPolicy`] instances look the same in a
+/// trace; the id on [`PolicyEvalResult::policy_id`] tells them apart, and
+/// [`PolicyEvalResult::format`] shows it in place of the type. Only the
+/// top node of each result is tagged.
+///
+/// The wrapper is otherwise transparent: it reports the inner policy type,
+/// effect, security rule, and composition (carrying the id, so a compiled
+/// tree tags the same node), so checker decisions are identical with or
+/// without it.
+pub struct IdentifiedPolicy<D: PolicyDomain, P> {
+    inner: P,
+    id: Cow<'static, str>,
+    _domain: PhantomData<D>,
+}
+
+impl<D: PolicyDomain, P: Policy<D>> IdentifiedPolicy<D, P> {
+    /// Wraps `inner`, tagging its results with `id`.
+    pub fn new(inner: P, id: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            inner,
+            id: id.into(),
+            _domain: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, P> Policy<D> for IdentifiedPolicy<D, P>
+where
+    D: PolicyDomain,
+    P: Policy<D>,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        self.inner
+            .evaluate(ctx)
+            .await
+            .with_policy_id(self.id.clone())
+    }
+
+    async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
+        self.inner
+            .evaluate_batch(ctx)
+            .await
+            .into_iter()
+            .map(|result| result.with_policy_id(self.id.clone()))
+            .collect()
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        self.inner
+            .declared_facts(subject, action, resource, context, facts);
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        self.inner
+            .composition()
+            .map(|composition| PolicyComposition {
+                policy_id: Some(self.id.clone()),
+                ..composition
+            })
+    }
+}
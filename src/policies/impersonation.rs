@@ -0,0 +1,105 @@
+use crate::{
+    CombineOp, DeclaredFacts, Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult,
+    SecurityRuleMetadata,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+
+type Impersonator<D> = Box<
+    dyn Fn(&<D as PolicyDomain>::Context) -> Option<<D as PolicyDomain>::Subject> + Send + Sync,
+>;
+
+/// Wraps a policy so a request made on someone's behalf needs the authority
+/// of both parties.
+///
+/// `impersonator` reads the acting subject — a support agent, say — from the
+/// request context. When it returns `None` the wrapper is transparent and
+/// returns the inner result for the subject unchanged. When it returns an
+/// impersonator, the inner policy runs for the effective subject and then,
+/// if that grants, for the impersonator; access is granted only if both
+/// grant. The result is an `AND` node whose children are the effective
+/// subject's result followed, when evaluated, by the impersonator's.
+///
+/// Like [`crate::AuditOnDeny`], the wrapper reports the inner policy type,
+/// effect, and security rule.
+pub struct ImpersonationPolicy<D: PolicyDomain, P> {
+    inner: P,
+    impersonator: Impersonator<D>,
+}
+
+impl<D: PolicyDomain, P: Policy<D>> ImpersonationPolicy<D, P> {
+    /// Wraps `inner`, reading the impersonating subject from the context with
+    /// `impersonator`.
+    pub fn new<F>(inner: P, impersonator: F) -> Self
+    where
+        F: Fn(&D::Context) -> Option<D::Subject> + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            impersonator: Box::new(impersonator),
+        }
+    }
+}
+
+#[async_trait]
+impl<D, P> Policy<D> for ImpersonationPolicy<D, P>
+where
+    D: PolicyDomain,
+    P: Policy<D>,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let Some(impersonator) = (self.impersonator)(ctx.context) else {
+            return self.inner.evaluate(ctx).await;
+        };
+
+        let effective = self.inner.evaluate(ctx).await;
+        let mut children = vec![effective];
+        if children[0].is_granted() {
+            let impersonator_ctx = EvalCtx {
+                session: ctx.session,
+                subject: &impersonator,
+                action: ctx.action,
+                resource: ctx.resource,
+                context: ctx.context,
+                policy_type: ctx.policy_type.clone(),
+            };
+            children.push(self.inner.evaluate(&impersonator_ctx).await);
+        }
+        let outcome = children.len() == 2 && children[1].is_granted();
+        PolicyEvalResult::Combined {
+            policy_type: ctx.policy_type.clone(),
+            policy_id: None,
+            operation: CombineOp::And,
+            children,
+            outcome,
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        self.inner
+            .declared_facts(subject, action, resource, context, facts);
+        if let Some(impersonator) = (self.impersonator)(context) {
+            self.inner
+                .declared_facts(&impersonator, action, resource, context, facts);
+        }
+    }
+}
@@ -1,7 +1,39 @@
+mod abac;
+mod audit;
+mod cached;
+mod context;
+mod decision_cache;
 mod delegating;
+mod identified;
+mod impersonation;
+mod narrowed;
 mod rbac;
 mod rebac;
+mod remote;
+mod rollout;
+mod tenant;
+#[cfg(feature = "tokio")]
+mod timeout;
+mod transitive;
 
-pub use delegating::DelegatingPolicy;
+pub use abac::{
+    AbacPolicy, AsyncAbacPolicy, AttributeExpr, AttributeValue, Attributes, ConditionFuture,
+};
+pub use audit::{AuditOnDeny, AuditSink};
+pub(crate) use cached::mark_cached;
+pub use cached::CachedPolicy;
+pub use context::{ContextTenantPolicy, SchedulePolicy, ScheduleWindow, TimeWindowPolicy, Weekday};
+pub use decision_cache::{CachedDecisionPolicy, DecisionCache};
+pub(crate) use delegating::delegated_evaluation_to_result;
+pub use delegating::{DelegatingPolicy, MappedPolicy};
+pub use identified::IdentifiedPolicy;
+pub use impersonation::ImpersonationPolicy;
+pub use narrowed::{MapContext, MapResource, MapSubject};
 pub use rbac::RbacPolicy;
 pub use rebac::RebacPolicy;
+pub use remote::{RemoteError, RemoteFuture, RemotePolicy};
+pub use rollout::RolloutPolicy;
+pub use tenant::TenantIsolationPolicy;
+#[cfg(feature = "tokio")]
+pub use timeout::TimeoutPolicy;
+pub use transitive::TransitiveRebacPolicy;
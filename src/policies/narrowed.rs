@@ -0,0 +1,233 @@
+use crate::{
+    DeclaredFacts, Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult, SecurityRuleMetadata,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+
+const RESOURCE_OUT_OF_SCOPE_REASON: &str = "Resource is outside this policy's scope";
+const SUBJECT_OUT_OF_SCOPE_REASON: &str = "Subject is outside this policy's scope";
+const CONTEXT_OUT_OF_SCOPE_REASON: &str = "Context is outside this policy's scope";
+
+/// Runs a policy written for a narrower resource type.
+///
+/// Built with [`crate::PolicyExt::map_resource`]. The mapping converts the
+/// outer resource, typically by picking one variant of a resource enum;
+/// when it returns `None` the result is
+/// [`PolicyEvalResult::NotApplicable`] and the inner policy does not run.
+/// Otherwise the inner policy's result is returned unchanged. The wrapper
+/// reports the inner policy type, effect, and security rule.
+pub struct MapResource<Outer: PolicyDomain, Inner: PolicyDomain, P> {
+    inner: P,
+    map: Box<dyn Fn(&Outer::Resource) -> Option<Inner::Resource> + Send + Sync>,
+    _domains: PhantomData<(Outer, Inner)>,
+}
+
+impl<Outer: PolicyDomain, Inner: PolicyDomain, P> MapResource<Outer, Inner, P> {
+    /// Wraps `inner`, narrowing each resource with `map`.
+    pub fn new<F>(inner: P, map: F) -> Self
+    where
+        F: Fn(&Outer::Resource) -> Option<Inner::Resource> + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            map: Box::new(map),
+            _domains: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<Outer, Inner, P> Policy<Outer> for MapResource<Outer, Inner, P>
+where
+    Outer: PolicyDomain,
+    Inner: PolicyDomain<Subject = Outer::Subject, Action = Outer::Action, Context = Outer::Context>,
+    P: Policy<Inner>,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Outer>) -> PolicyEvalResult {
+        let Some(resource) = (self.map)(ctx.resource) else {
+            return ctx.not_applicable(RESOURCE_OUT_OF_SCOPE_REASON);
+        };
+        let inner_ctx = EvalCtx::<Inner> {
+            session: ctx.session,
+            subject: ctx.subject,
+            action: ctx.action,
+            resource: &resource,
+            context: ctx.context,
+            policy_type: ctx.policy_type.clone(),
+        };
+        self.inner.evaluate(&inner_ctx).await
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &Outer::Subject,
+        action: &Outer::Action,
+        resource: &Outer::Resource,
+        context: &Outer::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        if let Some(resource) = (self.map)(resource) {
+            self.inner
+                .declared_facts(subject, action, &resource, context, facts);
+        }
+    }
+}
+
+/// Runs a policy written for a narrower subject type.
+///
+/// Built with [`crate::PolicyExt::map_subject`]; see [`MapResource`].
+pub struct MapSubject<Outer: PolicyDomain, Inner: PolicyDomain, P> {
+    inner: P,
+    map: Box<dyn Fn(&Outer::Subject) -> Option<Inner::Subject> + Send + Sync>,
+    _domains: PhantomData<(Outer, Inner)>,
+}
+
+impl<Outer: PolicyDomain, Inner: PolicyDomain, P> MapSubject<Outer, Inner, P> {
+    /// Wraps `inner`, narrowing each subject with `map`.
+    pub fn new<F>(inner: P, map: F) -> Self
+    where
+        F: Fn(&Outer::Subject) -> Option<Inner::Subject> + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            map: Box::new(map),
+            _domains: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<Outer, Inner, P> Policy<Outer> for MapSubject<Outer, Inner, P>
+where
+    Outer: PolicyDomain,
+    Inner:
+        PolicyDomain<Action = Outer::Action, Resource = Outer::Resource, Context = Outer::Context>,
+    P: Policy<Inner>,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Outer>) -> PolicyEvalResult {
+        let Some(subject) = (self.map)(ctx.subject) else {
+            return ctx.not_applicable(SUBJECT_OUT_OF_SCOPE_REASON);
+        };
+        let inner_ctx = EvalCtx::<Inner> {
+            session: ctx.session,
+            subject: &subject,
+            action: ctx.action,
+            resource: ctx.resource,
+            context: ctx.context,
+            policy_type: ctx.policy_type.clone(),
+        };
+        self.inner.evaluate(&inner_ctx).await
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &Outer::Subject,
+        action: &Outer::Action,
+        resource: &Outer::Resource,
+        context: &Outer::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        if let Some(subject) = (self.map)(subject) {
+            self.inner
+                .declared_facts(&subject, action, resource, context, facts);
+        }
+    }
+}
+
+/// Runs a policy written for a narrower context type.
+///
+/// Built with [`crate::PolicyExt::map_context`]; see [`MapResource`].
+pub struct MapContext<Outer: PolicyDomain, Inner: PolicyDomain, P> {
+    inner: P,
+    map: Box<dyn Fn(&Outer::Context) -> Option<Inner::Context> + Send + Sync>,
+    _domains: PhantomData<(Outer, Inner)>,
+}
+
+impl<Outer: PolicyDomain, Inner: PolicyDomain, P> MapContext<Outer, Inner, P> {
+    /// Wraps `inner`, narrowing each context with `map`.
+    pub fn new<F>(inner: P, map: F) -> Self
+    where
+        F: Fn(&Outer::Context) -> Option<Inner::Context> + Send + Sync + 'static,
+    {
+        Self {
+            inner,
+            map: Box::new(map),
+            _domains: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<Outer, Inner, P> Policy<Outer> for MapContext<Outer, Inner, P>
+where
+    Outer: PolicyDomain,
+    Inner:
+        PolicyDomain<Subject = Outer::Subject, Action = Outer::Action, Resource = Outer::Resource>,
+    P: Policy<Inner>,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Outer>) -> PolicyEvalResult {
+        let Some(context) = (self.map)(ctx.context) else {
+            return ctx.not_applicable(CONTEXT_OUT_OF_SCOPE_REASON);
+        };
+        let inner_ctx = EvalCtx::<Inner> {
+            session: ctx.session,
+            subject: ctx.subject,
+            action: ctx.action,
+            resource: ctx.resource,
+            context: &context,
+            policy_type: ctx.policy_type.clone(),
+        };
+        self.inner.evaluate(&inner_ctx).await
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &Outer::Subject,
+        action: &Outer::Action,
+        resource: &Outer::Resource,
+        context: &Outer::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        if let Some(context) = (self.map)(context) {
+            self.inner
+                .declared_facts(subject, action, resource, &context, facts);
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use crate::{
-    BatchEvalCtx, EvalCtx, FactKey, FactLoadResult, FactOutcome, FactProvenance, Policy,
-    PolicyDomain, PolicyEvalResult, RelationshipQuery,
+    BatchEvalCtx, DeclaredFacts, EvalCtx, FactKey, FactLoadResult, FactOutcome, FactProvenance,
+    Policy, PolicyDomain, PolicyEvalResult, RelationshipQuery,
 };
 use async_trait::async_trait;
 use std::fmt;
@@ -8,11 +8,28 @@ use std::hash::Hash;
 use std::marker::PhantomData;
 use std::sync::Arc;
 
+type RelationResolver<D, Relation> = Arc<
+    dyn Fn(&<D as PolicyDomain>::Action, &<D as PolicyDomain>::Resource) -> Relation + Send + Sync,
+>;
+
+/// Where a [`RebacPolicy`] gets the relationship it requires.
+enum RequiredRelation<D: PolicyDomain, Relation> {
+    Fixed(Relation),
+    Resolved(RelationResolver<D, Relation>),
+}
+
 /// Relationship-based access control backed by request-scoped fact loading.
+///
+/// The required relationship is either fixed at construction with
+/// [`Self::new`], or derived from each request's action and resource with
+/// [`Self::with_relationship_resolver`], so one policy can require `viewer`
+/// for reads and `editor` for writes. Reasons and fact provenance name the
+/// relationship that was checked.
 pub struct RebacPolicy<D: PolicyDomain, SubjectId, ResourceId, Relation> {
     subject_id: Arc<dyn Fn(&D::Subject) -> SubjectId + Send + Sync>,
     resource_id: Arc<dyn Fn(&D::Resource) -> ResourceId + Send + Sync>,
-    relation: Relation,
+    relation: RequiredRelation<D, Relation>,
+    prefetch: bool,
     _domain: PhantomData<D>,
 }
 
@@ -32,10 +49,45 @@ impl<D: PolicyDomain, SubjectId, ResourceId, Relation>
         Self {
             subject_id: Arc::new(subject_id),
             resource_id: Arc::new(resource_id),
-            relation,
+            relation: RequiredRelation::Fixed(relation),
+            prefetch: false,
             _domain: PhantomData,
         }
     }
+
+    /// Creates a ReBAC policy whose required relation is resolved per
+    /// request from the action and resource, such as `"viewer"` for reads
+    /// and `"editor"` for edits.
+    pub fn with_relationship_resolver<SubjectIdFn, ResourceIdFn, ResolverFn>(
+        subject_id: SubjectIdFn,
+        resource_id: ResourceIdFn,
+        resolver: ResolverFn,
+    ) -> Self
+    where
+        SubjectIdFn: Fn(&D::Subject) -> SubjectId + Send + Sync + 'static,
+        ResourceIdFn: Fn(&D::Resource) -> ResourceId + Send + Sync + 'static,
+        ResolverFn: Fn(&D::Action, &D::Resource) -> Relation + Send + Sync + 'static,
+    {
+        Self {
+            subject_id: Arc::new(subject_id),
+            resource_id: Arc::new(resource_id),
+            relation: RequiredRelation::Resolved(Arc::new(resolver)),
+            prefetch: false,
+            _domain: PhantomData,
+        }
+    }
+
+    /// Declares the relationship key through [`Policy::declared_facts`], so
+    /// batch evaluations and [`crate::PermissionChecker::evaluate_subjects`]
+    /// load the keys for every item or subject in one
+    /// [`crate::FactSource::load_many`] call.
+    ///
+    /// Off by default because it also loads keys for items that an earlier
+    /// policy decides, which the checker would otherwise never look up.
+    pub fn with_prefetch(mut self) -> Self {
+        self.prefetch = true;
+        self
+    }
 }
 
 #[async_trait]
@@ -52,10 +104,10 @@ where
         let key = RelationshipQuery {
             subject_id: (self.subject_id)(ctx.subject),
             resource_id: (self.resource_id)(ctx.resource),
-            relation: self.relation.clone(),
+            relation: self.relation(ctx.action, ctx.resource),
         };
-        let key_repr = Self::render_key(&key);
-        self.result_from_fact(fact_name, &key_repr, ctx.session.get(key).await)
+        let fact = ctx.session.get(key.clone()).await;
+        Self::result_from_fact(fact_name, &key, fact)
     }
 
     async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
@@ -67,7 +119,7 @@ where
             .map(|item| RelationshipQuery {
                 subject_id: subject_id.clone(),
                 resource_id: (self.resource_id)(item.resource),
-                relation: self.relation.clone(),
+                relation: self.relation(ctx.action, item.resource),
             })
             .collect::<Vec<_>>();
 
@@ -87,13 +139,30 @@ where
 
         keys.iter()
             .zip(facts)
-            .map(|(key, fact)| self.result_from_fact(fact_name, &Self::render_key(key), fact))
+            .map(|(key, fact)| Self::result_from_fact(fact_name, key, fact))
             .collect()
     }
 
     fn policy_type(&self) -> std::borrow::Cow<'static, str> {
         std::borrow::Cow::Borrowed("RebacPolicy")
     }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        _context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        if self.prefetch {
+            facts.declare(RelationshipQuery {
+                subject_id: (self.subject_id)(subject),
+                resource_id: (self.resource_id)(resource),
+                relation: self.relation(action, resource),
+            });
+        }
+    }
 }
 
 impl<D, SubjectId, ResourceId, Relation> RebacPolicy<D, SubjectId, ResourceId, Relation>
@@ -101,8 +170,15 @@ where
     D: PolicyDomain,
     SubjectId: fmt::Debug,
     ResourceId: fmt::Debug,
-    Relation: fmt::Display,
+    Relation: Clone + fmt::Display,
 {
+    fn relation(&self, action: &D::Action, resource: &D::Resource) -> Relation {
+        match &self.relation {
+            RequiredRelation::Fixed(relation) => relation.clone(),
+            RequiredRelation::Resolved(resolver) => resolver(action, resource),
+        }
+    }
+
     fn render_key(key: &RelationshipQuery<SubjectId, ResourceId, Relation>) -> String {
         format!(
             "{:?} -[{}]-> {:?}",
@@ -111,24 +187,29 @@ where
     }
 
     fn result_from_fact(
-        &self,
         fact_name: &'static str,
-        key_repr: &str,
+        key: &RelationshipQuery<SubjectId, ResourceId, Relation>,
         fact: FactLoadResult<bool>,
     ) -> PolicyEvalResult {
+        let relation = &key.relation;
         let outcome = FactOutcome::from_load_result(&fact);
         let detail = match &fact {
             FactLoadResult::Error(error) => Some(error.to_string()),
             _ => None,
         };
-        let provenance = vec![FactProvenance::new(fact_name, key_repr, outcome, detail)];
+        let provenance = vec![FactProvenance::new(
+            fact_name,
+            Self::render_key(key),
+            outcome,
+            detail,
+        )];
 
         match fact {
             FactLoadResult::Found(true) => PolicyEvalResult::granted_with_facts(
                 "RebacPolicy",
                 Some(format!(
                     "Subject has '{}' relationship with resource",
-                    self.relation
+                    relation
                 )),
                 provenance,
             ),
@@ -136,18 +217,18 @@ where
                 "RebacPolicy",
                 format!(
                     "Subject does not have '{}' relationship with resource",
-                    self.relation
+                    relation
                 ),
                 provenance,
             ),
             FactLoadResult::Missing => PolicyEvalResult::not_applicable_with_facts(
                 "RebacPolicy",
-                format!("Relationship '{}' fact is missing", self.relation),
+                format!("Relationship '{}' fact is missing", relation),
                 provenance,
             ),
-            FactLoadResult::Error(error) => PolicyEvalResult::not_applicable_with_facts(
+            FactLoadResult::Error(error) => PolicyEvalResult::indeterminate_with_facts(
                 "RebacPolicy",
-                format!("Relationship '{}' fact load failed: {error}", self.relation),
+                format!("Relationship '{}' fact load failed: {error}", relation),
                 provenance,
             ),
         }
@@ -0,0 +1,130 @@
+use crate::{EvalCtx, Policy, PolicyDomain, PolicyEvalResult};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::future::Future;
+use std::pin::Pin;
+#[cfg(feature = "tokio")]
+use std::time::Duration;
+
+/// Error returned by a [`RemotePolicy`] call.
+pub type RemoteError = Box<dyn std::error::Error + Send + Sync>;
+
+/// The future a [`RemotePolicy`] call returns.
+pub type RemoteFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<PolicyEvalResult, RemoteError>> + Send + 'a>>;
+
+type RemoteCall<D> = Box<
+    dyn for<'a> Fn(
+            &'a <D as PolicyDomain>::Subject,
+            &'a <D as PolicyDomain>::Action,
+            &'a <D as PolicyDomain>::Resource,
+            &'a <D as PolicyDomain>::Context,
+        ) -> RemoteFuture<'a>
+        + Send
+        + Sync,
+>;
+
+/// Consults an external authorization service as one policy.
+///
+/// The wrapped call (typically backed by an HTTP or gRPC client, such as a
+/// `tower::Service`) returns the remote decision as a [`PolicyEvalResult`],
+/// which is placed in the local trace unchanged, so the remote decision
+/// composes with local policies under `AND`/`OR` like any other.
+///
+/// The policy fails closed: a call error, or a timeout set with
+/// `with_timeout` (requires the `tokio` feature), becomes
+/// [`PolicyEvalResult::Indeterminate`] with the error in the reason.
+///
+/// ```rust
+/// use gatehouse::*;
+///
+/// struct Documents;
+/// impl PolicyDomain for Documents {
+///     type Subject = String;
+///     type Action = String;
+///     type Resource = String;
+///     type Context = ();
+/// }
+///
+/// let remote = RemotePolicy::<Documents>::new("CentralAuthz", |subject, _action, resource, _ctx| {
+///     let (subject, resource) = (subject.clone(), resource.clone());
+///     Box::pin(async move {
+///         // Call the authorization service here.
+///         let allowed = subject == "alice" && resource == "doc-1";
+///         Ok(if allowed {
+///             PolicyEvalResult::granted("CentralAuthz", Some("remote grant".into()))
+///         } else {
+///             PolicyEvalResult::not_applicable("CentralAuthz", "remote deny")
+///         })
+///     })
+/// });
+/// # let _ = remote;
+/// ```
+pub struct RemotePolicy<D: PolicyDomain> {
+    name: Cow<'static, str>,
+    call: RemoteCall<D>,
+    #[cfg(feature = "tokio")]
+    timeout: Option<Duration>,
+}
+
+impl<D: PolicyDomain> RemotePolicy<D> {
+    /// Creates a remote policy named `name` that decides through `call`.
+    pub fn new<F>(name: impl Into<Cow<'static, str>>, call: F) -> Self
+    where
+        F: for<'a> Fn(
+                &'a D::Subject,
+                &'a D::Action,
+                &'a D::Resource,
+                &'a D::Context,
+            ) -> RemoteFuture<'a>
+            + Send
+            + Sync
+            + 'static,
+    {
+        Self {
+            name: name.into(),
+            call: Box::new(call),
+            #[cfg(feature = "tokio")]
+            timeout: None,
+        }
+    }
+
+    /// Fails closed when the remote call takes longer than `timeout`.
+    ///
+    /// Uses `tokio::time`, so evaluation must run inside a Tokio runtime.
+    #[cfg(feature = "tokio")]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    async fn call(&self, ctx: &EvalCtx<'_, D>) -> Result<PolicyEvalResult, String> {
+        let call = (self.call)(ctx.subject, ctx.action, ctx.resource, ctx.context);
+        #[cfg(feature = "tokio")]
+        if let Some(timeout) = self.timeout {
+            return match tokio::time::timeout(timeout, call).await {
+                Ok(result) => result.map_err(|error| format!("Remote policy failed: {error}")),
+                Err(_) => Err(format!("Remote policy timed out after {timeout:?}")),
+            };
+        }
+        call.await
+            .map_err(|error| format!("Remote policy failed: {error}"))
+    }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for RemotePolicy<D> {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        match self.call(ctx).await {
+            Ok(result) => result,
+            Err(reason) => {
+                tracing::warn!(policy.type = self.name.as_ref(), "{reason}");
+                ctx.indeterminate(reason)
+            }
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+}
@@ -0,0 +1,70 @@
+use crate::entropy::stable_hash;
+use crate::{EntropySource, EvalCtx, Policy, PolicyDomain, PolicyEvalResult, SystemEntropy};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::hash::Hash;
+use std::sync::Arc;
+
+/// Grants a percentage of requests, bucketed by a caller-chosen key.
+///
+/// The bucket key (usually a subject or tenant id) is hashed with a stable
+/// algorithm and passed to the policy's [`EntropySource`]; the request is
+/// granted when the resulting bucket in `0..100` is below `percentage`. With
+/// the default [`SystemEntropy`] a key keeps its bucket for the life of the
+/// policy. Inject a [`crate::FixedEntropy`] through [`Self::with_entropy`] for
+/// reproducible tests or buckets shared across processes.
+pub struct RolloutPolicy<D: PolicyDomain> {
+    name: Cow<'static, str>,
+    percentage: u8,
+    bucket_key: Arc<dyn Fn(&D::Subject, &D::Resource) -> u64 + Send + Sync>,
+    entropy: Arc<dyn EntropySource>,
+}
+
+impl<D: PolicyDomain> RolloutPolicy<D> {
+    /// Creates a rollout that grants `percentage` percent of bucket keys.
+    ///
+    /// Percentages above 100 are treated as 100.
+    pub fn new<K, F>(name: impl Into<Cow<'static, str>>, percentage: u8, bucket_key: F) -> Self
+    where
+        K: Hash,
+        F: Fn(&D::Subject, &D::Resource) -> K + Send + Sync + 'static,
+    {
+        Self {
+            name: name.into(),
+            percentage: percentage.min(100),
+            bucket_key: Arc::new(move |subject, resource| {
+                stable_hash(&bucket_key(subject, resource))
+            }),
+            entropy: Arc::new(SystemEntropy::new()),
+        }
+    }
+
+    /// Replaces the entropy source used for bucketing.
+    pub fn with_entropy(mut self, entropy: impl EntropySource + 'static) -> Self {
+        self.entropy = Arc::new(entropy);
+        self
+    }
+}
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for RolloutPolicy<D> {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let key = (self.bucket_key)(ctx.subject, ctx.resource);
+        let bucket = self.entropy.sample(key) % 100;
+        if bucket < u64::from(self.percentage) {
+            ctx.grant(format!(
+                "Bucket {bucket} is inside the {}% rollout",
+                self.percentage
+            ))
+        } else {
+            ctx.not_applicable(format!(
+                "Bucket {bucket} is outside the {}% rollout",
+                self.percentage
+            ))
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.name.clone()
+    }
+}
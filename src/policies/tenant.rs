@@ -0,0 +1,52 @@
+use crate::{Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult};
+use async_trait::async_trait;
+use std::marker::PhantomData;
+
+/// Forbids access whenever the subject's tenant differs from the resource's.
+///
+/// A safety net for multi-tenant checkers: the policy declares
+/// [`Effect::Forbid`], so it runs ahead of every allow-only policy and its veto
+/// holds regardless of what else grants. Same-tenant requests are
+/// [`PolicyEvalResult::NotApplicable`]; this policy never grants on its own.
+pub struct TenantIsolationPolicy<D: PolicyDomain, F1, F2> {
+    subject_tenant: F1,
+    resource_tenant: F2,
+    _domain: PhantomData<D>,
+}
+
+impl<D: PolicyDomain, F1, F2> TenantIsolationPolicy<D, F1, F2> {
+    /// Creates a tenant isolation policy from subject and resource tenant
+    /// resolvers.
+    pub fn new(subject_tenant: F1, resource_tenant: F2) -> Self {
+        Self {
+            subject_tenant,
+            resource_tenant,
+            _domain: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<D, F1, F2, TenantId> Policy<D> for TenantIsolationPolicy<D, F1, F2>
+where
+    D: PolicyDomain,
+    TenantId: PartialEq,
+    F1: Fn(&D::Subject) -> TenantId + Sync + Send,
+    F2: Fn(&D::Resource) -> TenantId + Sync + Send,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        if (self.subject_tenant)(ctx.subject) == (self.resource_tenant)(ctx.resource) {
+            ctx.not_applicable("Subject and resource share a tenant")
+        } else {
+            ctx.forbid("Resource belongs to another tenant")
+        }
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("TenantIsolationPolicy")
+    }
+
+    fn effect(&self) -> Effect {
+        Effect::Forbid
+    }
+}
@@ -0,0 +1,95 @@
+use crate::{
+    BatchEvalCtx, DeclaredFacts, Effect, EvalCtx, Policy, PolicyDomain, PolicyEvalResult,
+    SecurityRuleMetadata,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::marker::PhantomData;
+use std::time::Duration;
+
+/// Wraps a policy so a slow evaluation fails closed instead of stalling the
+/// request.
+///
+/// The inner policy runs under `tokio::time::timeout`. If it finishes in time
+/// its result is returned unchanged; otherwise the wrapper returns
+/// [`PolicyEvalResult::Indeterminate`] tagged with the inner policy type and
+/// a `"Policy timed out after …"` reason, so the trace shows which policy was
+/// attempted and timed out. On the batch path the timeout bounds the whole
+/// inner batch call, and every item of a timed-out batch is indeterminate.
+///
+/// Like [`crate::AuditOnDeny`], the wrapper reports the inner policy type,
+/// effect, and security rule. Evaluation must run inside a Tokio runtime.
+pub struct TimeoutPolicy<D: PolicyDomain, P> {
+    inner: P,
+    timeout: Duration,
+    _domain: PhantomData<D>,
+}
+
+impl<D: PolicyDomain, P: Policy<D>> TimeoutPolicy<D, P> {
+    /// Wraps `inner`, failing closed when one evaluation takes longer than
+    /// `timeout`.
+    pub fn new(inner: P, timeout: Duration) -> Self {
+        Self {
+            inner,
+            timeout,
+            _domain: PhantomData,
+        }
+    }
+
+    fn timed_out_reason(&self) -> String {
+        let reason = format!("Policy timed out after {:?}", self.timeout);
+        tracing::warn!(policy.type = self.inner.policy_type().as_ref(), "{reason}");
+        reason
+    }
+}
+
+#[async_trait]
+impl<D, P> Policy<D> for TimeoutPolicy<D, P>
+where
+    D: PolicyDomain,
+    P: Policy<D>,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        match tokio::time::timeout(self.timeout, self.inner.evaluate(ctx)).await {
+            Ok(result) => result,
+            Err(_) => ctx.indeterminate(self.timed_out_reason()),
+        }
+    }
+
+    async fn evaluate_batch<'item>(&self, ctx: &BatchEvalCtx<'item, D>) -> Vec<PolicyEvalResult> {
+        match tokio::time::timeout(self.timeout, self.inner.evaluate_batch(ctx)).await {
+            Ok(results) => results,
+            Err(_) => {
+                let reason = self.timed_out_reason();
+                ctx.items
+                    .iter()
+                    .map(|_| PolicyEvalResult::indeterminate(ctx.policy_type.clone(), &*reason))
+                    .collect()
+            }
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        self.inner.policy_type()
+    }
+
+    fn effect(&self) -> Effect {
+        self.inner.effect()
+    }
+
+    fn security_rule(&self) -> SecurityRuleMetadata {
+        self.inner.security_rule()
+    }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        self.inner
+            .declared_facts(subject, action, resource, context, facts);
+    }
+}
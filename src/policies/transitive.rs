@@ -0,0 +1,242 @@
+use crate::{
+    EvalCtx, FactKey, FactLoadResult, FactOutcome, FactProvenance, Policy, PolicyDomain,
+    PolicyEvalResult, RelatedSubjects, RelationshipQuery,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+const DEFAULT_MAX_DEPTH: usize = 4;
+
+/// Relationship-based access control that follows chains of intermediate
+/// objects.
+///
+/// Where [`crate::RebacPolicy`] asks one question — does the subject have
+/// `relation` to the resource? — this policy also asks it of every object the
+/// subject reaches through `via`, so "user is a member of a group that owns
+/// the document" grants `owner`. Intermediate objects are listed through the
+/// [`RelatedSubjects`] fact, and each hop's relationship checks go through
+/// [`RelationshipQuery`], so both are loaded, deduplicated, and cached by the
+/// [`crate::EvaluationSession`] one hop at a time.
+///
+/// The walk is breadth-first, so the shortest path wins. Objects already
+/// visited are skipped, which makes cyclic membership graphs safe, and the
+/// walk stops after `max_depth` `via` hops (4 unless set with
+/// [`Self::with_max_depth`]). A grant reports the discovered path in its
+/// reason and provenance. A failed fact load fails closed: if no path is found
+/// the result is [`PolicyEvalResult::Indeterminate`] rather than
+/// not-applicable.
+pub struct TransitiveRebacPolicy<D: PolicyDomain, SubjectId, ResourceId, Relation> {
+    subject_id: Arc<dyn Fn(&D::Subject) -> SubjectId + Send + Sync>,
+    resource_id: Arc<dyn Fn(&D::Resource) -> ResourceId + Send + Sync>,
+    relation: Relation,
+    via: Relation,
+    max_depth: usize,
+    _domain: PhantomData<D>,
+}
+
+impl<D: PolicyDomain, SubjectId, ResourceId, Relation>
+    TransitiveRebacPolicy<D, SubjectId, ResourceId, Relation>
+{
+    /// Creates a policy that grants when the subject, or any object reachable
+    /// from it through `via`, has `relation` to the resource.
+    pub fn new<SubjectIdFn, ResourceIdFn>(
+        subject_id: SubjectIdFn,
+        resource_id: ResourceIdFn,
+        relation: Relation,
+        via: Relation,
+    ) -> Self
+    where
+        SubjectIdFn: Fn(&D::Subject) -> SubjectId + Send + Sync + 'static,
+        ResourceIdFn: Fn(&D::Resource) -> ResourceId + Send + Sync + 'static,
+    {
+        Self {
+            subject_id: Arc::new(subject_id),
+            resource_id: Arc::new(resource_id),
+            relation,
+            via,
+            max_depth: DEFAULT_MAX_DEPTH,
+            _domain: PhantomData,
+        }
+    }
+
+    /// Sets the maximum number of `via` hops to follow. Zero checks only the
+    /// direct relationship, like [`crate::RebacPolicy`].
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+}
+
+#[async_trait]
+impl<D, SubjectId, ResourceId, Relation> Policy<D>
+    for TransitiveRebacPolicy<D, SubjectId, ResourceId, Relation>
+where
+    D: PolicyDomain,
+    SubjectId: Eq + Hash + Clone + Send + Sync + fmt::Debug + 'static,
+    ResourceId: Eq + Hash + Clone + Send + Sync + fmt::Debug + 'static,
+    Relation: Eq + Hash + Clone + Send + Sync + fmt::Display + 'static,
+{
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        let subject_id = (self.subject_id)(ctx.subject);
+        let resource_id = (self.resource_id)(ctx.resource);
+        let mut parents = HashMap::new();
+        let mut visited = HashSet::from([subject_id.clone()]);
+        let mut frontier = vec![subject_id];
+        let mut failures = Vec::new();
+
+        for depth in 0..=self.max_depth {
+            let checks = frontier
+                .iter()
+                .map(|node| RelationshipQuery {
+                    subject_id: node.clone(),
+                    resource_id: resource_id.clone(),
+                    relation: self.relation.clone(),
+                })
+                .collect::<Vec<_>>();
+            let facts = ctx.session.get_many(&checks).await;
+            for (key, fact) in checks.iter().zip(facts) {
+                match fact {
+                    FactLoadResult::Found(true) => {
+                        return self.granted(&parents, &key.subject_id, &resource_id);
+                    }
+                    FactLoadResult::Error(error) => failures.push(FactProvenance::new(
+                        RelationshipQuery::<SubjectId, ResourceId, Relation>::NAME,
+                        render_edge(&key.subject_id, &self.relation, &resource_id),
+                        FactOutcome::Error,
+                        Some(error.to_string()),
+                    )),
+                    _ => {}
+                }
+            }
+            if depth == self.max_depth {
+                break;
+            }
+
+            let expansions = frontier
+                .iter()
+                .map(|node| RelatedSubjects {
+                    subject_id: node.clone(),
+                    relation: self.via.clone(),
+                })
+                .collect::<Vec<_>>();
+            let facts = ctx.session.get_many(&expansions).await;
+            let mut next = Vec::new();
+            for (key, fact) in expansions.iter().zip(facts) {
+                match fact {
+                    FactLoadResult::Found(related) => {
+                        for object in related {
+                            if visited.insert(object.clone()) {
+                                parents.insert(object.clone(), key.subject_id.clone());
+                                next.push(object);
+                            }
+                        }
+                    }
+                    FactLoadResult::Error(error) => failures.push(FactProvenance::new(
+                        RelatedSubjects::<SubjectId, Relation>::NAME,
+                        format!("{:?} -[{}]-> *", key.subject_id, self.via),
+                        FactOutcome::Error,
+                        Some(error.to_string()),
+                    )),
+                    _ => {}
+                }
+            }
+            if next.is_empty() {
+                break;
+            }
+            frontier = next;
+        }
+
+        if failures.is_empty() {
+            PolicyEvalResult::not_applicable(
+                self.policy_type(),
+                format!(
+                    "No '{}' relationship with resource within {} '{}' hops",
+                    self.relation, self.max_depth, self.via
+                ),
+            )
+        } else {
+            PolicyEvalResult::indeterminate_with_facts(
+                self.policy_type(),
+                format!(
+                    "Relationship '{}' path search failed: {} fact loads errored",
+                    self.relation,
+                    failures.len()
+                ),
+                failures,
+            )
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        Cow::Borrowed("TransitiveRebacPolicy")
+    }
+}
+
+impl<D, SubjectId, ResourceId, Relation> TransitiveRebacPolicy<D, SubjectId, ResourceId, Relation>
+where
+    D: PolicyDomain,
+    SubjectId: Eq + Hash + fmt::Debug,
+    ResourceId: fmt::Debug,
+    Relation: fmt::Display,
+{
+    /// Rebuilds the path from the subject to `holder`, which has the relation
+    /// to the resource, and reports it.
+    fn granted(
+        &self,
+        parents: &HashMap<SubjectId, SubjectId>,
+        holder: &SubjectId,
+        resource_id: &ResourceId,
+    ) -> PolicyEvalResult {
+        let mut hops = vec![holder];
+        while let Some(parent) = parents.get(hops[hops.len() - 1]) {
+            hops.push(parent);
+        }
+        hops.reverse();
+
+        let mut provenance = hops
+            .windows(2)
+            .map(|pair| {
+                FactProvenance::new(
+                    "related_subjects",
+                    render_edge(pair[0], &self.via, pair[1]),
+                    FactOutcome::Found,
+                    None,
+                )
+            })
+            .collect::<Vec<_>>();
+        provenance.push(FactProvenance::new(
+            "relationship",
+            render_edge(holder, &self.relation, resource_id),
+            FactOutcome::Found,
+            None,
+        ));
+
+        let path = hops
+            .iter()
+            .map(|hop| format!("{hop:?}"))
+            .chain(std::iter::once(format!("{resource_id:?}")))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        PolicyEvalResult::granted_with_facts(
+            "TransitiveRebacPolicy",
+            Some(format!(
+                "Subject has '{}' relationship with resource via {path}",
+                self.relation
+            )),
+            provenance,
+        )
+    }
+}
+
+fn render_edge(
+    from: &impl fmt::Debug,
+    relation: &impl fmt::Display,
+    to: &impl fmt::Debug,
+) -> String {
+    format!("{from:?} -[{relation}]-> {to:?}")
+}
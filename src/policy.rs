@@ -1,7 +1,11 @@
-use crate::{EvaluationSession, FactProvenance, PolicyEvalResult, SecurityRuleMetadata};
+use crate::{
+    CombineOp, DeclaredFacts, EvaluationSession, FactProvenance, PolicyEvalResult,
+    SecurityRuleMetadata,
+};
 use async_trait::async_trait;
 use std::borrow::Cow;
 use std::sync::Arc;
+use tracing::Instrument;
 
 /// Names the four Rust types that make up one authorization domain.
 ///
@@ -121,6 +125,15 @@ impl<'a, D: PolicyDomain> EvalCtx<'a, D> {
         PolicyEvalResult::forbidden(self.policy_type.clone(), reason)
     }
 
+    /// Shorthand for `PolicyEvalResult::indeterminate(ctx.policy_type, reason)`.
+    ///
+    /// Use this when the policy could not decide, for example because a
+    /// lookup failed, so the trace shows the failure instead of a plain
+    /// non-grant.
+    pub fn indeterminate(&self, reason: impl Into<String>) -> PolicyEvalResult {
+        PolicyEvalResult::indeterminate(self.policy_type.clone(), reason)
+    }
+
     /// Shorthand for [`PolicyEvalResult::granted_with_facts`] tagged with
     /// `ctx.policy_type`.
     pub fn grant_with_facts(
@@ -175,6 +188,18 @@ pub struct BatchEvalCtx<'a, D: PolicyDomain> {
     pub policy_type: Cow<'static, str>,
 }
 
+/// The structure of a combinator policy, as exposed by
+/// [`Policy::composition`].
+pub struct PolicyComposition<'a, D: PolicyDomain> {
+    /// How the children's decisions are combined.
+    pub operation: CombineOp,
+    /// The child policies, in evaluation order.
+    pub children: &'a [Arc<dyn Policy<D>>],
+    /// The [`PolicyEvalResult::policy_id`] the combinator stamps on its
+    /// result, set by [`crate::PolicyExt::with_id`].
+    pub policy_id: Option<Cow<'static, str>>,
+}
+
 /// A generic async trait representing a single authorization policy for one
 /// [`PolicyDomain`].
 #[async_trait]
@@ -228,6 +253,89 @@ pub trait Policy<D: PolicyDomain>: Send + Sync {
     fn security_rule(&self) -> SecurityRuleMetadata {
         SecurityRuleMetadata::default()
     }
+
+    /// Declares the fact keys this policy expects to load for one request.
+    ///
+    /// This is a prefetch hint, not a contract. Before evaluating,
+    /// [`crate::PermissionChecker`] collects declarations from every policy
+    /// (for every item of a batch) and loads them through the session with
+    /// one [`EvaluationSession::get_many`] per key type, so the policy's own
+    /// `session.get` calls are served from the request cache. Declaring a key
+    /// the policy ends up not reading costs only the load; omitting one loses
+    /// only the prefetch. Decisions never depend on declarations.
+    ///
+    /// Combinators forward to their children. A [`crate::DelegatingPolicy`]
+    /// does not forward; its child checker prefetches its own declarations
+    /// when it is evaluated. The default declares nothing.
+    fn declared_facts(
+        &self,
+        _subject: &D::Subject,
+        _action: &D::Action,
+        _resource: &D::Resource,
+        _context: &D::Context,
+        _facts: &mut DeclaredFacts,
+    ) {
+    }
+
+    /// Exposes the children of a combinator policy.
+    ///
+    /// The built-in [`crate::AndPolicy`], [`crate::OrPolicy`],
+    /// [`crate::NotPolicy`], and [`crate::AtMostPolicy`] return their operation
+    /// and children in evaluation order; [`crate::PolicyExt::compile`] relies
+    /// on this to lower a tree. A custom policy should only return `Some` when
+    /// it evaluates exactly like the built-in combinator for `operation` over
+    /// the returned children. The default returns `None`, which makes the
+    /// policy an opaque leaf.
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        None
+    }
+}
+
+/// Evaluates `policy` inside a `gatehouse.policy` span carrying its type,
+/// outcome, and reason.
+///
+/// The checker, the built-in combinators, and [`crate::CompiledPolicy`]
+/// evaluate children through this, so nested combinators produce nested
+/// spans that mirror the [`PolicyEvalResult::Combined`] tree. Only the policy
+/// type, the outcome, and the policy's own reason are recorded, never the
+/// subject or resource.
+pub(crate) async fn evaluate_in_span<D, P>(policy: &P, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult
+where
+    D: PolicyDomain,
+    P: Policy<D> + ?Sized,
+{
+    let span = policy_span(&ctx.policy_type);
+    let result = policy.evaluate(ctx).instrument(span.clone()).await;
+    record_policy_outcome(&span, &result);
+    result
+}
+
+/// Opens the `gatehouse.policy` span [`evaluate_in_span`] evaluates a policy
+/// in, as a child of the current span.
+pub(crate) fn policy_span(policy_type: &str) -> tracing::Span {
+    tracing::debug_span!(
+        "gatehouse.policy",
+        policy.type = policy_type,
+        outcome = tracing::field::Empty,
+        reason = tracing::field::Empty,
+    )
+}
+
+/// Records a policy's outcome and reason on its `gatehouse.policy` span.
+pub(crate) fn record_policy_outcome(span: &tracing::Span, result: &PolicyEvalResult) {
+    let outcome = if result.is_granted() {
+        "granted"
+    } else if result.is_forbidden() {
+        "forbidden"
+    } else if result.is_indeterminate() {
+        "indeterminate"
+    } else {
+        "not_applicable"
+    };
+    span.record("outcome", outcome);
+    if let Some(reason) = result.reason_str() {
+        span.record("reason", reason);
+    }
 }
 
 #[async_trait]
@@ -254,6 +362,21 @@ where
     fn security_rule(&self) -> SecurityRuleMetadata {
         (**self).security_rule()
     }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        (**self).declared_facts(subject, action, resource, context, facts)
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        (**self).composition()
+    }
 }
 
 #[async_trait]
@@ -280,4 +403,19 @@ where
     fn security_rule(&self) -> SecurityRuleMetadata {
         (**self).security_rule()
     }
+
+    fn declared_facts(
+        &self,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+        facts: &mut DeclaredFacts,
+    ) {
+        (**self).declared_facts(subject, action, resource, context, facts)
+    }
+
+    fn composition(&self) -> Option<PolicyComposition<'_, D>> {
+        (**self).composition()
+    }
 }
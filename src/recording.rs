@@ -0,0 +1,180 @@
+//! Record-and-replay of checker inputs for reproducing production decisions.
+//!
+//! [`RecordingChecker`] snapshots the subject, action, resource, and context of
+//! each evaluation into a [`RecordSink`] as a serializable [`EvaluationRecord`].
+//! A record logged alongside an incident can later be deserialized and
+//! replayed against a local checker with [`EvaluationRecord::replay`].
+//!
+//! Records contain the full authorization inputs. Treat sinks as carrying the
+//! same sensitivity as the subject and resource data themselves.
+
+use crate::{AccessEvaluation, EvaluationSession, PermissionChecker, PolicyDomain};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// A serializable snapshot of one checker evaluation.
+#[derive(Serialize, Deserialize)]
+#[serde(bound(
+    serialize = "D::Subject: Serialize, D::Action: Serialize, D::Resource: Serialize, D::Context: Serialize",
+    deserialize = "D::Subject: Deserialize<'de>, D::Action: Deserialize<'de>, D::Resource: Deserialize<'de>, D::Context: Deserialize<'de>"
+))]
+pub struct EvaluationRecord<D: PolicyDomain> {
+    /// The checker name at record time, if the checker was named.
+    pub checker: Option<String>,
+    /// The evaluated subject.
+    pub subject: D::Subject,
+    /// The evaluated action.
+    pub action: D::Action,
+    /// The evaluated resource.
+    pub resource: D::Resource,
+    /// The evaluation context.
+    pub context: D::Context,
+    /// Whether the recorded evaluation granted access.
+    pub granted: bool,
+}
+
+impl<D: PolicyDomain> EvaluationRecord<D> {
+    /// Re-runs the recorded input against `checker` in `session`.
+    ///
+    /// Fact-backed policies read from `session`, so replaying a decision that
+    /// depended on facts needs a session whose sources reproduce them.
+    pub async fn replay(
+        &self,
+        checker: &PermissionChecker<D>,
+        session: &EvaluationSession,
+    ) -> AccessEvaluation {
+        checker
+            .bind(session, &self.subject, &self.action, &self.context)
+            .check(&self.resource)
+            .await
+    }
+}
+
+impl<D: PolicyDomain> Clone for EvaluationRecord<D>
+where
+    D::Subject: Clone,
+    D::Action: Clone,
+    D::Resource: Clone,
+    D::Context: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            checker: self.checker.clone(),
+            subject: self.subject.clone(),
+            action: self.action.clone(),
+            resource: self.resource.clone(),
+            context: self.context.clone(),
+            granted: self.granted,
+        }
+    }
+}
+
+impl<D: PolicyDomain> fmt::Debug for EvaluationRecord<D>
+where
+    D::Subject: fmt::Debug,
+    D::Action: fmt::Debug,
+    D::Resource: fmt::Debug,
+    D::Context: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("EvaluationRecord")
+            .field("checker", &self.checker)
+            .field("subject", &self.subject)
+            .field("action", &self.action)
+            .field("resource", &self.resource)
+            .field("context", &self.context)
+            .field("granted", &self.granted)
+            .finish()
+    }
+}
+
+/// Receives records captured by a [`RecordingChecker`].
+///
+/// Sinks are called synchronously on the evaluation path, so they should hand
+/// records off (to a channel, log line, or buffer) rather than block.
+pub trait RecordSink<D: PolicyDomain>: Send + Sync {
+    /// Accepts one captured evaluation.
+    fn record(&self, record: EvaluationRecord<D>);
+}
+
+impl<D, F> RecordSink<D> for F
+where
+    D: PolicyDomain,
+    F: Fn(EvaluationRecord<D>) + Send + Sync,
+{
+    fn record(&self, record: EvaluationRecord<D>) {
+        self(record)
+    }
+}
+
+/// A [`PermissionChecker`] wrapper that records every evaluation's inputs.
+pub struct RecordingChecker<D: PolicyDomain> {
+    checker: PermissionChecker<D>,
+    sink: Arc<dyn RecordSink<D>>,
+}
+
+impl<D: PolicyDomain> Clone for RecordingChecker<D> {
+    fn clone(&self) -> Self {
+        Self {
+            checker: self.checker.clone(),
+            sink: Arc::clone(&self.sink),
+        }
+    }
+}
+
+impl<D: PolicyDomain> RecordingChecker<D> {
+    /// Wraps `checker`, sending a record of every evaluation to `sink`.
+    pub fn new(checker: PermissionChecker<D>, sink: impl RecordSink<D> + 'static) -> Self {
+        Self {
+            checker,
+            sink: Arc::new(sink),
+        }
+    }
+
+    /// Returns the wrapped checker.
+    pub fn checker(&self) -> &PermissionChecker<D> {
+        &self.checker
+    }
+
+    /// Evaluates one resource and records the inputs and decision.
+    pub async fn check(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> AccessEvaluation
+    where
+        D::Subject: Clone,
+        D::Action: Clone,
+        D::Resource: Clone,
+        D::Context: Clone,
+    {
+        let evaluation = self
+            .checker
+            .bind(session, subject, action, context)
+            .check(resource)
+            .await;
+        self.sink.record(EvaluationRecord {
+            checker: self.checker.name().map(str::to_owned),
+            subject: subject.clone(),
+            action: action.clone(),
+            resource: resource.clone(),
+            context: context.clone(),
+            granted: evaluation.is_granted(),
+        });
+        evaluation
+    }
+
+    /// Re-runs a captured record against the wrapped checker without
+    /// recording it again.
+    pub async fn replay(
+        &self,
+        session: &EvaluationSession,
+        record: &EvaluationRecord<D>,
+    ) -> AccessEvaluation {
+        record.replay(&self.checker, session).await
+    }
+}
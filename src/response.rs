@@ -0,0 +1,286 @@
+//! HTTP responses for [`AccessEvaluation`] in axum and actix-web handlers.
+//!
+//! With the `axum` feature, [`AccessEvaluation`] and [`AccessResponse`]
+//! implement `axum::response::IntoResponse`; with the `actix` feature they
+//! implement `actix_web::Responder`. A grant becomes an empty `200 OK` and a
+//! denial a `403 Forbidden`, so a handler can return the evaluation as its
+//! error type, for example `Result<Json<Invoice>, AccessEvaluation>`.
+//!
+//! [`ObligationHandlers`] carries a grant's [`Obligation`]s over to the
+//! response the handler builds, for example as headers.
+
+use crate::{AccessEvaluation, Obligation};
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// What the body of a `403 Forbidden` [`AccessResponse`] holds.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DenialBody {
+    /// The denial reason as `text/plain`.
+    #[default]
+    Reason,
+    /// The denial reason followed by the formatted evaluation trace, as
+    /// `text/plain`.
+    Trace,
+    /// A [`crate::ForbiddenResponse`] as `application/json`.
+    #[cfg(feature = "serde")]
+    Json,
+}
+
+/// An [`AccessEvaluation`] ready to be returned from an HTTP handler.
+///
+/// Converting an evaluation directly uses [`DenialBody::Reason`]. Like
+/// [`crate::GatehouseLayer`], the trace is left out unless asked for with
+/// [`Self::with_denial_body`], since it names policies and their reasons to
+/// whoever made the request.
+#[derive(Debug, Clone)]
+pub struct AccessResponse {
+    evaluation: AccessEvaluation,
+    denial_body: DenialBody,
+}
+
+impl AccessResponse {
+    /// Wraps `evaluation` with the default [`DenialBody::Reason`] body.
+    pub fn new(evaluation: AccessEvaluation) -> Self {
+        Self {
+            evaluation,
+            denial_body: DenialBody::default(),
+        }
+    }
+
+    /// Sets the body sent with a denial.
+    pub fn with_denial_body(mut self, denial_body: DenialBody) -> Self {
+        self.denial_body = denial_body;
+        self
+    }
+
+    /// The wrapped evaluation.
+    pub fn evaluation(&self) -> &AccessEvaluation {
+        &self.evaluation
+    }
+
+    /// The status code, content type, and body of the response.
+    fn parts(&self) -> (u16, Option<&'static str>, String) {
+        const TEXT: &str = "text/plain; charset=utf-8";
+        let AccessEvaluation::Denied { reason, trace } = &self.evaluation else {
+            return (200, None, String::new());
+        };
+        match self.denial_body {
+            DenialBody::Reason => (403, Some(TEXT), reason.clone()),
+            DenialBody::Trace => (403, Some(TEXT), format!("{reason}\n\n{}", trace.format())),
+            #[cfg(feature = "serde")]
+            DenialBody::Json => {
+                let body = serde_json::to_string(&crate::ForbiddenResponse::from(&self.evaluation))
+                    .expect("forbidden responses should always serialize to JSON");
+                (403, Some("application/json"), body)
+            }
+        }
+    }
+}
+
+impl From<AccessEvaluation> for AccessResponse {
+    fn from(evaluation: AccessEvaluation) -> Self {
+        Self::new(evaluation)
+    }
+}
+
+#[cfg(feature = "axum")]
+impl axum_core::response::IntoResponse for AccessResponse {
+    fn into_response(self) -> axum_core::response::Response {
+        let (status, content_type, body) = self.parts();
+        let mut response = http::Response::builder()
+            .status(status)
+            .body(axum_core::body::Body::from(body))
+            .expect("access responses use valid status codes");
+        if let Some(content_type) = content_type {
+            response.headers_mut().insert(
+                http::header::CONTENT_TYPE,
+                http::HeaderValue::from_static(content_type),
+            );
+        }
+        response
+    }
+}
+
+#[cfg(feature = "axum")]
+impl axum_core::response::IntoResponse for AccessEvaluation {
+    fn into_response(self) -> axum_core::response::Response {
+        AccessResponse::new(self).into_response()
+    }
+}
+
+#[cfg(feature = "actix")]
+impl actix_web::Responder for AccessResponse {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, _request: &actix_web::HttpRequest) -> actix_web::HttpResponse {
+        let (status, content_type, body) = self.parts();
+        let status = actix_web::http::StatusCode::from_u16(status)
+            .expect("access responses use valid status codes");
+        let mut response = actix_web::HttpResponse::build(status);
+        if let Some(content_type) = content_type {
+            response.content_type(content_type);
+        }
+        response.body(body)
+    }
+}
+
+#[cfg(feature = "actix")]
+impl actix_web::Responder for AccessEvaluation {
+    type Body = actix_web::body::BoxBody;
+
+    fn respond_to(self, request: &actix_web::HttpRequest) -> actix_web::HttpResponse {
+        AccessResponse::new(self).respond_to(request)
+    }
+}
+
+type ObligationHandler<R> = Arc<dyn Fn(&Obligation, &mut R) -> bool + Send + Sync>;
+
+/// Maps obligation ids to changes to a handler's response `R`, so a grant's
+/// [`AccessEvaluation::obligations`] are enforced where the response is
+/// built.
+///
+/// Build one at startup with [`Self::on`], or with `header` for the common
+/// case of copying an obligation's value into a response header, and call
+/// [`Self::apply`] on each granted response. `R` is
+/// `axum::response::Response` with the `axum` feature or
+/// `actix_web::HttpResponse` with the `actix` feature, but any response type
+/// works with [`Self::on`].
+///
+/// ```rust
+/// # #[cfg(feature = "axum")]
+/// # {
+/// # use gatehouse::*;
+/// # use axum_core::response::{IntoResponse, Response};
+/// let obligations = ObligationHandlers::<Response>::new().header("watermark", "x-watermark");
+///
+/// let evaluation = AccessEvaluation::Granted {
+///     policy_type: "Owner".into(),
+///     reason: None,
+///     trace: EvalTrace::with_root(
+///         PolicyEvalResult::granted("Owner", None)
+///             .with_obligation(Obligation::new("watermark", "owner")),
+///     ),
+/// };
+/// let mut response = "report".into_response();
+/// let unenforced = obligations.apply(&evaluation, &mut response);
+/// assert!(unenforced.is_empty());
+/// assert_eq!(response.headers()["x-watermark"], "owner");
+/// # }
+/// ```
+pub struct ObligationHandlers<R> {
+    handlers: HashMap<Cow<'static, str>, ObligationHandler<R>>,
+}
+
+impl<R> Clone for ObligationHandlers<R> {
+    fn clone(&self) -> Self {
+        Self {
+            handlers: self.handlers.clone(),
+        }
+    }
+}
+
+impl<R> Default for ObligationHandlers<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R> std::fmt::Debug for ObligationHandlers<R> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ObligationHandlers")
+            .field("ids", &self.handlers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl<R> ObligationHandlers<R> {
+    /// Creates a set with no handlers.
+    pub fn new() -> Self {
+        Self {
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// Handles obligations with `id` by calling `handler`, replacing any
+    /// earlier handler for the same id.
+    ///
+    /// The handler returns whether it enforced the obligation.
+    pub fn on(
+        mut self,
+        id: impl Into<Cow<'static, str>>,
+        handler: impl Fn(&Obligation, &mut R) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.handlers.insert(id.into(), Arc::new(handler));
+        self
+    }
+
+    /// Runs the handler for each of `evaluation`'s obligations against
+    /// `response`, and returns the obligations that were not enforced.
+    ///
+    /// An obligation is not enforced when no handler is registered for its
+    /// id or its handler returned `false`. A grant is conditional on its
+    /// obligations, so a handler should fail the request rather than send the
+    /// response when this is not empty. Denials carry no obligations.
+    pub fn apply<'a>(
+        &self,
+        evaluation: &'a AccessEvaluation,
+        response: &mut R,
+    ) -> Vec<&'a Obligation> {
+        evaluation
+            .obligations()
+            .into_iter()
+            .filter(|obligation| {
+                !self
+                    .handlers
+                    .get(obligation.id.as_ref())
+                    .is_some_and(|handler| handler(obligation, response))
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "axum")]
+impl ObligationHandlers<axum_core::response::Response> {
+    /// Handles obligations with `id` by setting the response header `name`
+    /// to the obligation's value. Values that are not valid header values
+    /// are left unenforced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid lowercase header name.
+    pub fn header(self, id: impl Into<Cow<'static, str>>, name: &'static str) -> Self {
+        let name = http::HeaderName::from_static(name);
+        self.on(id, move |obligation, response| {
+            let Ok(value) = http::HeaderValue::from_str(&obligation.value) else {
+                return false;
+            };
+            response.headers_mut().insert(name.clone(), value);
+            true
+        })
+    }
+}
+
+#[cfg(feature = "actix")]
+impl ObligationHandlers<actix_web::HttpResponse> {
+    /// Handles obligations with `id` by setting the response header `name`
+    /// to the obligation's value. Values that are not valid header values
+    /// are left unenforced.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `name` is not a valid lowercase header name.
+    pub fn header(self, id: impl Into<Cow<'static, str>>, name: &'static str) -> Self {
+        use actix_web::http::header::{HeaderName, HeaderValue};
+        let name = HeaderName::from_static(name);
+        self.on(id, move |obligation, response| {
+            let Ok(value) = HeaderValue::from_str(&obligation.value) else {
+                return false;
+            };
+            response.headers_mut().insert(name.clone(), value);
+            true
+        })
+    }
+}
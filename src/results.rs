@@ -1,10 +1,13 @@
 use std::borrow::Cow;
 use std::fmt;
+use std::time::SystemTime;
 
 /// The type of boolean combining operation a policy might represent.
+///
+/// With the `serde` feature it serializes as its [`Display`](fmt::Display)
+/// string, such as `"AND"` or `"AT_MOST(2)"`, so parameterized operations
+/// have the same shape as the others.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
 #[non_exhaustive]
 pub enum CombineOp {
     /// All inner policies must grant access.
@@ -16,8 +19,43 @@ pub enum CombineOp {
     /// A parent policy delegated the decision to another checker.
     Delegate,
     /// Any forbidding policy denies; otherwise at least one policy must
-    /// grant. The root operation of [`crate::PermissionChecker`].
+    /// grant. The default root operation of [`crate::PermissionChecker`].
     DenyOverrides,
+    /// At least one and at most `max_grants` inner policies must grant. See
+    /// [`crate::AtMostPolicy`].
+    AtMost {
+        /// The largest number of inner grants that still grants.
+        max_grants: usize,
+    },
+    /// The first tier to grant or forbid decides, highest priority first.
+    /// See [`crate::TieredPolicy`].
+    Tiered,
+    /// At least `threshold` of the `of` inner policies must grant. See
+    /// [`crate::ThresholdPolicy`].
+    Threshold {
+        /// The smallest number of inner grants that grants.
+        threshold: usize,
+        /// The number of inner policies.
+        of: usize,
+    },
+    /// The inner grants must be mutually exclusive. See
+    /// [`crate::XorPolicy`].
+    Xor {
+        /// How many inner grants are accepted.
+        mode: crate::XorMode,
+    },
+    /// Exactly one policy may be applicable, and it decides. See
+    /// [`crate::CombineStrategy::OnlyOneApplicable`].
+    OnlyOneApplicable,
+    /// The first inner grant weighted at or above a floor grants. See
+    /// [`crate::WeightedOrPolicy`].
+    WeightedOr,
+    /// The first policy to grant or forbid decides, in the order policies
+    /// were added. See [`crate::CombineStrategy::FirstMatch`].
+    FirstApplicable,
+    /// The inner contributions must sum to at least a threshold. See
+    /// [`crate::ScoreChecker`].
+    Score,
 }
 
 impl fmt::Display for CombineOp {
@@ -28,10 +66,28 @@ impl fmt::Display for CombineOp {
             CombineOp::Not => write!(f, "NOT"),
             CombineOp::Delegate => write!(f, "DELEGATE"),
             CombineOp::DenyOverrides => write!(f, "DENY_OVERRIDES"),
+            CombineOp::AtMost { max_grants } => write!(f, "AT_MOST({max_grants})"),
+            CombineOp::Tiered => write!(f, "TIERED"),
+            CombineOp::Threshold { threshold, of } => write!(f, "THRESHOLD({threshold}/{of})"),
+            CombineOp::Xor { mode } => match mode {
+                crate::XorMode::ExactlyOne => write!(f, "XOR"),
+                crate::XorMode::Odd => write!(f, "XOR(ODD)"),
+            },
+            CombineOp::OnlyOneApplicable => write!(f, "ONLY_ONE_APPLICABLE"),
+            CombineOp::WeightedOr => write!(f, "WEIGHTED_OR"),
+            CombineOp::FirstApplicable => write!(f, "FIRST_APPLICABLE"),
+            CombineOp::Score => write!(f, "SCORE"),
         }
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for CombineOp {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
 /// How a fact load that informed a policy decision resolved.
 ///
 /// This mirrors [`crate::FactLoadResult`] without its value type, so it can be
@@ -131,6 +187,39 @@ impl fmt::Display for FactProvenance {
     }
 }
 
+/// A follow-up action the caller must perform when acting on a grant, such
+/// as redacting a field or writing an audit record.
+///
+/// Policies attach obligations to their grants with
+/// [`PolicyEvalResult::with_obligation`]; the caller reads the merged set
+/// from [`AccessEvaluation::obligations`]. Gatehouse does not interpret
+/// obligations, so `id` and `value` are whatever the policy and the handler
+/// agree on.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct Obligation {
+    /// What must be done, such as `"redact"` or `"audit"`.
+    pub id: Cow<'static, str>,
+    /// The parameter of the obligation, such as the field to redact.
+    pub value: String,
+}
+
+impl Obligation {
+    /// Creates an obligation.
+    pub fn new(id: impl Into<Cow<'static, str>>, value: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            value: value.into(),
+        }
+    }
+}
+
+impl fmt::Display for Obligation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.id, self.value)
+    }
+}
+
 /// The result of evaluating a single policy (or a combination).
 ///
 /// This enum is used both by individual policies and by combinators to represent the
@@ -145,10 +234,13 @@ impl fmt::Display for FactProvenance {
 ///   semantics). Produced by [`crate::PolicyBuilder`] policies with
 ///   [`crate::Effect::Forbid`] whose predicate matches, or by custom policies via
 ///   [`crate::EvalCtx::forbid`].
+/// - [`PolicyEvalResult::Indeterminate`]: Indicates the policy could not reach a decision, for
+///   example because a backing lookup failed. It never grants, so the checker fails closed, but
+///   the trace keeps "couldn't decide" distinct from "did not grant".
 /// - [`PolicyEvalResult::Combined`]: Represents the aggregate result of combining multiple policies.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 #[non_exhaustive]
 pub enum PolicyEvalResult {
     /// Access granted. Contains the policy type and an optional reason.
@@ -159,21 +251,43 @@ pub enum PolicyEvalResult {
         /// pass through with zero allocation; dynamic names still work via
         /// `Cow::Owned`.
         policy_type: Cow<'static, str>,
+        /// Caller-supplied identifier of the policy instance, set with
+        /// [`crate::PolicyExt::with_id`]. Distinguishes instances that share
+        /// a policy type.
+        policy_id: Option<Cow<'static, str>>,
         /// An optional human-readable reason for the grant.
         reason: Option<String>,
         /// Facts the policy consulted to reach this decision. Empty for
         /// policies that are not fact-backed.
         provenance: Vec<FactProvenance>,
+        /// Advisory risk contribution, summed by
+        /// [`AccessEvaluation::aggregate_risk`]. Never affects the decision.
+        risk: Option<f32>,
+        /// When the grant stops holding, for time-limited grants. The
+        /// earliest expiry among the grants behind a decision is reported by
+        /// [`AccessEvaluation::valid_until`].
+        valid_until: Option<SystemTime>,
+        /// Actions the caller must perform if it acts on this grant, merged
+        /// across the grants behind a decision by
+        /// [`AccessEvaluation::obligations`].
+        obligations: Vec<Obligation>,
     },
     /// Policy did not apply. Contains the policy type and a reason.
     NotApplicable {
         /// The name of the policy that did not apply.
         policy_type: Cow<'static, str>,
+        /// Caller-supplied identifier of the policy instance, set with
+        /// [`crate::PolicyExt::with_id`]. Distinguishes instances that share
+        /// a policy type.
+        policy_id: Option<Cow<'static, str>>,
         /// A human-readable reason why this policy did not grant.
         reason: String,
         /// Facts the policy consulted to reach this decision. Empty for
         /// policies that are not fact-backed.
         provenance: Vec<FactProvenance>,
+        /// Advisory risk contribution, summed by
+        /// [`AccessEvaluation::aggregate_risk`]. Never affects the decision.
+        risk: Option<f32>,
     },
     /// Access actively forbidden: the policy matched and vetoes this request.
     ///
@@ -184,11 +298,41 @@ pub enum PolicyEvalResult {
     Forbidden {
         /// The name of the policy that forbids access.
         policy_type: Cow<'static, str>,
+        /// Caller-supplied identifier of the policy instance, set with
+        /// [`crate::PolicyExt::with_id`]. Distinguishes instances that share
+        /// a policy type.
+        policy_id: Option<Cow<'static, str>>,
         /// A human-readable reason for the veto.
         reason: String,
         /// Facts the policy consulted to reach this decision. Empty for
         /// policies that are not fact-backed.
         provenance: Vec<FactProvenance>,
+        /// Advisory risk contribution, summed by
+        /// [`AccessEvaluation::aggregate_risk`]. Never affects the decision.
+        risk: Option<f32>,
+    },
+    /// The policy could not reach a decision.
+    ///
+    /// Treated like [`PolicyEvalResult::NotApplicable`] when combining, so it
+    /// never grants, except that a [`crate::NotPolicy`] over an indeterminate
+    /// result does not grant either. Use it when a policy's inputs were
+    /// unavailable (a failed database or remote call) rather than answering
+    /// `false`.
+    Indeterminate {
+        /// The name of the policy that could not decide.
+        policy_type: Cow<'static, str>,
+        /// Caller-supplied identifier of the policy instance, set with
+        /// [`crate::PolicyExt::with_id`]. Distinguishes instances that share
+        /// a policy type.
+        policy_id: Option<Cow<'static, str>>,
+        /// A human-readable reason why no decision was reached.
+        reason: String,
+        /// Facts the policy consulted before giving up. Empty for policies
+        /// that are not fact-backed.
+        provenance: Vec<FactProvenance>,
+        /// Advisory risk contribution, summed by
+        /// [`AccessEvaluation::aggregate_risk`]. Never affects the decision.
+        risk: Option<f32>,
     },
     /// Combined result from multiple policy evaluations.
     /// Contains the policy type, the combining operation ([`CombineOp`]),
@@ -196,6 +340,10 @@ pub enum PolicyEvalResult {
     Combined {
         /// The name of the combinator policy (e.g. `"AndPolicy"`).
         policy_type: Cow<'static, str>,
+        /// Caller-supplied identifier of the policy instance, set with
+        /// [`crate::PolicyExt::with_id`]. Distinguishes instances that share
+        /// a policy type.
+        policy_id: Option<Cow<'static, str>>,
         /// The boolean operation used to combine child results.
         operation: CombineOp,
         /// The individual results from each child policy.
@@ -260,7 +408,7 @@ pub enum PolicyEvalResult {
 /// ```
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
-#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+#[cfg_attr(feature = "serde", serde(tag = "type", rename_all = "snake_case"))]
 #[non_exhaustive]
 pub enum AccessEvaluation {
     /// Access was granted.
@@ -283,19 +431,190 @@ pub enum AccessEvaluation {
     },
 }
 
+/// The outcome of an evaluation without its trace.
+///
+/// A compact alternative to [`AccessEvaluation`] for callers that only need
+/// to `match` on the result. Get one from [`AccessEvaluation::decision`], by
+/// converting an evaluation with `Decision::from`, or directly from
+/// [`crate::PermissionChecker::decide`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(tag = "type", content = "reason", rename_all = "snake_case")
+)]
+#[non_exhaustive]
+pub enum Decision {
+    /// Access was granted.
+    Allow,
+    /// Access was denied, with the summary denial reason.
+    Deny(String),
+}
+
+impl From<AccessEvaluation> for Decision {
+    fn from(evaluation: AccessEvaluation) -> Self {
+        match evaluation {
+            AccessEvaluation::Granted { .. } => Self::Allow,
+            AccessEvaluation::Denied { reason, .. } => Self::Deny(reason),
+        }
+    }
+}
+
+/// An evaluation flattened into plain fields.
+///
+/// For callers that want the outcome, reason, obligations, and trace without
+/// matching on [`AccessEvaluation`]'s variants, such as audit logging or a
+/// JSON API. Get one from [`AccessEvaluation::into_summary`]. Unlike
+/// [`Decision`], it keeps the trace.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct DecisionSummary {
+    /// Whether access was granted.
+    pub allowed: bool,
+    /// The granting policy's reason, or `"granted by {policy}"` when it gave
+    /// none; the summary denial reason for denials.
+    pub reason: String,
+    /// The obligations of a grant, as from [`AccessEvaluation::obligations`].
+    /// Always empty for denials.
+    pub obligations: Vec<Obligation>,
+    /// The full evaluation trace.
+    pub trace: EvalTrace,
+}
+
+impl From<AccessEvaluation> for DecisionSummary {
+    fn from(evaluation: AccessEvaluation) -> Self {
+        evaluation.into_summary()
+    }
+}
+
+/// Error returned by [`crate::PermissionChecker::authorize`] when access is
+/// denied.
+///
+/// [`Display`](fmt::Display) shows the summary denial reason; the full trace
+/// stays available through [`Self::trace`] for logging.
+#[derive(Debug, Clone)]
+pub struct AccessDenied {
+    reason: String,
+    trace: EvalTrace,
+}
+
+impl AccessDenied {
+    /// The summary denial reason.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// The evaluation trace of the denied request.
+    pub fn trace(&self) -> &EvalTrace {
+        &self.trace
+    }
+}
+
+impl fmt::Display for AccessDenied {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "access denied: {}", self.reason)
+    }
+}
+
+impl std::error::Error for AccessDenied {}
+
+impl From<AccessEvaluation> for Result<(), AccessDenied> {
+    fn from(evaluation: AccessEvaluation) -> Self {
+        match evaluation {
+            AccessEvaluation::Granted { .. } => Ok(()),
+            AccessEvaluation::Denied { reason, trace } => Err(AccessDenied { reason, trace }),
+        }
+    }
+}
+
 /// Walks a [`PolicyEvalResult`] tree looking for a `NotApplicable`
 /// leaf whose `policy_type` equals `expected`. Used by
 /// [`AccessEvaluation::assert_not_applicable_by`].
 fn leaf_not_applicable_matches(node: &PolicyEvalResult, expected: &str) -> bool {
     match node {
         PolicyEvalResult::NotApplicable { policy_type, .. } => policy_type.as_ref() == expected,
-        PolicyEvalResult::Granted { .. } | PolicyEvalResult::Forbidden { .. } => false,
+        PolicyEvalResult::Granted { .. }
+        | PolicyEvalResult::Forbidden { .. }
+        | PolicyEvalResult::Indeterminate { .. } => false,
         PolicyEvalResult::Combined { children, .. } => children
             .iter()
             .any(|child| leaf_not_applicable_matches(child, expected)),
     }
 }
 
+/// Whether any node under `node`, combinators included, was produced by a
+/// policy whose type or id is `expected`.
+fn policy_appears(node: &PolicyEvalResult, expected: &str) -> bool {
+    let (policy_type, children) = match node {
+        PolicyEvalResult::Granted { policy_type, .. }
+        | PolicyEvalResult::NotApplicable { policy_type, .. }
+        | PolicyEvalResult::Forbidden { policy_type, .. }
+        | PolicyEvalResult::Indeterminate { policy_type, .. } => (policy_type, &[][..]),
+        PolicyEvalResult::Combined {
+            policy_type,
+            children,
+            ..
+        } => (policy_type, children.as_slice()),
+    };
+    policy_type.as_ref() == expected
+        || node.policy_id() == Some(expected)
+        || children.iter().any(|child| policy_appears(child, expected))
+}
+
+/// Collects `(policy_type, reason)` from the non-granting leaves under
+/// `node`, skipping granted subtrees. Used by
+/// [`AccessEvaluation::all_denial_reasons`].
+fn collect_denial_reasons(node: &PolicyEvalResult, reasons: &mut Vec<(String, String)>) {
+    match node {
+        PolicyEvalResult::Granted { .. } => {}
+        PolicyEvalResult::NotApplicable {
+            policy_type,
+            reason,
+            ..
+        }
+        | PolicyEvalResult::Forbidden {
+            policy_type,
+            reason,
+            ..
+        }
+        | PolicyEvalResult::Indeterminate {
+            policy_type,
+            reason,
+            ..
+        } => reasons.push((policy_type.to_string(), reason.clone())),
+        PolicyEvalResult::Combined {
+            children, outcome, ..
+        } => {
+            if !outcome {
+                for child in children {
+                    collect_denial_reasons(child, reasons);
+                }
+            }
+        }
+    }
+}
+
+/// Collects `(policy_type, reason)` for every leaf of `node`, whatever the
+/// outcome of the combinators above it, split by whether the leaf granted.
+fn collect_leaf_reasons(
+    node: &PolicyEvalResult,
+    granted: bool,
+    reasons: &mut Vec<(String, String)>,
+) {
+    match node {
+        PolicyEvalResult::Combined { children, .. } => {
+            for child in children {
+                collect_leaf_reasons(child, granted, reasons);
+            }
+        }
+        leaf if leaf.is_granted() == granted => reasons.push((
+            leaf.policy_type_str().to_string(),
+            leaf.reason().unwrap_or_default(),
+        )),
+        _ => {}
+    }
+}
+
 impl AccessEvaluation {
     /// Whether access was granted
     pub fn is_granted(&self) -> bool {
@@ -313,6 +632,23 @@ impl AccessEvaluation {
         }
     }
 
+    /// Returns how many of the checker's policies ran before it decided.
+    ///
+    /// Filled in by every [`crate::PermissionChecker`] entry point, batch
+    /// ones included. `None` for evaluations built by hand, and for batch
+    /// items that [`crate::BoundEvaluator::evaluate_with_deadline`] denied
+    /// because the deadline passed before they were evaluated.
+    pub fn stats(&self) -> Option<EvalStats> {
+        self.trace().stats()
+    }
+
+    pub(crate) fn with_stats(mut self, stats: EvalStats) -> Self {
+        match &mut self {
+            Self::Granted { trace, .. } | Self::Denied { trace, .. } => trace.stats = Some(stats),
+        }
+        self
+    }
+
     /// Returns the granting policy's name when the evaluation was a grant.
     ///
     /// Useful for non-panicking inspection in tests and in production code
@@ -346,12 +682,7 @@ impl AccessEvaluation {
         let Self::Denied { trace, .. } = self else {
             return None;
         };
-        let Some(PolicyEvalResult::Combined {
-            operation: CombineOp::DenyOverrides,
-            children,
-            ..
-        }) = trace.root()
-        else {
+        let Some(PolicyEvalResult::Combined { children, .. }) = trace.root() else {
             return None;
         };
         children
@@ -359,6 +690,134 @@ impl AccessEvaluation {
             .find_map(|child| child.forbidden_leaf().map(|(policy_type, _)| policy_type))
     }
 
+    /// Returns whether this denial happened because some policy could not
+    /// decide, such as a failed fact load or a timeout, rather than because
+    /// no policy granted or a forbid vetoed.
+    ///
+    /// See [`PolicyEvalResult::is_indeterminate`]. Returns `false` for grants
+    /// and for forbid vetoes.
+    pub fn is_indeterminate(&self) -> bool {
+        match self {
+            Self::Granted { .. } => false,
+            Self::Denied { trace, .. } => {
+                self.forbidden_by().is_none()
+                    && trace.root().is_some_and(PolicyEvalResult::is_indeterminate)
+            }
+        }
+    }
+
+    /// Returns `(policy_type, reason)` for every non-granting leaf in the
+    /// trace of a denial, in evaluation order.
+    ///
+    /// Where [`Self::denied_reason`] is one summary such as `"All policies
+    /// denied access"`, this lists each policy's own reason so an API can
+    /// answer "denied because: X, Y, Z". Subtrees that granted are skipped,
+    /// as are policies a short-circuit never evaluated. Returns an empty list
+    /// for grants.
+    pub fn all_denial_reasons(&self) -> Vec<(String, String)> {
+        let mut reasons = Vec::new();
+        if let (Self::Denied { .. }, Some(root)) = (self, self.trace().root()) {
+            collect_denial_reasons(root, &mut reasons);
+        }
+        reasons
+    }
+
+    /// Returns `(policy_type, reason)` for every evaluated policy in the
+    /// trace that did not grant, in evaluation order, whatever the decision.
+    ///
+    /// Unlike [`Self::all_denial_reasons`], this also lists the policies that
+    /// declined when a sibling's grant carried the decision, so an `OR` grant
+    /// still shows who said no. Only leaf results are listed; a policy under
+    /// a [`CombineOp::Not`] is reported by its own result. Policies a
+    /// short-circuit never evaluated do not appear.
+    pub fn denied_policies(&self) -> Vec<(String, String)> {
+        let mut reasons = Vec::new();
+        if let Some(root) = self.trace().root() {
+            collect_leaf_reasons(root, false, &mut reasons);
+        }
+        reasons
+    }
+
+    /// Returns `(policy_type, reason)` for every evaluated policy in the
+    /// trace that granted, in evaluation order, whatever the decision. A
+    /// grant without a reason is listed with an empty one.
+    ///
+    /// See [`Self::denied_policies`] for which policies are listed.
+    pub fn granted_policies(&self) -> Vec<(String, String)> {
+        let mut reasons = Vec::new();
+        if let Some(root) = self.trace().root() {
+            collect_leaf_reasons(root, true, &mut reasons);
+        }
+        reasons
+    }
+
+    /// Returns the earliest expiry among the grants behind a granted
+    /// decision, or `None` for denials and for grants that do not expire.
+    ///
+    /// Callers minting capability tokens from a grant should cap the token
+    /// lifetime at this instant. See [`PolicyEvalResult::with_valid_until`].
+    pub fn valid_until(&self) -> Option<SystemTime> {
+        match self {
+            Self::Granted { trace, .. } => trace.root().and_then(PolicyEvalResult::valid_until),
+            Self::Denied { .. } => None,
+        }
+    }
+
+    /// Collapses the evaluation into a [`Decision`], dropping the trace.
+    ///
+    /// A denial carries the same summary reason as [`Self::Denied`].
+    pub fn decision(&self) -> Decision {
+        match self {
+            Self::Granted { .. } => Decision::Allow,
+            Self::Denied { reason, .. } => Decision::Deny(reason.clone()),
+        }
+    }
+
+    /// Flattens the evaluation into a [`DecisionSummary`], keeping the trace.
+    pub fn into_summary(self) -> DecisionSummary {
+        let obligations = self.obligations().into_iter().cloned().collect();
+        match self {
+            Self::Granted {
+                policy_type,
+                reason,
+                trace,
+            } => DecisionSummary {
+                allowed: true,
+                reason: reason.unwrap_or_else(|| format!("granted by {policy_type}")),
+                obligations,
+                trace,
+            },
+            Self::Denied { trace, reason } => DecisionSummary {
+                allowed: false,
+                reason,
+                obligations,
+                trace,
+            },
+        }
+    }
+
+    /// Returns the obligations of every grant behind a granted decision, in
+    /// trace order, or nothing for denials.
+    ///
+    /// A caller that acts on the grant must fulfil each of them. See
+    /// [`PolicyEvalResult::with_obligation`].
+    pub fn obligations(&self) -> Vec<&Obligation> {
+        let mut obligations = Vec::new();
+        if let (Self::Granted { .. }, Some(root)) = (self, self.trace().root()) {
+            root.collect_obligations(&mut obligations);
+        }
+        obligations
+    }
+
+    /// Sums the advisory risk contributions of every evaluated policy, or
+    /// `None` when no evaluated policy reported one.
+    ///
+    /// Policies skipped by a short-circuit do not contribute. The score is
+    /// informational; the decision is [`Self::is_granted`].
+    pub fn aggregate_risk(&self) -> Option<f32> {
+        self.trace().root().and_then(PolicyEvalResult::total_risk)
+    }
+
     /// Test helper: panic unless the evaluation is `Granted` and the
     /// granting policy's name matches `expected`.
     ///
@@ -387,14 +846,19 @@ impl AccessEvaluation {
     pub fn assert_granted_by(&self, expected: &str) {
         match self {
             Self::Granted { policy_type, .. } => {
-                assert_eq!(
-                    policy_type.as_ref(),
-                    expected,
-                    "expected grant by policy `{expected}`, but the grant came from `{policy_type}`"
+                assert!(
+                    policy_type.as_ref() == expected,
+                    "expected grant by policy `{expected}`, but the grant came from \
+                     `{policy_type}`; got:\n{}",
+                    self.display_trace()
                 );
             }
             Self::Denied { reason, .. } => {
-                panic!("expected grant by policy `{expected}`, but access was denied: {reason}");
+                panic!(
+                    "expected grant by policy `{expected}`, but access was denied: {reason}; \
+                     got:\n{}",
+                    self.display_trace()
+                );
             }
         }
     }
@@ -412,11 +876,12 @@ impl AccessEvaluation {
         } = self
         {
             panic!(
-                "expected denial, but access was granted by `{policy_type}`{}",
+                "expected denial, but access was granted by `{policy_type}`{}; got:\n{}",
                 reason
                     .as_ref()
                     .map(|r| format!(": {r}"))
-                    .unwrap_or_default()
+                    .unwrap_or_default(),
+                self.display_trace()
             );
         }
     }
@@ -440,12 +905,16 @@ impl AccessEvaluation {
             Self::Denied { reason, .. } => {
                 assert!(
                     reason.contains(needle),
-                    "expected summary denial reason to contain `{needle}`, got `{reason}`"
+                    "expected summary denial reason to contain `{needle}`, got `{reason}`; \
+                     got:\n{}",
+                    self.display_trace()
                 );
             }
             Self::Granted { policy_type, .. } => {
                 panic!(
-                    "expected denial containing `{needle}`, but access was granted by `{policy_type}`"
+                    "expected denial containing `{needle}`, but access was granted by \
+                     `{policy_type}`; got:\n{}",
+                    self.display_trace()
                 );
             }
         }
@@ -576,6 +1045,45 @@ impl AccessEvaluation {
         );
     }
 
+    /// Test helper: panic unless a policy named `expected` was evaluated,
+    /// at any depth of the trace and with any outcome.
+    ///
+    /// Matches the policy type or the id set with
+    /// [`crate::PolicyExt::with_id`]. Use it to check that a sub-policy inside
+    /// a combinator or a delegated checker actually ran; the outcome
+    /// assertions above only look at the deciding policy or at leaves of one
+    /// kind.
+    #[track_caller]
+    pub fn assert_policy_evaluated(&self, expected: &str) {
+        let evaluated = self
+            .trace()
+            .root()
+            .is_some_and(|root| policy_appears(root, expected));
+        assert!(
+            evaluated,
+            "expected policy `{expected}` to appear in the trace; got:\n{}",
+            self.display_trace()
+        );
+    }
+
+    /// Test helper: panic if a policy named `expected` appears anywhere in
+    /// the trace.
+    ///
+    /// The counterpart of [`Self::assert_policy_evaluated`], for asserting
+    /// that a short-circuit skipped a policy.
+    #[track_caller]
+    pub fn assert_policy_not_evaluated(&self, expected: &str) {
+        let evaluated = self
+            .trace()
+            .root()
+            .is_some_and(|root| policy_appears(root, expected));
+        assert!(
+            !evaluated,
+            "expected policy `{expected}` not to be evaluated; got:\n{}",
+            self.display_trace()
+        );
+    }
+
     /// Converts the evaluation into a `Result`, mapping a denial into an error.
     ///
     /// `error_fn` receives the denial reason string and should return your
@@ -619,6 +1127,25 @@ impl AccessEvaluation {
         }
     }
 
+    /// Converts the evaluation into a `Result` that keeps the whole
+    /// evaluation on both arms: `Ok` on a grant, `Err` on a denial.
+    ///
+    /// Unlike [`Self::to_result`], the trace survives in the error, so a
+    /// caller can `?`-propagate a denial and still log it in full:
+    ///
+    /// ```rust,ignore
+    /// let granted = bound.check(&document).await.into_result()?;
+    /// ```
+    // Both arms carry the same type, so boxing only the error would not help.
+    #[allow(clippy::result_large_err)]
+    pub fn into_result(self) -> Result<AccessEvaluation, AccessEvaluation> {
+        if self.is_granted() {
+            Ok(self)
+        } else {
+            Err(self)
+        }
+    }
+
     /// Returns a human-readable string containing both the decision headline
     /// and the full evaluation trace tree.
     ///
@@ -658,6 +1185,208 @@ impl fmt::Display for AccessEvaluation {
     }
 }
 
+/// How many of a checker's policies one evaluation ran, from
+/// [`AccessEvaluation::stats`].
+///
+/// Comparing `evaluated` with `total` across requests shows what ordering
+/// cheap or likely-to-grant policies first saves. Policies evaluated
+/// concurrently, as by [`crate::BoundEvaluator::check_parallel`], all run, so
+/// those evaluations never short-circuit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct EvalStats {
+    /// Policies that ran for the request.
+    pub evaluated: usize,
+    /// Policies registered on the checker.
+    pub total: usize,
+    /// Whether the checker decided before running every policy.
+    pub short_circuited: bool,
+    /// Whether the evaluation came from the checker's memo, in which case
+    /// no policy ran and `evaluated` is `0`.
+    pub memoized: bool,
+}
+
+impl EvalStats {
+    pub(crate) fn new(evaluated: usize, total: usize) -> Self {
+        Self {
+            evaluated,
+            total,
+            short_circuited: evaluated < total,
+            memoized: false,
+        }
+    }
+
+    pub(crate) fn memoized(total: usize) -> Self {
+        Self {
+            evaluated: 0,
+            total,
+            short_circuited: false,
+            memoized: true,
+        }
+    }
+}
+
+/// The symbols [`EvalTrace::format_with`] marks each node with.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TraceSymbols {
+    /// `✔`, `✘`, `⛔`, and `⚠`, with `↳` before each fact and `…` for a
+    /// truncated subtree. The default.
+    #[default]
+    Unicode,
+    /// `[+]`, `[-]`, `[!]`, and `[?]`, with `->` before each fact and `...`
+    /// for a truncated subtree, for sinks that do not render UTF-8.
+    Ascii,
+}
+
+impl TraceSymbols {
+    fn granted(self) -> &'static str {
+        match self {
+            Self::Unicode => "✔",
+            Self::Ascii => "[+]",
+        }
+    }
+
+    fn denied(self) -> &'static str {
+        match self {
+            Self::Unicode => "✘",
+            Self::Ascii => "[-]",
+        }
+    }
+
+    fn forbidden(self) -> &'static str {
+        match self {
+            Self::Unicode => "⛔",
+            Self::Ascii => "[!]",
+        }
+    }
+
+    fn indeterminate(self) -> &'static str {
+        match self {
+            Self::Unicode => "⚠",
+            Self::Ascii => "[?]",
+        }
+    }
+
+    fn fact(self) -> &'static str {
+        match self {
+            Self::Unicode => "↳",
+            Self::Ascii => "->",
+        }
+    }
+
+    fn truncated(self) -> &'static str {
+        match self {
+            Self::Unicode => "…",
+            Self::Ascii => "...",
+        }
+    }
+}
+
+/// How [`EvalTrace::format_with`] renders a trace.
+///
+/// The default reproduces [`EvalTrace::format`]: Unicode symbols, two-space
+/// indents, every level, reasons included, and no colors.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    symbols: TraceSymbols,
+    indent: usize,
+    max_depth: Option<usize>,
+    reasons: bool,
+    colored: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            symbols: TraceSymbols::default(),
+            indent: 2,
+            max_depth: None,
+            reasons: true,
+            colored: false,
+        }
+    }
+}
+
+impl FormatOptions {
+    /// Creates the default options.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the symbols nodes are marked with.
+    pub fn with_symbols(mut self, symbols: TraceSymbols) -> Self {
+        self.symbols = symbols;
+        self
+    }
+
+    /// Sets how many spaces each level is indented by.
+    pub fn with_indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Shows nodes down to `max_depth` levels below the root, replacing the
+    /// children of a combinator at that depth with a single truncation
+    /// marker. The root is depth 0.
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+
+    /// Sets whether each node's reason follows its outcome.
+    pub fn with_reasons(mut self, reasons: bool) -> Self {
+        self.reasons = reasons;
+        self
+    }
+
+    /// Sets whether each node is wrapped in ANSI color codes: green for
+    /// grants, red for forbids, yellow for indeterminate results, and dim
+    /// for everything else.
+    pub fn with_colors(mut self, colored: bool) -> Self {
+        self.colored = colored;
+        self
+    }
+}
+
+/// The ANSI color of a formatted node.
+#[derive(Clone, Copy)]
+enum Style {
+    Granted,
+    Denied,
+    Forbidden,
+    Indeterminate,
+}
+
+impl Style {
+    fn ansi(self) -> &'static str {
+        match self {
+            Self::Granted => "\x1b[32m",
+            Self::Denied => "\x1b[2m",
+            Self::Forbidden => "\x1b[31m",
+            Self::Indeterminate => "\x1b[33m",
+        }
+    }
+}
+
+/// Callbacks for a depth-first traversal of a result tree, driven by
+/// [`PolicyEvalResult::accept`].
+///
+/// Implement this to render a trace in a format of your own, such as HTML or
+/// telemetry events. Every method defaults to doing nothing, so implement
+/// only the ones you need. `depth` is 0 for the node `accept` was called on
+/// and grows by one per combinator.
+pub trait TraceVisitor {
+    /// Called for each non-combined node.
+    fn visit_leaf(&mut self, _depth: usize, _leaf: &PolicyEvalResult) {}
+
+    /// Called for a [`PolicyEvalResult::Combined`] node before its children.
+    fn enter_combined(&mut self, _depth: usize, _node: &PolicyEvalResult) {}
+
+    /// Called for a [`PolicyEvalResult::Combined`] node after its children.
+    fn leave_combined(&mut self, _depth: usize, _node: &PolicyEvalResult) {}
+}
+
 /// A tree of [`PolicyEvalResult`] nodes capturing every policy decision made
 /// during an access evaluation.
 ///
@@ -690,17 +1419,25 @@ impl fmt::Display for AccessEvaluation {
 #[cfg_attr(feature = "serde", derive(serde::Serialize))]
 pub struct EvalTrace {
     root: Option<PolicyEvalResult>,
+    #[cfg_attr(feature = "serde", serde(skip_serializing_if = "Option::is_none"))]
+    stats: Option<EvalStats>,
 }
 
 impl EvalTrace {
     /// Creates an empty trace with no evaluation results.
     pub fn new() -> Self {
-        Self { root: None }
+        Self {
+            root: None,
+            stats: None,
+        }
     }
 
     /// Creates a trace with the given [`PolicyEvalResult`] as the root node.
     pub fn with_root(result: PolicyEvalResult) -> Self {
-        Self { root: Some(result) }
+        Self {
+            root: Some(result),
+            stats: None,
+        }
     }
 
     /// Sets (or replaces) the root node of the evaluation tree.
@@ -713,16 +1450,80 @@ impl EvalTrace {
         self.root.as_ref()
     }
 
+    /// Returns how many policies the checker ran, if a [`PermissionChecker`]
+    /// produced this trace.
+    ///
+    /// [`PermissionChecker`]: crate::PermissionChecker
+    pub fn stats(&self) -> Option<EvalStats> {
+        self.stats
+    }
+
     /// Returns a formatted, indented representation of the evaluation tree.
     ///
     /// Each node shows a `✔` or `✘` prefix, the policy name, and the reason.
-    /// Combined nodes indent their children for readability.
+    /// Combined nodes indent their children for readability. This is
+    /// [`Self::format_with`] with the default [`FormatOptions`].
     pub fn format(&self) -> String {
+        self.format_with(&FormatOptions::default())
+    }
+
+    /// Returns the evaluation tree formatted as configured by `options`, for
+    /// log sinks that do not render UTF-8 or that need deep trees cut short.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// let trace = EvalTrace::with_root(PolicyEvalResult::granted(
+    ///     "AdminPolicy",
+    ///     Some("User is admin".into()),
+    /// ));
+    /// let options = FormatOptions::new()
+    ///     .with_symbols(TraceSymbols::Ascii)
+    ///     .with_reasons(false);
+    /// assert_eq!(trace.format_with(&options), "[+] AdminPolicy GRANTED");
+    /// ```
+    pub fn format_with(&self, options: &FormatOptions) -> String {
         match &self.root {
-            Some(root) => root.format(0),
+            Some(root) => root.format_with(options),
             None => "No evaluation trace available".to_string(),
         }
     }
+
+    /// Returns a copy of the trace with the branches that did not affect the
+    /// decision removed, for logs where [`Self::format`] is mostly
+    /// "does not apply" noise.
+    ///
+    /// A subtree is kept when it granted, or when it contains a forbid or an
+    /// indeterminate result; everything else, such as
+    /// [`PolicyEvalResult::NotApplicable`] leaves and combinators that
+    /// declined without either, is dropped. The root is always kept, so a
+    /// denial no policy engaged with prunes to the bare root. The children
+    /// of a [`CombineOp::Not`] node are kept whole, since a not-applicable
+    /// child is what makes it grant. The trace this is called on is left
+    /// unchanged, so keep it when the full tree is needed.
+    pub fn pruned(&self) -> Self {
+        let root = self.root.as_ref().map(|root| match root {
+            PolicyEvalResult::Combined { .. } => root.pruned_children(),
+            leaf => leaf.clone(),
+        });
+        Self {
+            root,
+            stats: self.stats,
+        }
+    }
+
+    /// Returns the trace as JSON for audit logs.
+    ///
+    /// The root sits under `root` (`null` for an empty trace). Every node is
+    /// an object tagged with `type` (`granted`, `not_applicable`,
+    /// `forbidden`, `indeterminate`, or `combined`) next to its
+    /// `policy_type`; leaves carry their `reason`, and combined nodes their
+    /// `operation`, boolean `outcome`, and `children` in evaluation order.
+    /// The `operation` is always a string, the [`CombineOp`]'s display form
+    /// such as `"OR"` or `"THRESHOLD(2/3)"`.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("evaluation traces should always serialize to JSON")
+    }
 }
 
 impl PolicyEvalResult {
@@ -736,8 +1537,12 @@ impl PolicyEvalResult {
     pub fn granted(policy_type: impl Into<Cow<'static, str>>, reason: Option<String>) -> Self {
         Self::Granted {
             policy_type: policy_type.into(),
+            policy_id: None,
             reason,
             provenance: Vec::new(),
+            risk: None,
+            valid_until: None,
+            obligations: Vec::new(),
         }
     }
 
@@ -751,8 +1556,10 @@ impl PolicyEvalResult {
     ) -> Self {
         Self::NotApplicable {
             policy_type: policy_type.into(),
+            policy_id: None,
             reason: reason.into(),
             provenance: Vec::new(),
+            risk: None,
         }
     }
 
@@ -768,8 +1575,28 @@ impl PolicyEvalResult {
     pub fn forbidden(policy_type: impl Into<Cow<'static, str>>, reason: impl Into<String>) -> Self {
         Self::Forbidden {
             policy_type: policy_type.into(),
+            policy_id: None,
             reason: reason.into(),
             provenance: Vec::new(),
+            risk: None,
+        }
+    }
+
+    /// Builds an indeterminate leaf result with no fact provenance.
+    ///
+    /// Use this when the policy could not decide, for example because a
+    /// lookup it depends on failed. Prefer [`crate::EvalCtx::indeterminate`]
+    /// inside policy bodies.
+    pub fn indeterminate(
+        policy_type: impl Into<Cow<'static, str>>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Self::Indeterminate {
+            policy_type: policy_type.into(),
+            policy_id: None,
+            reason: reason.into(),
+            provenance: Vec::new(),
+            risk: None,
         }
     }
 
@@ -781,8 +1608,12 @@ impl PolicyEvalResult {
     ) -> Self {
         Self::Granted {
             policy_type: policy_type.into(),
+            policy_id: None,
             reason,
             provenance,
+            risk: None,
+            valid_until: None,
+            obligations: Vec::new(),
         }
     }
 
@@ -794,8 +1625,10 @@ impl PolicyEvalResult {
     ) -> Self {
         Self::NotApplicable {
             policy_type: policy_type.into(),
+            policy_id: None,
             reason: reason.into(),
             provenance,
+            risk: None,
         }
     }
 
@@ -807,8 +1640,26 @@ impl PolicyEvalResult {
     ) -> Self {
         Self::Forbidden {
             policy_type: policy_type.into(),
+            policy_id: None,
+            reason: reason.into(),
+            provenance,
+            risk: None,
+        }
+    }
+
+    /// Builds an indeterminate leaf result carrying the facts consulted
+    /// before the policy gave up.
+    pub fn indeterminate_with_facts(
+        policy_type: impl Into<Cow<'static, str>>,
+        reason: impl Into<String>,
+        provenance: Vec<FactProvenance>,
+    ) -> Self {
+        Self::Indeterminate {
+            policy_type: policy_type.into(),
+            policy_id: None,
             reason: reason.into(),
             provenance,
+            risk: None,
         }
     }
 
@@ -816,11 +1667,28 @@ impl PolicyEvalResult {
     pub fn is_granted(&self) -> bool {
         match self {
             Self::Granted { .. } => true,
-            Self::NotApplicable { .. } | Self::Forbidden { .. } => false,
+            Self::NotApplicable { .. } | Self::Forbidden { .. } | Self::Indeterminate { .. } => {
+                false
+            }
             Self::Combined { outcome, .. } => *outcome,
         }
     }
 
+    /// Returns whether this result failed to grant because some policy could
+    /// not decide.
+    ///
+    /// True for an [`PolicyEvalResult::Indeterminate`] leaf, and for a
+    /// non-granting combinator with an indeterminate descendant.
+    pub fn is_indeterminate(&self) -> bool {
+        match self {
+            Self::Indeterminate { .. } => true,
+            Self::Combined {
+                children, outcome, ..
+            } => !outcome && children.iter().any(Self::is_indeterminate),
+            Self::Granted { .. } | Self::NotApplicable { .. } | Self::Forbidden { .. } => false,
+        }
+    }
+
     /// Returns whether this result contains an active forbid
     /// ([`PolicyEvalResult::Forbidden`]).
     pub fn is_forbidden(&self) -> bool {
@@ -835,13 +1703,65 @@ impl PolicyEvalResult {
                 ..
             } => Some((policy_type.as_ref(), Some(reason.as_str()))),
             Self::Combined { children, .. } => children.iter().find_map(Self::forbidden_leaf),
-            Self::Granted { .. } | Self::NotApplicable { .. } => None,
+            Self::Granted { .. } | Self::NotApplicable { .. } | Self::Indeterminate { .. } => None,
+        }
+    }
+
+    /// Returns the child that decided an `AND` or `OR` node early.
+    ///
+    /// For a denying `AND` this is the first forbidding child, or failing
+    /// that the first child that did not grant. For an `OR` it is the first
+    /// forbidding child of a denial, or the first granting child of a grant.
+    /// `None` for leaves, for other operations, and for an `AND` that granted
+    /// or an `OR` that denied without a forbid, since every child was
+    /// needed. Children appear in evaluation order, which puts forbid-capable
+    /// policies first, so the culprit is not necessarily the one declared
+    /// first.
+    pub fn short_circuit_culprit(&self) -> Option<&PolicyEvalResult> {
+        let Self::Combined {
+            operation,
+            children,
+            outcome,
+            ..
+        } = self
+        else {
+            return None;
+        };
+        let forbidding = || children.iter().find(|child| child.is_forbidden());
+        match (operation, outcome) {
+            (CombineOp::And, false) => {
+                forbidding().or_else(|| children.iter().find(|child| !child.is_granted()))
+            }
+            (CombineOp::Or, false) => forbidding(),
+            (CombineOp::Or, true) => children.iter().find(|child| child.is_granted()),
+            _ => None,
         }
     }
 
-    /// Returns the reason string if available
+    /// Returns the reason string if available.
+    ///
+    /// Combinators have no reason of their own, with one exception: a
+    /// granting [`CombineOp::Not`] node explains itself from the inner
+    /// result, as `"Granted because 'X' did not grant: <inner reason>"`, so
+    /// a grant from [`crate::NotPolicy`] carries a reason up to
+    /// [`AccessEvaluation::Granted`]. [`Self::reason_str`] cannot build that
+    /// string and returns `None` for it.
     pub fn reason(&self) -> Option<String> {
-        self.reason_str().map(str::to_owned)
+        match self {
+            Self::Combined {
+                operation: CombineOp::Not,
+                children,
+                outcome: true,
+                ..
+            } => children.first().map(|inner| {
+                let name = inner.policy_id().unwrap_or(inner.policy_type_str());
+                match inner.reason() {
+                    Some(reason) => format!("Granted because '{name}' did not grant: {reason}"),
+                    None => format!("Granted because '{name}' did not grant"),
+                }
+            }),
+            _ => self.reason_str().map(str::to_owned),
+        }
     }
 
     /// Returns the reason without cloning, if available.
@@ -851,7 +1771,9 @@ impl PolicyEvalResult {
     pub fn reason_str(&self) -> Option<&str> {
         match self {
             Self::Granted { reason, .. } => reason.as_deref(),
-            Self::NotApplicable { reason, .. } | Self::Forbidden { reason, .. } => Some(reason),
+            Self::NotApplicable { reason, .. }
+            | Self::Forbidden { reason, .. }
+            | Self::Indeterminate { reason, .. } => Some(reason),
             Self::Combined { .. } => None,
         }
     }
@@ -863,75 +1785,349 @@ impl PolicyEvalResult {
         match self {
             Self::Granted { provenance, .. }
             | Self::NotApplicable { provenance, .. }
-            | Self::Forbidden { provenance, .. } => provenance,
+            | Self::Forbidden { provenance, .. }
+            | Self::Indeterminate { provenance, .. } => provenance,
             Self::Combined { .. } => &[],
         }
     }
 
-    /// Formats the evaluation tree with indentation for readability
+    /// Attaches an advisory risk contribution to a leaf result.
+    ///
+    /// The score never changes the decision; it is summed across evaluated
+    /// policies by [`AccessEvaluation::aggregate_risk`] for callers such as
+    /// step-up authentication. Has no effect on [`Self::Combined`] nodes.
+    pub fn with_risk(mut self, score: f32) -> Self {
+        match &mut self {
+            Self::Granted { risk, .. }
+            | Self::NotApplicable { risk, .. }
+            | Self::Forbidden { risk, .. }
+            | Self::Indeterminate { risk, .. } => *risk = Some(score),
+            Self::Combined { .. } => {}
+        }
+        self
+    }
+
+    /// Marks a granted leaf as valid only until `valid_until`.
+    ///
+    /// Use this from time-limited policies (a grant inside a time window)
+    /// so callers minting tokens from the decision can cap their lifetime.
+    /// Has no effect on results that are not [`Self::Granted`].
+    pub fn with_valid_until(mut self, valid_until: SystemTime) -> Self {
+        if let Self::Granted {
+            valid_until: expiry,
+            ..
+        } = &mut self
+        {
+            *expiry = Some(valid_until);
+        }
+        self
+    }
+
+    /// Attaches an obligation the caller must fulfil when acting on a grant.
+    ///
+    /// Can be called repeatedly to attach several. Has no effect on results
+    /// that are not [`Self::Granted`].
+    pub fn with_obligation(mut self, obligation: Obligation) -> Self {
+        if let Self::Granted { obligations, .. } = &mut self {
+            obligations.push(obligation);
+        }
+        self
+    }
+
+    /// Whether [`EvalTrace::pruned`] keeps this subtree.
+    fn affects_decision(&self) -> bool {
+        self.is_granted() || self.is_forbidden() || self.is_indeterminate()
+    }
+
+    /// Copies this node, keeping only the children that affect the decision.
+    fn pruned_children(&self) -> Self {
+        match self {
+            Self::Combined {
+                policy_type,
+                policy_id,
+                operation,
+                children,
+                outcome,
+            } if *operation != CombineOp::Not => Self::Combined {
+                policy_type: policy_type.clone(),
+                policy_id: policy_id.clone(),
+                operation: *operation,
+                children: children
+                    .iter()
+                    .filter(|child| child.affects_decision())
+                    .map(Self::pruned_children)
+                    .collect(),
+                outcome: *outcome,
+            },
+            _ => self.clone(),
+        }
+    }
+
+    /// Collects the obligations of the grants this result relies on, skipping
+    /// the same subtrees as [`Self::valid_until`].
+    fn collect_obligations<'a>(&'a self, into: &mut Vec<&'a Obligation>) {
+        match self {
+            Self::Granted { obligations, .. } => into.extend(obligations),
+            Self::Combined {
+                children,
+                outcome: true,
+                ..
+            } => children
+                .iter()
+                .filter(|child| child.is_granted())
+                .for_each(|child| child.collect_obligations(into)),
+            _ => {}
+        }
+    }
+
+    /// Returns the earliest expiry among the grants this result relies on,
+    /// or `None` when none of them expires.
+    ///
+    /// Only grants that contributed are considered: the subtrees of
+    /// non-granting combinators are skipped, and a [`CombineOp::Not`] grant
+    /// relies on no inner grant.
+    pub fn valid_until(&self) -> Option<SystemTime> {
+        match self {
+            Self::Granted { valid_until, .. } => *valid_until,
+            Self::Combined {
+                children,
+                outcome: true,
+                ..
+            } => children
+                .iter()
+                .filter(|child| child.is_granted())
+                .filter_map(Self::valid_until)
+                .min(),
+            _ => None,
+        }
+    }
+
+    /// Returns this leaf's advisory risk contribution, if any.
+    ///
+    /// Always `None` for combinators; use [`Self::total_risk`] for a subtree.
+    pub fn risk(&self) -> Option<f32> {
+        match self {
+            Self::Granted { risk, .. }
+            | Self::NotApplicable { risk, .. }
+            | Self::Forbidden { risk, .. }
+            | Self::Indeterminate { risk, .. } => *risk,
+            Self::Combined { .. } => None,
+        }
+    }
+
+    /// Sums the risk contributions of every leaf in this subtree, or `None`
+    /// when no leaf contributed.
+    pub fn total_risk(&self) -> Option<f32> {
+        match self {
+            Self::Combined { children, .. } => children
+                .iter()
+                .filter_map(Self::total_risk)
+                .fold(None, |total, risk| Some(total.unwrap_or(0.0) + risk)),
+            leaf => leaf.risk(),
+        }
+    }
+
+    /// Returns the caller-supplied identifier of the policy instance behind
+    /// this node, if one was set with [`crate::PolicyExt::with_id`] or, for a
+    /// [`crate::PolicyBuilder`] policy, from the builder's name.
+    pub fn policy_id(&self) -> Option<&str> {
+        match self {
+            Self::Granted { policy_id, .. }
+            | Self::NotApplicable { policy_id, .. }
+            | Self::Forbidden { policy_id, .. }
+            | Self::Indeterminate { policy_id, .. }
+            | Self::Combined { policy_id, .. } => policy_id.as_deref(),
+        }
+    }
+
+    /// Sets the identifier of the policy instance behind this node.
+    ///
+    /// Only the node itself is tagged, not its children. Policies rarely
+    /// call this directly; wrap them with [`crate::PolicyExt::with_id`].
+    pub fn with_policy_id(mut self, id: impl Into<Cow<'static, str>>) -> Self {
+        match &mut self {
+            Self::Granted { policy_id, .. }
+            | Self::NotApplicable { policy_id, .. }
+            | Self::Forbidden { policy_id, .. }
+            | Self::Indeterminate { policy_id, .. }
+            | Self::Combined { policy_id, .. } => *policy_id = Some(id.into()),
+        }
+        self
+    }
+
+    fn policy_type_str(&self) -> &str {
+        match self {
+            Self::Granted { policy_type, .. }
+            | Self::NotApplicable { policy_type, .. }
+            | Self::Forbidden { policy_type, .. }
+            | Self::Indeterminate { policy_type, .. }
+            | Self::Combined { policy_type, .. } => policy_type,
+        }
+    }
+
+    /// Calls `visitor` with every node of this tree and its depth, parents
+    /// before their children and children in evaluation order.
+    ///
+    /// ```rust
+    /// # use gatehouse::*;
+    /// let tree = PolicyEvalResult::Combined {
+    ///     policy_type: "AndPolicy".into(),
+    ///     policy_id: None,
+    ///     operation: CombineOp::And,
+    ///     children: vec![PolicyEvalResult::granted("Admin", None)],
+    ///     outcome: true,
+    /// };
+    /// let mut lines = Vec::new();
+    /// tree.walk(&mut |depth, node| {
+    ///     lines.push(format!("{}{}", "-".repeat(depth), node.is_granted()));
+    /// });
+    /// assert_eq!(lines, ["true", "-true"]);
+    /// ```
+    pub fn walk(&self, visitor: &mut impl FnMut(usize, &PolicyEvalResult)) {
+        self.walk_at(0, visitor);
+    }
+
+    fn walk_at(&self, depth: usize, visitor: &mut impl FnMut(usize, &PolicyEvalResult)) {
+        visitor(depth, self);
+        if let Self::Combined { children, .. } = self {
+            for child in children {
+                child.walk_at(depth + 1, visitor);
+            }
+        }
+    }
+
+    /// Drives `visitor` through this tree depth-first. See [`TraceVisitor`].
+    pub fn accept(&self, visitor: &mut impl TraceVisitor) {
+        self.accept_at(0, visitor);
+    }
+
+    fn accept_at(&self, depth: usize, visitor: &mut impl TraceVisitor) {
+        match self {
+            Self::Combined { children, .. } => {
+                visitor.enter_combined(depth, self);
+                for child in children {
+                    child.accept_at(depth + 1, visitor);
+                }
+                visitor.leave_combined(depth, self);
+            }
+            leaf => visitor.visit_leaf(depth, leaf),
+        }
+    }
+
+    /// Formats the evaluation tree with indentation for readability.
+    ///
+    /// Each node is labelled with its [`Self::policy_id`] when one is set,
+    /// and with its policy type otherwise.
     pub fn format(&self, indent: usize) -> String {
+        let mut result = String::new();
+        self.render(&mut result, indent, 0, &FormatOptions::default());
+        result
+    }
+
+    /// Formats the evaluation tree as configured by `options`.
+    pub fn format_with(&self, options: &FormatOptions) -> String {
+        let mut result = String::new();
+        self.render(&mut result, 0, 0, options);
+        result
+    }
+
+    /// Appends this node at `indent` spaces, then its children and fact
+    /// provenance, each on its own line.
+    fn render(&self, out: &mut String, indent: usize, depth: usize, options: &FormatOptions) {
+        let symbols = options.symbols;
         let indent_str = " ".repeat(indent);
+        let name = self.policy_id().unwrap_or(self.policy_type_str());
+        let with_reason = |reason: &str| {
+            if options.reasons {
+                format!(": {reason}")
+            } else {
+                String::new()
+            }
+        };
 
-        match self {
+        let (style, headline, provenance) = match self {
             Self::Granted {
-                policy_type,
-                reason,
-                provenance,
+                reason, provenance, ..
             } => {
-                let reason_text = reason
-                    .as_ref()
-                    .map_or("".to_string(), |r| format!(": {}", r));
-                let headline = format!("{}✔ {} GRANTED{}", indent_str, policy_type, reason_text);
-                Self::append_provenance(headline, &indent_str, provenance)
+                let reason_text = reason.as_deref().map(with_reason).unwrap_or_default();
+                let headline = format!("{} {} GRANTED{}", symbols.granted(), name, reason_text);
+                (Style::Granted, headline, provenance.as_slice())
             }
             Self::NotApplicable {
-                policy_type,
-                reason,
-                provenance,
+                reason, provenance, ..
             } => {
-                let headline =
-                    format!("{}✘ {} NOT_APPLICABLE: {}", indent_str, policy_type, reason);
-                Self::append_provenance(headline, &indent_str, provenance)
+                let headline = format!(
+                    "{} {} NOT_APPLICABLE{}",
+                    symbols.denied(),
+                    name,
+                    with_reason(reason)
+                );
+                (Style::Denied, headline, provenance.as_slice())
             }
             Self::Forbidden {
-                policy_type,
-                reason,
-                provenance,
+                reason, provenance, ..
             } => {
-                let headline = format!("{}⛔ {} FORBIDDEN: {}", indent_str, policy_type, reason);
-                Self::append_provenance(headline, &indent_str, provenance)
+                let headline = format!(
+                    "{} {} FORBIDDEN{}",
+                    symbols.forbidden(),
+                    name,
+                    with_reason(reason)
+                );
+                (Style::Forbidden, headline, provenance.as_slice())
             }
-            Self::Combined {
-                policy_type,
-                operation,
-                children,
-                outcome,
+            Self::Indeterminate {
+                reason, provenance, ..
             } => {
-                let outcome_char = if *outcome { "✔" } else { "✘" };
-                let mut result = format!(
-                    "{}{} {} ({})",
-                    indent_str, outcome_char, policy_type, operation
+                let headline = format!(
+                    "{} {} INDETERMINATE{}",
+                    symbols.indeterminate(),
+                    name,
+                    with_reason(reason)
                 );
-
-                for child in children {
-                    result.push_str(&format!("\n{}", child.format(indent + 2)));
-                }
-                result
+                (Style::Indeterminate, headline, provenance.as_slice())
             }
+            Self::Combined {
+                operation, outcome, ..
+            } => {
+                let (style, symbol) = if *outcome {
+                    (Style::Granted, symbols.granted())
+                } else {
+                    (Style::Denied, symbols.denied())
+                };
+                (style, format!("{symbol} {name} ({operation})"), &[][..])
+            }
+        };
+        out.push_str(&indent_str);
+        if options.colored {
+            out.push_str(&format!("{}{headline}\x1b[0m", style.ansi()));
+        } else {
+            out.push_str(&headline);
         }
-    }
-
-    /// Appends one indented `↳ fact …` line per consulted fact under a leaf node.
-    fn append_provenance(
-        headline: String,
-        indent_str: &str,
-        provenance: &[FactProvenance],
-    ) -> String {
-        let mut result = headline;
         for fact in provenance {
-            result.push_str(&format!("\n{indent_str}  ↳ {fact}"));
+            out.push_str(&format!("\n{indent_str}  {} {fact}", symbols.fact()));
+        }
+
+        if let Self::Combined { children, .. } = self {
+            let child_indent = indent + options.indent;
+            if children.is_empty() {
+                return;
+            }
+            if options
+                .max_depth
+                .is_some_and(|max_depth| depth >= max_depth)
+            {
+                out.push_str(&format!(
+                    "\n{}{}",
+                    " ".repeat(child_indent),
+                    symbols.truncated()
+                ));
+                return;
+            }
+            for child in children {
+                out.push('\n');
+                child.render(out, child_indent, depth + 1, options);
+            }
         }
-        result
     }
 }
 
@@ -0,0 +1,144 @@
+//! Score-based decisions for risk-based authorization.
+//!
+//! A [`ScoredPolicy`] contributes a number instead of a decision, and a
+//! [`ScoreChecker`] grants when the contributions sum to at least its
+//! threshold. The trait is separate from [`Policy`](crate::Policy), so
+//! boolean policies and checkers are unaffected.
+
+use crate::{
+    AccessEvaluation, CombineOp, EvalCtx, EvalStats, EvalTrace, EvaluationSession, PolicyDomain,
+    PolicyEvalResult,
+};
+use async_trait::async_trait;
+use std::borrow::Cow;
+use std::sync::Arc;
+
+const SCORE_CHECKER_POLICY_TYPE: &str = "ScoreChecker";
+
+/// A policy that contributes a score to a [`ScoreChecker`] rather than
+/// granting or denying.
+///
+/// Positive contributions count towards the threshold and negative ones
+/// against it. Weight a signal by scaling its contribution.
+#[async_trait]
+pub trait ScoredPolicy<D: PolicyDomain>: Send + Sync {
+    /// Returns this policy's contribution for the request in `ctx`.
+    async fn score(&self, ctx: &EvalCtx<'_, D>) -> f32;
+
+    /// Policy name for debugging and for the trace.
+    fn policy_type(&self) -> Cow<'static, str>;
+}
+
+/// Sums the contributions of its [`ScoredPolicy`]s and grants when the total
+/// reaches a threshold.
+///
+/// Every policy is evaluated, in the order added. Each contribution appears in
+/// the trace as a leaf under a [`CombineOp::Score`] root, granted when it is
+/// positive and not applicable otherwise, and the evaluation's reason states
+/// the total and the threshold. A `NaN` contribution makes the total `NaN`,
+/// which never reaches the threshold. Like [`crate::PermissionChecker`], a
+/// checker with no policies denies.
+pub struct ScoreChecker<D: PolicyDomain> {
+    policies: Vec<Arc<dyn ScoredPolicy<D>>>,
+    threshold: f32,
+}
+
+impl<D: PolicyDomain> Clone for ScoreChecker<D> {
+    fn clone(&self) -> Self {
+        Self {
+            policies: self.policies.clone(),
+            threshold: self.threshold,
+        }
+    }
+}
+
+impl<D: PolicyDomain> ScoreChecker<D> {
+    /// Creates a checker with no policies that grants at `threshold`.
+    pub fn new(threshold: f32) -> Self {
+        Self {
+            policies: Vec::new(),
+            threshold,
+        }
+    }
+
+    /// Adds a scored policy.
+    pub fn add_policy<P: ScoredPolicy<D> + 'static>(&mut self, policy: P) {
+        self.policies.push(Arc::new(policy));
+    }
+
+    /// Returns the total score at which the checker grants.
+    pub fn threshold(&self) -> f32 {
+        self.threshold
+    }
+
+    /// Scores one request and decides it against the threshold.
+    pub async fn evaluate(
+        &self,
+        session: &EvaluationSession,
+        subject: &D::Subject,
+        action: &D::Action,
+        resource: &D::Resource,
+        context: &D::Context,
+    ) -> AccessEvaluation {
+        if self.policies.is_empty() {
+            let result = PolicyEvalResult::not_applicable(
+                SCORE_CHECKER_POLICY_TYPE,
+                "No policies configured",
+            );
+            return AccessEvaluation::Denied {
+                trace: EvalTrace::with_root(result),
+                reason: "No policies configured".to_string(),
+            }
+            .with_stats(EvalStats::new(0, 0));
+        }
+
+        let mut total = 0.0;
+        let mut children = Vec::with_capacity(self.policies.len());
+        for policy in &self.policies {
+            let policy_type = policy.policy_type();
+            let ctx = EvalCtx {
+                session,
+                subject,
+                action,
+                resource,
+                context,
+                policy_type: policy_type.clone(),
+            };
+            let score = policy.score(&ctx).await;
+            total += score;
+            let reason = format!("contributed {score}");
+            children.push(if score > 0.0 {
+                PolicyEvalResult::granted(policy_type, Some(reason))
+            } else {
+                PolicyEvalResult::not_applicable(policy_type, reason)
+            });
+        }
+
+        let granted = total >= self.threshold;
+        let trace = EvalTrace::with_root(PolicyEvalResult::Combined {
+            policy_type: Cow::Borrowed(SCORE_CHECKER_POLICY_TYPE),
+            policy_id: None,
+            operation: CombineOp::Score,
+            children,
+            outcome: granted,
+        });
+        let stats = EvalStats::new(self.policies.len(), self.policies.len());
+        if granted {
+            AccessEvaluation::Granted {
+                policy_type: Cow::Borrowed(SCORE_CHECKER_POLICY_TYPE),
+                reason: Some(format!(
+                    "score {total} reached threshold {}",
+                    self.threshold
+                )),
+                trace,
+            }
+            .with_stats(stats)
+        } else {
+            AccessEvaluation::Denied {
+                trace,
+                reason: format!("score {total} is below threshold {}", self.threshold),
+            }
+            .with_stats(stats)
+        }
+    }
+}
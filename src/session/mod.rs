@@ -7,6 +7,7 @@ use futures_channel::oneshot;
 use std::any::{Any, TypeId};
 use std::collections::hash_map::DefaultHasher;
 use std::collections::HashMap;
+use std::future::Future;
 use std::hash::Hasher;
 use std::num::NonZeroUsize;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -531,6 +532,57 @@ impl EvaluationSession {
         state.results_from_cache(keys)
     }
 
+    /// Loads one fact with an ad-hoc loader through the session cache.
+    ///
+    /// Use this for facts that are derived inline rather than served by a
+    /// registered [`FactSource`]. `load` runs only when `key` is neither
+    /// cached nor already being loaded in this session; concurrent callers for
+    /// the same key wait for the first one's result, and the result (including
+    /// an error) is cached for the rest of the session. A source registered for
+    /// `K` is not consulted, so avoid mixing both styles for one key type.
+    ///
+    /// On [`Self::shared_empty`] nothing is cached: `load` runs on every call,
+    /// so one request's facts never leak into another's.
+    pub async fn get_or_load<K, F, Fut>(&self, key: K, load: F) -> FactLoadResult<K::Value>
+    where
+        K: FactKey,
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = FactLoadResult<K::Value>>,
+    {
+        if self.inner.shared_empty {
+            return load().await;
+        }
+
+        let keys = [key];
+        let state = self.state::<K>();
+        let load_plan = state.plan_loads(&keys);
+        if let Some(mut results) = load_plan.cached_results {
+            return results.remove(0);
+        }
+
+        if !load_plan.keys.is_empty() {
+            let mut in_flight_guard =
+                InFlightGuard::new(Arc::clone(&state), load_plan.keys.clone());
+            let load_id = self.inner.next_load_id.fetch_add(1, Ordering::Relaxed);
+            let load_span = tracing::debug_span!(
+                "gatehouse.fact_load",
+                fact.name = K::NAME,
+                fact.load_id = load_id,
+                fact.key_count = 1,
+                fact.unique_key_count = 1,
+            );
+            let result = load().instrument(load_span).await;
+            state.finish_keys(&load_plan.keys, vec![result]);
+            in_flight_guard.mark_finished(&load_plan.keys);
+        }
+
+        for waiter in load_plan.waiters {
+            let _ = waiter.await;
+        }
+
+        state.results_from_cache(&keys).remove(0)
+    }
+
     fn state<K>(&self) -> Arc<FactState<K>>
     where
         K: FactKey,
@@ -0,0 +1,59 @@
+//! Ergonomics for checkers whose decisions depend only on the subject and
+//! request context.
+//!
+//! Organization-scoped permission checks ("may this staff user administer the
+//! organization in this request?") have no meaningful action or resource.
+//! [`SubjectOnly`] is a ready-made [`PolicyDomain`] with unit action and
+//! resource, and [`SubjectOnlyChecker`] adds methods that omit them.
+
+use crate::{AccessEvaluation, EvaluationSession, PermissionChecker, PolicyBuilder, PolicyDomain};
+use std::marker::PhantomData;
+
+/// A [`PolicyDomain`] with subject `S`, context `C`, and `()` action and
+/// resource.
+pub struct SubjectOnly<S, C>(PhantomData<fn() -> (S, C)>);
+
+impl<S, C> PolicyDomain for SubjectOnly<S, C>
+where
+    S: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    type Subject = S;
+    type Action = ();
+    type Resource = ();
+    type Context = C;
+}
+
+/// A [`PermissionChecker`] over the [`SubjectOnly`] domain.
+pub type SubjectOnlyChecker<S, C> = PermissionChecker<SubjectOnly<S, C>>;
+
+impl<S, C> PermissionChecker<SubjectOnly<S, C>>
+where
+    S: Send + Sync + 'static,
+    C: Send + Sync + 'static,
+{
+    /// Adds a granting policy built from a subject-and-context predicate.
+    pub fn add_subject_policy<F>(&mut self, name: impl Into<String>, predicate: F)
+    where
+        F: Fn(&S, &C) -> bool + Send + Sync + 'static,
+    {
+        self.add_policy(
+            PolicyBuilder::<SubjectOnly<S, C>>::new(name)
+                .when(move |subject: &S, _: &(), _: &(), context: &C| predicate(subject, context))
+                .build(),
+        );
+    }
+
+    /// Evaluates `subject` in `context`.
+    ///
+    /// Like every checker call this takes an explicit session; pass
+    /// [`EvaluationSession::empty`] when no policy loads facts.
+    pub async fn check(
+        &self,
+        session: &EvaluationSession,
+        subject: &S,
+        context: &C,
+    ) -> AccessEvaluation {
+        self.bind(session, subject, &(), context).check(&()).await
+    }
+}
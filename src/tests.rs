@@ -6,7 +6,7 @@ use std::sync::Arc;
 
 mod core_tests {
     use super::*;
-    use std::collections::{BTreeMap, HashSet};
+    use std::collections::{BTreeMap, HashMap, HashSet};
     use std::future::Future;
     use std::pin::Pin;
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -1546,6 +1546,7 @@ mod core_tests {
         let result = policy.evaluate(&ctx).await;
 
         assert!(!result.is_granted());
+        assert!(result.is_indeterminate());
         let provenance = result.provenance();
         assert_eq!(provenance.len(), 1);
         assert_eq!(provenance[0].outcome, FactOutcome::Error);
@@ -1830,6 +1831,247 @@ mod core_tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_rebac_policy_grants_on_absent_relationship_through_not_source() {
+        let blocked_user = uuid::Uuid::new_v4();
+        let resource_id = uuid::Uuid::new_v4();
+        let session = FactRegistry::builder()
+            .with::<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>, _>(NotSource::new(
+                TestRelationshipSource {
+                    grants: HashSet::from([RelationshipQuery {
+                        subject_id: blocked_user,
+                        resource_id,
+                        relation: "blocked".to_string(),
+                    }]),
+                    batch_sizes: Arc::new(Mutex::new(Vec::new())),
+                    max_batch_size: None,
+                },
+            ))
+            .build()
+            .session();
+        let policy = relationship_policy("blocked".to_string());
+        let resource = TestResource { id: resource_id };
+
+        let other_user = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let ctx = EvalCtx {
+            session: &session,
+            subject: &other_user,
+            action: &TestAction,
+            resource: &resource,
+            context: &TestContext,
+            policy_type: std::borrow::Cow::Borrowed("TestPolicy"),
+        };
+        assert!(policy.evaluate(&ctx).await.is_granted());
+
+        let blocked = TestSubject { id: blocked_user };
+        let ctx = EvalCtx {
+            subject: &blocked,
+            ..ctx
+        };
+        let result = policy.evaluate(&ctx).await;
+        assert!(!result.is_granted());
+        assert!(!result.is_indeterminate());
+
+        // Missing facts are not negated into grants.
+        let session = FactRegistry::builder()
+            .with::<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>, _>(NotSource::new(
+                MissingRelationshipSource,
+            ))
+            .build()
+            .session();
+        let ctx = EvalCtx {
+            session: &session,
+            ..ctx
+        };
+        assert!(!policy.evaluate(&ctx).await.is_granted());
+    }
+
+    #[tokio::test]
+    async fn test_relation_router_sends_each_relation_to_its_source() {
+        let subject_id = uuid::Uuid::new_v4();
+        let resource_id = uuid::Uuid::new_v4();
+        let owner_batches = Arc::new(Mutex::new(Vec::new()));
+        let viewer_batches = Arc::new(Mutex::new(Vec::new()));
+        let router = RelationRouter::new()
+            .with(
+                "owner".to_string(),
+                TestRelationshipSource {
+                    grants: HashSet::from([RelationshipQuery {
+                        subject_id,
+                        resource_id,
+                        relation: "owner".to_string(),
+                    }]),
+                    batch_sizes: owner_batches.clone(),
+                    max_batch_size: NonZeroUsize::new(8),
+                },
+            )
+            .with(
+                "viewer".to_string(),
+                TestRelationshipSource {
+                    grants: HashSet::new(),
+                    batch_sizes: viewer_batches.clone(),
+                    max_batch_size: NonZeroUsize::new(4),
+                },
+            );
+        assert_eq!(router.max_batch_size(), NonZeroUsize::new(4));
+        let session = FactRegistry::builder()
+            .with::<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>, _>(router)
+            .build()
+            .session();
+        let subject = TestSubject { id: subject_id };
+        let resource = TestResource { id: resource_id };
+        let ctx = EvalCtx {
+            session: &session,
+            subject: &subject,
+            action: &TestAction,
+            resource: &resource,
+            context: &TestContext,
+            policy_type: std::borrow::Cow::Borrowed("TestPolicy"),
+        };
+
+        assert!(relationship_policy("owner".to_string())
+            .evaluate(&ctx)
+            .await
+            .is_granted());
+        let viewer = relationship_policy("viewer".to_string())
+            .evaluate(&ctx)
+            .await;
+        assert!(!viewer.is_granted());
+        assert!(!viewer.is_indeterminate());
+        assert_eq!(*owner_batches.lock().unwrap(), vec![1]);
+        assert_eq!(*viewer_batches.lock().unwrap(), vec![1]);
+
+        // A relation without a route fails closed instead of reading as absent.
+        let editor = relationship_policy("editor".to_string())
+            .evaluate(&ctx)
+            .await;
+        assert!(!editor.is_granted());
+        assert!(editor.is_indeterminate());
+    }
+
+    struct GroupGraphSource {
+        members: HashMap<uuid::Uuid, Vec<uuid::Uuid>>,
+        owners: HashSet<(uuid::Uuid, uuid::Uuid)>,
+    }
+
+    #[async_trait]
+    impl FactSource<RelatedSubjects<uuid::Uuid, String>> for GroupGraphSource {
+        async fn load_many(
+            &self,
+            keys: &[RelatedSubjects<uuid::Uuid, String>],
+        ) -> Vec<FactLoadResult<Vec<uuid::Uuid>>> {
+            keys.iter()
+                .map(|key| match self.members.get(&key.subject_id) {
+                    Some(groups) => FactLoadResult::Found(groups.clone()),
+                    None => FactLoadResult::Missing,
+                })
+                .collect()
+        }
+    }
+
+    #[async_trait]
+    impl FactSource<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>> for GroupGraphSource {
+        async fn load_many(
+            &self,
+            keys: &[RelationshipQuery<uuid::Uuid, uuid::Uuid, String>],
+        ) -> Vec<FactLoadResult<bool>> {
+            keys.iter()
+                .map(|key| {
+                    FactLoadResult::Found(self.owners.contains(&(key.subject_id, key.resource_id)))
+                })
+                .collect()
+        }
+    }
+
+    fn group_graph_session(source: GroupGraphSource) -> EvaluationSession {
+        let source = Arc::new(source);
+        FactRegistry::builder()
+            .with_arc::<RelatedSubjects<uuid::Uuid, String>>(source.clone())
+            .with_arc::<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>>(source)
+            .build()
+            .session()
+    }
+
+    #[tokio::test]
+    async fn test_transitive_rebac_policy_follows_membership_and_records_path() {
+        let user = uuid::Uuid::new_v4();
+        let team = uuid::Uuid::new_v4();
+        let group = uuid::Uuid::new_v4();
+        let document = uuid::Uuid::new_v4();
+        // team and group are members of each other, so the walk must not loop.
+        let session = group_graph_session(GroupGraphSource {
+            members: HashMap::from([(user, vec![team]), (team, vec![group]), (group, vec![team])]),
+            owners: HashSet::from([(group, document)]),
+        });
+        let policy = TransitiveRebacPolicy::<TestDomain, uuid::Uuid, uuid::Uuid, String>::new(
+            |subject: &TestSubject| subject.id,
+            |resource: &TestResource| resource.id,
+            "owner".to_string(),
+            "member".to_string(),
+        );
+        let subject = TestSubject { id: user };
+        let resource = TestResource { id: document };
+        let ctx = EvalCtx {
+            session: &session,
+            subject: &subject,
+            action: &TestAction,
+            resource: &resource,
+            context: &TestContext,
+            policy_type: std::borrow::Cow::Borrowed("TestPolicy"),
+        };
+
+        let result = policy.evaluate(&ctx).await;
+        assert!(result.is_granted());
+        assert_eq!(
+            result.reason().as_deref(),
+            Some(
+                format!(
+                    "Subject has 'owner' relationship with resource via \
+                     {user:?} -> {team:?} -> {group:?} -> {document:?}"
+                )
+                .as_str()
+            )
+        );
+        let provenance = result.provenance();
+        assert_eq!(
+            provenance
+                .iter()
+                .map(|fact| fact.fact_name)
+                .collect::<Vec<_>>(),
+            ["related_subjects", "related_subjects", "relationship"]
+        );
+        assert_eq!(provenance[1].key, format!("{team:?} -[member]-> {group:?}"));
+
+        // One hop reaches the team but not the owning group.
+        let shallow = TransitiveRebacPolicy::<TestDomain, uuid::Uuid, uuid::Uuid, String>::new(
+            |subject: &TestSubject| subject.id,
+            |resource: &TestResource| resource.id,
+            "owner".to_string(),
+            "member".to_string(),
+        )
+        .with_max_depth(1);
+        let result = shallow.evaluate(&ctx).await;
+        assert!(!result.is_granted());
+        assert!(!result.is_indeterminate());
+
+        // A cycle with no owner terminates without a grant.
+        let other_document = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let ctx = EvalCtx {
+            resource: &other_document,
+            ..ctx
+        };
+        let result = policy.evaluate(&ctx).await;
+        assert!(!result.is_granted());
+        assert_eq!(
+            result.reason().as_deref(),
+            Some("No 'owner' relationship with resource within 4 'member' hops")
+        );
+    }
+
     // Combinator tests.
     #[tokio::test]
     async fn test_and_policy_allows_when_all_allow() {
@@ -1874,6 +2116,7 @@ mod core_tests {
                 operation,
                 children,
                 outcome,
+                ..
             } => {
                 assert_eq!(operation, CombineOp::And);
                 assert!(!outcome);
@@ -1927,6 +2170,7 @@ mod core_tests {
                 operation,
                 children,
                 outcome,
+                ..
             } => {
                 assert_eq!(operation, CombineOp::Or);
                 assert!(!outcome);
@@ -1973,6 +2217,7 @@ mod core_tests {
                 operation,
                 children,
                 outcome,
+                ..
             } => {
                 assert_eq!(operation, CombineOp::Not);
                 assert!(!outcome);
@@ -1996,6 +2241,167 @@ mod core_tests {
         assert!(or_policy_result.is_err());
     }
 
+    fn at_most(
+        max_grants: usize,
+        policies: Vec<Arc<dyn Policy<TestDomain>>>,
+    ) -> AtMostPolicy<TestDomain> {
+        AtMostPolicy::try_new(NonZeroUsize::new(max_grants).unwrap(), policies)
+            .expect("Unable to create at-most policy")
+    }
+
+    #[tokio::test]
+    async fn test_at_most_policy_grants_within_limit() {
+        let policy = at_most(
+            1,
+            vec![
+                Arc::new(AlwaysDenyPolicy("SupportPath")),
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysDenyPolicy("OwnerPath")),
+            ],
+        );
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let result = policy
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(result.is_granted());
+        match result {
+            PolicyEvalResult::Combined {
+                policy_type,
+                operation,
+                children,
+                ..
+            } => {
+                assert_eq!(policy_type, "AtMostPolicy");
+                assert_eq!(operation, CombineOp::AtMost { max_grants: 1 });
+                assert_eq!(children.len(), 3);
+            }
+            other => panic!("Expected Combined result from AtMostPolicy, got {other:?}"),
+        }
+
+        let two_of_two = at_most(
+            2,
+            vec![Arc::new(AlwaysAllowPolicy), Arc::new(AlwaysAllowPolicy)],
+        );
+        assert!(two_of_two
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await
+            .is_granted());
+    }
+
+    #[tokio::test]
+    async fn test_at_most_policy_denies_over_limit_and_when_nothing_grants() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let overlapping = at_most(
+            1,
+            vec![
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysDenyPolicy("NeverReached")),
+            ],
+        );
+        let result = overlapping
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!result.is_granted());
+        assert!(
+            !result.format(0).contains("NeverReached"),
+            "evaluation stops once the limit is exceeded"
+        );
+
+        let nothing = at_most(
+            1,
+            vec![
+                Arc::new(AlwaysDenyPolicy("A")),
+                Arc::new(AlwaysDenyPolicy("B")),
+            ],
+        );
+        assert!(!nothing
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await
+            .is_granted());
+
+        let vetoed = at_most(
+            1,
+            vec![
+                Arc::new(AlwaysAllowPolicy),
+                Arc::from(PolicyBuilder::<TestDomain>::new("Veto").forbid().build()),
+            ],
+        );
+        let result = vetoed
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!result.is_granted());
+        assert!(result.is_forbidden());
+
+        assert!(AtMostPolicy::<TestDomain>::try_new(NonZeroUsize::MIN, vec![]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_at_most_policy_batch_matches_single_path() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let owned_resources = (0..4)
+            .map(|value| TestResource {
+                id: uuid::Uuid::from_u128(value),
+            })
+            .collect::<Vec<_>>();
+        let batch_items = owned_resources
+            .iter()
+            .map(|resource| PolicyBatchItem { resource })
+            .collect::<Vec<_>>();
+        let even: Arc<dyn Policy<TestDomain>> = Arc::from(
+            PolicyBuilder::<TestDomain>::new("Even")
+                .resources(|resource: &TestResource| resource.id.as_u128() % 2 == 0)
+                .build(),
+        );
+        let low: Arc<dyn Policy<TestDomain>> = Arc::from(
+            PolicyBuilder::<TestDomain>::new("Low")
+                .resources(|resource: &TestResource| resource.id.as_u128() < 2)
+                .build(),
+        );
+        let policy = at_most(1, vec![even, low]);
+
+        let batch = policy
+            .evaluate_access_batch(&subject, &TestAction, &TestContext, &batch_items)
+            .await;
+        let mut single = Vec::new();
+        for resource in &owned_resources {
+            single.push(
+                policy
+                    .evaluate_access(&subject, &TestAction, resource, &TestContext)
+                    .await
+                    .is_granted(),
+            );
+        }
+
+        assert_eq!(
+            batch
+                .iter()
+                .map(|result| result.is_granted())
+                .collect::<Vec<_>>(),
+            vec![false, true, true, false]
+        );
+        assert_eq!(
+            batch
+                .iter()
+                .map(|result| result.is_granted())
+                .collect::<Vec<_>>(),
+            single
+        );
+    }
+
     #[tokio::test]
     async fn test_deeply_nested_combinators() {
         // Create a complex policy structure: NOT(AND(Allow, OR(Deny, NOT(Deny))))
@@ -2208,6 +2614,37 @@ mod core_tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_builder_field_eq_replaces_owner_check_closure() {
+        let policy = PolicyBuilder::<TestDomain>::new("OwnerPolicy")
+            .field_eq(
+                "subject.id",
+                |subject: &TestSubject| subject.id,
+                "resource.id",
+                |resource: &TestResource| resource.id,
+            )
+            .build();
+
+        let owner_id = uuid::Uuid::new_v4();
+        let owner = TestSubject { id: owner_id };
+        let owned_resource = TestResource { id: owner_id };
+        let other_resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let result = policy
+            .evaluate_access(&owner, &TestAction, &owned_resource, &TestContext)
+            .await;
+        assert!(result.is_granted());
+        assert_eq!(result.reason_str(), Some("subject.id equals resource.id"));
+
+        let result = policy
+            .evaluate_access(&owner, &TestAction, &other_resource, &TestContext)
+            .await;
+        assert!(!result.is_granted());
+        assert_eq!(result.reason_str(), Some("Policy predicate did not match"));
+    }
+
     // ==================== RbacPolicy Tests ====================
 
     #[tokio::test]
@@ -2670,9 +3107,21 @@ mod core_tests {
     }
 
     #[tokio::test]
-    async fn test_access_evaluation_display_trace_granted() {
-        let mut checker = PermissionChecker::new();
-        checker.add_policy(AlwaysAllowPolicy);
+    async fn test_access_evaluation_into_result_keeps_trace_through_question_mark() {
+        async fn authorize(
+            checker: &PermissionChecker<TestDomain>,
+            subject: &TestSubject,
+            resource: &TestResource,
+        ) -> Result<String, AccessEvaluation> {
+            let granted = checker
+                .evaluate_access(subject, &TestAction, resource, &TestContext)
+                .await
+                .into_result()?;
+            Ok(granted
+                .granted_policy_type()
+                .unwrap_or_default()
+                .to_string())
+        }
 
         let subject = TestSubject {
             id: uuid::Uuid::new_v4(),
@@ -2681,9 +3130,35 @@ mod core_tests {
             id: uuid::Uuid::new_v4(),
         };
 
-        let result = checker
-            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
-            .await;
+        let mut allowing = PermissionChecker::new();
+        allowing.add_policy(AlwaysAllowPolicy);
+        assert_eq!(
+            authorize(&allowing, &subject, &resource).await.unwrap(),
+            "AlwaysAllowPolicy"
+        );
+
+        let mut denying = PermissionChecker::new();
+        denying.add_policy(AlwaysDenyPolicy("Access denied"));
+        let denied = authorize(&denying, &subject, &resource).await.unwrap_err();
+        assert_eq!(denied.denied_reason(), Some("All policies denied access"));
+        denied.assert_trace_contains("✘ AlwaysDenyPolicy NOT_APPLICABLE: Access denied");
+    }
+
+    #[tokio::test]
+    async fn test_access_evaluation_display_trace_granted() {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(AlwaysAllowPolicy);
+
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let result = checker
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
 
         let trace_display = result.display_trace();
         assert!(
@@ -2827,6 +3302,7 @@ mod core_tests {
     fn test_policy_eval_result_reason_combined() {
         let result = PolicyEvalResult::Combined {
             policy_type: std::borrow::Cow::Borrowed("CombinedPolicy"),
+            policy_id: None,
             operation: CombineOp::And,
             children: vec![],
             outcome: true,
@@ -2872,6 +3348,10 @@ mod core_tests {
         assert_eq!(format!("{}", CombineOp::And), "AND");
         assert_eq!(format!("{}", CombineOp::Or), "OR");
         assert_eq!(format!("{}", CombineOp::Not), "NOT");
+        assert_eq!(
+            format!("{}", CombineOp::AtMost { max_grants: 2 }),
+            "AT_MOST(2)"
+        );
     }
 
     // ==================== PermissionChecker Default Tests ====================
@@ -3071,6 +3551,7 @@ mod core_tests {
 
         let combined = PolicyEvalResult::Combined {
             policy_type: "C".into(),
+            policy_id: None,
             operation: CombineOp::Or,
             children: vec![],
             outcome: false,
@@ -3091,6 +3572,93 @@ mod core_tests {
         assert_serialize::<CombineOp>();
     }
 
+    #[cfg(feature = "serde")]
+    #[test]
+    fn eval_trace_to_json_tags_every_node_with_its_type() {
+        let trace = EvalTrace::with_root(PolicyEvalResult::Combined {
+            policy_type: std::borrow::Cow::Borrowed("OrPolicy"),
+            policy_id: None,
+            operation: CombineOp::Or,
+            children: vec![
+                PolicyEvalResult::not_applicable("OwnerPolicy", "not the owner"),
+                PolicyEvalResult::granted("AdminPolicy", Some("is admin".into())),
+            ],
+            outcome: true,
+        });
+
+        let json = trace.to_json();
+        let root = &json["root"];
+        assert_eq!(root["type"], "combined");
+        assert_eq!(root["policy_type"], "OrPolicy");
+        assert_eq!(root["operation"], "OR");
+        assert_eq!(root["outcome"], true);
+        assert_eq!(root["children"][0]["type"], "not_applicable");
+        assert_eq!(root["children"][0]["reason"], "not the owner");
+        assert_eq!(root["children"][1]["type"], "granted");
+        assert_eq!(root["children"][1]["policy_type"], "AdminPolicy");
+        assert_eq!(root["children"][1]["reason"], "is admin");
+
+        assert_eq!(EvalTrace::new().to_json()["root"], serde_json::Value::Null);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn eval_trace_to_json_writes_parameterized_operations_as_strings() {
+        for (operation, expected) in [
+            (CombineOp::AtMost { max_grants: 1 }, "AT_MOST(1)"),
+            (
+                CombineOp::Threshold {
+                    threshold: 2,
+                    of: 3,
+                },
+                "THRESHOLD(2/3)",
+            ),
+            (CombineOp::Xor { mode: XorMode::Odd }, "XOR(ODD)"),
+        ] {
+            let trace = EvalTrace::with_root(PolicyEvalResult::Combined {
+                policy_type: std::borrow::Cow::Borrowed("Combinator"),
+                policy_id: None,
+                operation,
+                children: Vec::new(),
+                outcome: false,
+            });
+            assert_eq!(trace.to_json()["root"]["operation"], expected);
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn forbidden_response_serializes_to_the_documented_shape() {
+        let mut checker = PermissionChecker::<TestDomain>::new();
+        checker.add_policy(AlwaysDenyPolicy("not a member"));
+        let evaluation = checker
+            .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+            .await;
+
+        let body = serde_json::to_value(ForbiddenResponse::from(&evaluation)).unwrap();
+        assert_eq!(
+            body,
+            serde_json::json!({
+                "code": "not_permitted",
+                "message": evaluation.denied_reason().unwrap(),
+                "requirements": ["AlwaysDenyPolicy: not a member"],
+            })
+        );
+
+        checker.add_policy(
+            PolicyBuilder::<TestDomain>::new("Suspended")
+                .forbid()
+                .build(),
+        );
+        let evaluation = checker
+            .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+            .await;
+        assert_eq!(
+            ForbiddenResponse::from(&evaluation).code,
+            ForbiddenResponse::FORBIDDEN
+        );
+    }
+
     #[tokio::test]
     async fn granted_policy_type_and_denied_reason_accessors() {
         let grant = allow_checker()
@@ -3201,140 +3769,2436 @@ mod core_tests {
             .await;
         evaluation.assert_trace_contains("this string is not in the trace");
     }
-}
-
-mod policy_builder_tests {
-    use super::*;
-    use std::future::Future;
-    use std::pin::Pin;
-    use uuid::Uuid;
 
-    trait PolicyBoxExt<D>
-    where
-        D: PolicyDomain,
-    {
-        fn evaluate_access<'a>(
-            &'a self,
-            subject: &'a D::Subject,
-            action: &'a D::Action,
-            resource: &'a D::Resource,
-            context: &'a D::Context,
-        ) -> Pin<Box<dyn Future<Output = PolicyEvalResult> + Send + 'a>>;
+    #[tokio::test]
+    async fn assert_policy_evaluated_finds_policies_inside_combinators() {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(
+            AndPolicy::builder()
+                .with(AlwaysDenyPolicy("first"))
+                .with(AlwaysAllowPolicy)
+                .build()
+                .unwrap(),
+        );
+        let evaluation = checker
+            .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+            .await;
+        evaluation.assert_policy_evaluated("AndPolicy");
+        evaluation.assert_policy_evaluated("AlwaysDenyPolicy");
+        // The AND short-circuits on its first child.
+        evaluation.assert_policy_not_evaluated("AlwaysAllowPolicy");
     }
 
-    impl<D> PolicyBoxExt<D> for Box<dyn Policy<D>>
-    where
-        D: PolicyDomain,
-    {
-        fn evaluate_access<'a>(
-            &'a self,
-            subject: &'a D::Subject,
-            action: &'a D::Action,
-            resource: &'a D::Resource,
-            context: &'a D::Context,
-        ) -> Pin<Box<dyn Future<Output = PolicyEvalResult> + Send + 'a>> {
-            Box::pin(async move {
-                let session = EvaluationSession::new();
-                let policy_type = self.policy_type();
-                let ctx = EvalCtx {
-                    session: &session,
-                    subject,
-                    action,
-                    resource,
-                    context,
-                    policy_type,
-                };
-                self.evaluate(&ctx).await
-            })
+    #[tokio::test]
+    #[should_panic(expected = "expected policy `Missing` to appear in the trace")]
+    async fn assert_policy_evaluated_panics_with_the_trace() {
+        let evaluation = deny_checker()
+            .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+            .await;
+        evaluation.assert_policy_evaluated("Missing");
+    }
+    async fn rollout_decisions(policy: &RolloutPolicy<TestDomain>) -> Vec<bool> {
+        let resource = TestResource {
+            id: uuid::Uuid::nil(),
+        };
+        let mut decisions = Vec::new();
+        for value in 0..200u128 {
+            let subject = TestSubject {
+                id: uuid::Uuid::from_u128(value),
+            };
+            decisions.push(
+                policy
+                    .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+                    .await
+                    .is_granted(),
+            );
         }
+        decisions
     }
 
-    // Define simple test types
-    #[derive(Debug, Clone)]
-    struct TestSubject {
-        pub name: String,
+    fn seeded_rollout(percentage: u8, seed: u64) -> RolloutPolicy<TestDomain> {
+        RolloutPolicy::<TestDomain>::new(
+            "NewEditorRollout",
+            percentage,
+            |subject: &TestSubject, _resource: &TestResource| subject.id,
+        )
+        .with_entropy(FixedEntropy::new(seed))
     }
-    #[derive(Debug, Clone)]
-    struct TestAction;
-    #[derive(Debug, Clone)]
-    struct TestResource;
-    #[derive(Debug, Clone)]
-    struct TestContext;
 
-    struct TestDomain;
+    #[tokio::test]
+    async fn rollout_with_fixed_entropy_is_reproducible_across_runs() {
+        let first_run = rollout_decisions(&seeded_rollout(30, 42)).await;
+        let second_run = rollout_decisions(&seeded_rollout(30, 42)).await;
+        assert_eq!(first_run, second_run);
 
-    impl PolicyDomain for TestDomain {
-        type Subject = TestSubject;
-        type Action = TestAction;
-        type Resource = TestResource;
-        type Context = TestContext;
+        let granted = first_run.iter().filter(|granted| **granted).count();
+        assert!(
+            (30..=90).contains(&granted),
+            "30% of 200 subjects should land near 60 grants, got {granted}"
+        );
+
+        let other_seed = rollout_decisions(&seeded_rollout(30, 7)).await;
+        assert_ne!(first_run, other_seed, "the seed selects the buckets");
     }
 
-    // Test that with no predicates the builder returns a policy that always "matches"
     #[tokio::test]
-    async fn test_policy_builder_allows_when_no_predicates() {
-        let policy = PolicyBuilder::<TestDomain>::new("NoPredicatesPolicy").build();
+    async fn rollout_percentage_bounds_grant_none_or_all() {
+        assert!(rollout_decisions(&seeded_rollout(0, 1))
+            .await
+            .iter()
+            .all(|granted| !granted));
+        assert!(rollout_decisions(&seeded_rollout(100, 1))
+            .await
+            .iter()
+            .all(|granted| *granted));
+        assert!(rollout_decisions(&seeded_rollout(u8::MAX, 1))
+            .await
+            .iter()
+            .all(|granted| *granted));
+    }
 
-        let result = policy
-            .evaluate_access(
-                &TestSubject { name: "Any".into() },
+    #[test]
+    fn system_entropy_is_stable_per_instance() {
+        let entropy = SystemEntropy::new();
+        assert_eq!(entropy.sample(17), entropy.sample(17));
+        assert_eq!(
+            FixedEntropy::new(3).sample(17),
+            FixedEntropy::new(3).sample(17)
+        );
+    }
+
+    fn even_resource_policy(name: &'static str) -> Arc<dyn Policy<TestDomain>> {
+        Arc::from(
+            PolicyBuilder::<TestDomain>::new(name)
+                .resources(|resource: &TestResource| resource.id.as_bytes()[0] % 2 == 0)
+                .build(),
+        )
+    }
+
+    fn five_level_tree() -> OrPolicy<TestDomain> {
+        let forbid_high: Arc<dyn Policy<TestDomain>> = Arc::from(
+            PolicyBuilder::<TestDomain>::new("ForbidHighResources")
+                .resources(|resource: &TestResource| resource.id.as_bytes()[1] >= 0xc0)
+                .forbid()
+                .build(),
+        );
+        let level_five = NotPolicy::new(even_resource_policy("EvenLevelFive"));
+        let level_four =
+            AndPolicy::try_new(vec![Arc::new(level_five), Arc::new(AlwaysAllowPolicy)])
+                .expect("Unable to create and-policy");
+        let level_three = at_most(
+            1,
+            vec![
+                Arc::new(level_four),
+                even_resource_policy("EvenLevelThree"),
+                Arc::new(AlwaysDenyPolicy("LevelThreeDeny")),
+            ],
+        );
+        let level_two = AndPolicy::try_new(vec![
+            Arc::new(level_three),
+            Arc::new(AlwaysAllowPolicy.or(AlwaysDenyPolicy("LevelTwoDeny"))),
+        ])
+        .expect("Unable to create and-policy");
+        OrPolicy::try_new(vec![
+            Arc::new(AlwaysDenyPolicy("RootDeny")),
+            Arc::new(level_two),
+            forbid_high,
+        ])
+        .expect("Unable to create or-policy")
+    }
+
+    #[tokio::test]
+    async fn test_compiled_policy_matches_interpreted_five_level_tree() {
+        let interpreted = five_level_tree();
+        let compiled = five_level_tree().compile();
+        assert_eq!(compiled.combinator_count(), 6);
+        assert_eq!(compiled.leaf_count(), 8);
+
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let mut granted = 0;
+        for _ in 0..64 {
+            let resource = TestResource {
+                id: uuid::Uuid::new_v4(),
+            };
+            let expected = interpreted
+                .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+                .await;
+            let actual = compiled
+                .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+                .await;
+            assert_eq!(actual.is_granted(), expected.is_granted());
+            assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+            granted += usize::from(actual.is_granted());
+        }
+        assert!(granted > 0 && granted < 64);
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_annotated_marks_short_circuited_nodes() {
+        let mut checker = PermissionChecker::<TestDomain>::new();
+        checker.add_policy(AlwaysAllowPolicy.or(AlwaysDenyPolicy("SkippedInOr")));
+        checker.add_policy(AlwaysDenyPolicy("SkippedInChecker"));
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let tree = checker
+            .evaluate_annotated(
+                &EvaluationSession::empty(),
+                &subject,
                 &TestAction,
-                &TestResource,
+                &resource,
                 &TestContext,
             )
             .await;
-        assert!(
-            result.is_granted(),
-            "Policy built with no predicates should allow access (default true)"
+        assert!(tree.evaluation.is_granted());
+        assert_eq!(tree.root.operation, Some(CombineOp::DenyOverrides));
+        assert_eq!(tree.root.children.len(), 2);
+
+        let or_node = &tree.root.children[0];
+        assert_eq!(or_node.policy_type, "OrPolicy");
+        assert_eq!(or_node.operation, Some(CombineOp::Or));
+        assert_eq!(or_node.outcome, NodeOutcome::Granted { reason: None });
+        assert_eq!(
+            or_node.children[0].outcome,
+            NodeOutcome::Granted {
+                reason: Some("Always allow policy".to_string())
+            }
         );
+        assert_eq!(or_node.children[1].policy_type, "AlwaysDenyPolicy");
+        assert_eq!(or_node.children[1].outcome, NodeOutcome::NotEvaluated);
+
+        let skipped = &tree.root.children[1];
+        assert_eq!(skipped.policy_type, "AlwaysDenyPolicy");
+        assert_eq!(skipped.operation, None);
+        assert!(!skipped.outcome.is_evaluated());
+    }
+
+    struct RiskPolicy {
+        name: &'static str,
+        grants: bool,
+        risk: f32,
+    }
+
+    #[async_trait]
+    impl Policy<TestDomain> for RiskPolicy {
+        async fn evaluate(&self, ctx: &EvalCtx<'_, TestDomain>) -> PolicyEvalResult {
+            let result = if self.grants {
+                ctx.grant("risky grant")
+            } else {
+                ctx.not_applicable("risky signal")
+            };
+            result.with_risk(self.risk)
+        }
+
+        fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed(self.name)
+        }
     }
 
-    // Test that a subject predicate is applied correctly.
     #[tokio::test]
-    async fn test_policy_builder_with_subject_predicate() {
-        let policy = PolicyBuilder::<TestDomain>::new("SubjectPolicy")
-            .subjects(|s: &TestSubject| s.name == "Alice")
-            .build();
+    async fn test_aggregate_risk_sums_evaluated_policies() {
+        let mut checker = PermissionChecker::<TestDomain>::new();
+        checker.add_policy(RiskPolicy {
+            name: "NewDeviceSignal",
+            grants: false,
+            risk: 0.25,
+        });
+        checker.add_policy(RiskPolicy {
+            name: "OwnerGrant",
+            grants: true,
+            risk: 0.5,
+        });
+        checker.add_policy(RiskPolicy {
+            name: "NeverReached",
+            grants: true,
+            risk: 4.0,
+        });
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
 
-        // Should allow if the subject's name is "Alice"
-        let result1 = policy
-            .evaluate_access(
-                &TestSubject {
-                    name: "Alice".into(),
-                },
+        let evaluation = checker
+            .bind(
+                &EvaluationSession::empty(),
+                &subject,
                 &TestAction,
-                &TestResource,
                 &TestContext,
             )
+            .check(&resource)
             .await;
-        assert!(
-            result1.is_granted(),
-            "Policy should allow access for subject 'Alice'"
-        );
+        evaluation.assert_granted_by("OwnerGrant");
+        assert_eq!(evaluation.aggregate_risk(), Some(0.75));
 
-        // Otherwise, it should not apply.
-        let result2 = policy
-            .evaluate_access(
-                &TestSubject { name: "Bob".into() },
+        let mut quiet = PermissionChecker::<TestDomain>::new();
+        quiet.add_policy(AlwaysAllowPolicy);
+        let evaluation = quiet
+            .bind(
+                &EvaluationSession::empty(),
+                &subject,
                 &TestAction,
-                &TestResource,
                 &TestContext,
             )
+            .check(&resource)
             .await;
-        assert!(
-            !result2.is_granted(),
-            "Policy should not grant access for subject not named 'Alice'"
-        );
+        assert_eq!(evaluation.aggregate_risk(), None);
+    }
+
+    fn mock_remote(reply: &'static str) -> RemotePolicy<TestDomain> {
+        RemotePolicy::new(
+            "RemoteAuthz",
+            move |_subject, _action, _resource, _context| {
+                Box::pin(async move {
+                    match reply {
+                        "grant" => Ok(PolicyEvalResult::granted(
+                            "RemoteAuthz",
+                            Some("remote grant".to_string()),
+                        )),
+                        "deny" => Ok(PolicyEvalResult::not_applicable(
+                            "RemoteAuthz",
+                            "remote deny",
+                        )),
+                        _ => Err("connection refused".into()),
+                    }
+                })
+            },
+        )
     }
 
-    // Test that `.forbid()` turns an otherwise matching predicate into an active veto.
     #[tokio::test]
-    async fn test_policy_builder_forbid() {
-        let policy = PolicyBuilder::<TestDomain>::new("ForbidPolicy")
-            .forbid()
-            .build();
-
-        // Even though no predicate fails (so predicate returns true),
+    async fn test_remote_policy_composes_and_fails_closed() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let expected = [
+            ("grant", true, Some("remote grant")),
+            ("deny", false, Some("remote deny")),
+            (
+                "error",
+                false,
+                Some("Remote policy failed: connection refused"),
+            ),
+        ];
+
+        for (reply, granted, remote_reason) in expected {
+            let policy = AlwaysDenyPolicy("local deny").or(mock_remote(reply));
+            let result = policy
+                .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+                .await;
+            assert_eq!(result.is_granted(), granted, "reply {reply}");
+            let PolicyEvalResult::Combined { children, .. } = &result else {
+                panic!("Expected Combined result, got {result:?}");
+            };
+            assert_eq!(children.len(), 2);
+            assert_eq!(children[1].reason_str(), remote_reason);
+            assert!(result.format(0).contains("RemoteAuthz"));
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_remote_policy_times_out_closed() {
+        let policy = RemotePolicy::<TestDomain>::new("SlowAuthz", |_, _, _, _| {
+            Box::pin(async {
+                tokio::time::sleep(std::time::Duration::from_secs(5)).await;
+                Ok(PolicyEvalResult::granted("SlowAuthz", None))
+            })
+        })
+        .with_timeout(std::time::Duration::from_millis(50));
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let result = policy
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!result.is_granted());
+        assert_eq!(
+            result.reason_str(),
+            Some("Remote policy timed out after 50ms")
+        );
+    }
+
+    struct UnavailablePolicy;
+
+    #[async_trait]
+    impl Policy<TestDomain> for UnavailablePolicy {
+        async fn evaluate(&self, ctx: &EvalCtx<'_, TestDomain>) -> PolicyEvalResult {
+            ctx.indeterminate("relationship store unavailable")
+        }
+
+        fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("UnavailablePolicy")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_indeterminate_fails_closed_and_is_surfaced_in_trace() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let leaf = UnavailablePolicy
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!leaf.is_granted());
+        assert!(!leaf.is_forbidden());
+        assert!(leaf.is_indeterminate());
+        assert_eq!(
+            leaf.format(0),
+            "⚠ UnavailablePolicy INDETERMINATE: relationship store unavailable"
+        );
+
+        // Negating "couldn't decide" must not produce a grant, interpreted
+        // or compiled.
+        let negated = NotPolicy::new(UnavailablePolicy)
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!negated.is_granted());
+        assert!(negated.is_indeterminate());
+        let compiled = NotPolicy::new(UnavailablePolicy)
+            .compile()
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert_eq!(format!("{compiled:?}"), format!("{negated:?}"));
+
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(NotPolicy::new(UnavailablePolicy));
+        checker.add_policy(AlwaysDenyPolicy("AlwaysDeny"));
+        let evaluation = checker
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        evaluation.assert_denied();
+        evaluation.assert_trace_contains("⚠ UnavailablePolicy INDETERMINATE");
+    }
+
+    #[cfg(feature = "tokio")]
+    struct SlowAllowPolicy;
+
+    #[cfg(feature = "tokio")]
+    #[async_trait]
+    impl Policy<TestDomain> for SlowAllowPolicy {
+        async fn evaluate(&self, ctx: &EvalCtx<'_, TestDomain>) -> PolicyEvalResult {
+            tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            ctx.grant("slow grant")
+        }
+
+        fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("SlowAllowPolicy")
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_evaluate_with_deadline_fails_closed_after_deadline() {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(SlowAllowPolicy);
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resources = (0..6)
+            .map(|value| TestResource {
+                id: uuid::Uuid::from_u128(value),
+            })
+            .collect::<Vec<_>>();
+        let session = EvaluationSession::empty();
+        let deadline = tokio::time::Instant::now() + std::time::Duration::from_millis(35);
+
+        let results = checker
+            .bind(&session, &subject, &TestAction, &TestContext)
+            .evaluate_with_deadline(&resources, deadline)
+            .await;
+
+        assert_eq!(results.len(), resources.len());
+        for (index, (resource, evaluation)) in results.iter().enumerate() {
+            assert_eq!(resource.id, resources[index].id);
+            if index < 3 {
+                evaluation.assert_granted_by("SlowAllowPolicy");
+            } else {
+                evaluation.assert_denied_with_reason_containing("batch deadline exceeded");
+                assert!(evaluation
+                    .trace()
+                    .root()
+                    .is_some_and(PolicyEvalResult::is_indeterminate));
+            }
+        }
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_ambient_deadline_fails_closed_once_it_passes() {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(SlowAllowPolicy);
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let session = EvaluationSession::empty();
+        let bound = checker.bind(&session, &subject, &TestAction, &TestContext);
+        let in_ms = |ms| tokio::time::Instant::now() + std::time::Duration::from_millis(ms);
+
+        with_deadline(in_ms(50), bound.check(&resource))
+            .await
+            .assert_granted_by("SlowAllowPolicy");
+
+        let evaluation = with_deadline(in_ms(5), bound.check(&resource)).await;
+        evaluation.assert_denied_with_reason_containing("deadline exceeded");
+        assert!(evaluation
+            .trace()
+            .root()
+            .is_some_and(PolicyEvalResult::is_indeterminate));
+
+        // An inner scope cannot extend the outer deadline.
+        let nested = with_deadline(in_ms(5), with_deadline(in_ms(50), bound.check(&resource)));
+        nested.await.assert_denied();
+
+        bound
+            .check(&resource)
+            .await
+            .assert_granted_by("SlowAllowPolicy");
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_ambient_deadline_skips_remaining_policies_and_counts_them() {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(AsyncAbacPolicy::<TestDomain>::new(
+            "SlowLookup",
+            |_, _, _, _| {
+                Box::pin(async {
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    false
+                })
+            },
+        ));
+        checker.add_policy(AlwaysDenyPolicy("Second"));
+        checker.add_policy(AlwaysAllowPolicy);
+        let subject = test_subject();
+        let resource = test_resource();
+        let session = EvaluationSession::empty();
+        let deadline = tokio::time::Instant::now() + std::time::Duration::from_millis(10);
+
+        // The lookup finishes right at the deadline, so the checker stops
+        // before the next policy instead of granting through the last one.
+        let evaluation = with_deadline(
+            deadline,
+            checker
+                .bind(&session, &subject, &TestAction, &TestContext)
+                .check(&resource),
+        )
+        .await;
+
+        evaluation.assert_denied_with_reason_containing(
+            "deadline exceeded: 1 of 3 policies evaluated, 2 skipped",
+        );
+        assert!(evaluation
+            .trace()
+            .root()
+            .is_some_and(PolicyEvalResult::is_indeterminate));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_timeout_policy_marks_slow_inner_policy_indeterminate() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let in_time = TimeoutPolicy::new(SlowAllowPolicy, std::time::Duration::from_millis(50))
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(in_time.is_granted());
+
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(TimeoutPolicy::new(
+            SlowAllowPolicy,
+            std::time::Duration::from_millis(5),
+        ));
+        let session = EvaluationSession::empty();
+        let bound = checker.bind(&session, &subject, &TestAction, &TestContext);
+
+        let evaluation = bound.check(&resource).await;
+        evaluation.assert_denied();
+        evaluation
+            .assert_trace_contains("⚠ SlowAllowPolicy INDETERMINATE: Policy timed out after 5ms");
+        for (_, evaluation) in bound.evaluate([&resource, &resource]).await {
+            evaluation.assert_denied();
+            evaluation.assert_trace_contains(
+                "⚠ SlowAllowPolicy INDETERMINATE: Policy timed out after 5ms",
+            );
+        }
+    }
+
+    fn forbid_everything(name: &'static str) -> Arc<dyn Policy<TestDomain>> {
+        Arc::from(PolicyBuilder::<TestDomain>::new(name).forbid().build())
+    }
+
+    #[tokio::test]
+    async fn test_tiered_policy_lets_the_highest_deciding_tier_win() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let session = EvaluationSession::empty();
+
+        // An organization-tier forbid beats a team-tier grant.
+        let org_forbids = TieredPolicy::try_new(vec![
+            vec![forbid_everything("OrgLegalHold")],
+            vec![Arc::new(AlwaysAllowPolicy)],
+        ])
+        .expect("Unable to create tiered policy");
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(org_forbids);
+        let evaluation = checker
+            .bind(&session, &subject, &TestAction, &TestContext)
+            .check(&resource)
+            .await;
+        assert_eq!(evaluation.forbidden_by(), Some("OrgLegalHold"));
+
+        // An organization-tier grant beats a team-tier forbid, which is never
+        // evaluated.
+        let org_grants = TieredPolicy::try_new(vec![
+            vec![
+                Arc::new(AlwaysDenyPolicy("OrgNoOpinion")),
+                Arc::new(AlwaysAllowPolicy),
+            ],
+            vec![forbid_everything("TeamBlock")],
+        ])
+        .expect("Unable to create tiered policy");
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(org_grants);
+        let evaluation = checker
+            .bind(&session, &subject, &TestAction, &TestContext)
+            .check(&resource)
+            .await;
+        evaluation.assert_granted_by("TieredPolicy");
+        evaluation.assert_trace_contains("✔ TieredPolicy (TIERED)");
+        evaluation.assert_trace_contains("✔ Tier 1 (DENY_OVERRIDES)");
+        assert!(!evaluation.display_trace().contains("TeamBlock"));
+
+        // A tier without an opinion defers to the next one.
+        let deferred = TieredPolicy::try_new(vec![
+            vec![Arc::new(AlwaysDenyPolicy("OrgNoOpinion"))],
+            vec![forbid_everything("TeamBlock")],
+        ])
+        .expect("Unable to create tiered policy")
+        .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+        .await;
+        assert!(!deferred.is_granted());
+        assert!(deferred.is_forbidden());
+
+        assert!(TieredPolicy::<TestDomain>::try_new(vec![vec![]]).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_threshold_policy_requires_n_of_m_grants() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let threshold = |n: usize, policies: Vec<Arc<dyn Policy<TestDomain>>>| {
+            ThresholdPolicy::try_new(NonZeroUsize::new(n).unwrap(), policies)
+                .expect("Unable to create threshold policy")
+        };
+        let children = |result: &PolicyEvalResult| match result {
+            PolicyEvalResult::Combined { children, .. } => children.len(),
+            _ => panic!("expected a combined result"),
+        };
+
+        let dual_control = threshold(
+            2,
+            vec![
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysDenyPolicy("SecondApprover")),
+                Arc::new(AlwaysAllowPolicy),
+            ],
+        )
+        .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+        .await;
+        assert!(dual_control.is_granted());
+        assert!(dual_control
+            .format(0)
+            .starts_with("✔ ThresholdPolicy (THRESHOLD(2/3))"));
+        assert_eq!(children(&dual_control), 3);
+
+        // Stops as soon as the threshold can no longer be reached.
+        let unreachable = threshold(
+            3,
+            vec![
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysDenyPolicy("SecondApprover")),
+                Arc::new(AlwaysAllowPolicy),
+            ],
+        )
+        .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+        .await;
+        assert!(!unreachable.is_granted());
+        assert_eq!(children(&unreachable), 2);
+
+        let vetoed = threshold(
+            1,
+            vec![Arc::new(AlwaysAllowPolicy), forbid_everything("Frozen")],
+        )
+        .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+        .await;
+        assert!(!vetoed.is_granted());
+        assert!(vetoed.is_forbidden());
+
+        let error = ThresholdPolicy::<TestDomain>::try_new(
+            NonZeroUsize::new(4).unwrap(),
+            vec![Arc::new(AlwaysAllowPolicy)],
+        )
+        .err()
+        .expect("threshold above the policy count must be rejected");
+        assert_eq!(
+            error.to_string(),
+            "ThresholdPolicy threshold 4 exceeds policy count 1"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_xor_policy_grants_only_mutually_exclusive_grants() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let evaluate = |mode: XorMode, policies: Vec<Arc<dyn Policy<TestDomain>>>| {
+            let policy = XorPolicy::try_new(mode, policies).expect("Unable to create xor policy");
+            let (subject, resource) = (subject.clone(), resource.clone());
+            async move {
+                policy
+                    .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+                    .await
+            }
+        };
+        let grant_count = |result: &PolicyEvalResult| match result {
+            PolicyEvalResult::Combined { children, .. } => {
+                children.iter().filter(|child| child.is_granted()).count()
+            }
+            _ => panic!("expected a combined result"),
+        };
+
+        let exactly_one = evaluate(
+            XorMode::ExactlyOne,
+            vec![
+                Arc::new(AlwaysDenyPolicy("PersonalOwner")),
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysDenyPolicy("TeamOwner")),
+            ],
+        )
+        .await;
+        assert!(exactly_one.is_granted());
+        assert!(exactly_one.format(0).starts_with("✔ XorPolicy (XOR)"));
+
+        // A second grant denies and stops evaluation.
+        let two_owners = evaluate(
+            XorMode::ExactlyOne,
+            vec![
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysDenyPolicy("TeamOwner")),
+            ],
+        )
+        .await;
+        assert!(!two_owners.is_granted());
+        assert_eq!(grant_count(&two_owners), 2);
+
+        let three_grants = evaluate(
+            XorMode::Odd,
+            vec![
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysAllowPolicy),
+            ],
+        )
+        .await;
+        assert!(three_grants.is_granted());
+        assert!(three_grants.format(0).starts_with("✔ XorPolicy (XOR(ODD))"));
+
+        let no_grants = evaluate(
+            XorMode::ExactlyOne,
+            vec![
+                Arc::new(AlwaysDenyPolicy("PersonalOwner")),
+                Arc::new(AlwaysDenyPolicy("TeamOwner")),
+            ],
+        )
+        .await;
+        assert!(!no_grants.is_granted());
+
+        assert!(
+            XorPolicy::<TestDomain>::try_new(XorMode::Odd, vec![Arc::new(AlwaysAllowPolicy)])
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_weighted_or_policy_ignores_weak_grants() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let signal = |name: &str| -> Arc<dyn Policy<TestDomain>> {
+            Arc::from(PolicyBuilder::<TestDomain>::new(name).build())
+        };
+
+        let policy = WeightedOrPolicy::try_new(
+            vec![
+                (signal("DeviceFingerprint"), 0.2),
+                (signal("HardwareKey"), 0.9),
+                (signal("KnownNetwork"), 0.5),
+            ],
+            0.7,
+        )
+        .expect("Unable to create weighted or policy");
+        let result = policy
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(result.is_granted());
+        let trace = result.format(0);
+        assert!(
+            trace.starts_with("✔ WeightedOrPolicy (WEIGHTED_OR)"),
+            "{trace}"
+        );
+        // The weak grant is evaluated and skipped; the strong one decides.
+        assert!(trace.contains("DeviceFingerprint GRANTED"), "{trace}");
+        assert!(trace.contains("HardwareKey GRANTED"), "{trace}");
+        assert!(!trace.contains("KnownNetwork"), "{trace}");
+
+        let weak_only = WeightedOrPolicy::try_new(
+            vec![
+                (signal("DeviceFingerprint"), 0.2),
+                (signal("KnownNetwork"), 0.5),
+            ],
+            0.7,
+        )
+        .expect("Unable to create weighted or policy");
+        let result = weak_only
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!result.is_granted());
+
+        let vetoed = WeightedOrPolicy::try_new(
+            vec![
+                (signal("HardwareKey"), 0.9),
+                (forbid_everything("Blocklist"), 0.0),
+            ],
+            0.7,
+        )
+        .expect("Unable to create weighted or policy");
+        let result = vetoed
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(result.is_forbidden());
+
+        assert!(WeightedOrPolicy::<TestDomain>::try_new(Vec::new(), 0.5).is_err());
+    }
+    #[tokio::test]
+    async fn test_policy_check_returns_access_evaluation_without_a_checker() {
+        let owner = uuid::Uuid::new_v4();
+        let policy = PolicyBuilder::<TestDomain>::new("OwnerOnly")
+            .when(move |subject, _action, _resource, _context| subject.id == owner)
+            .build();
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let session = EvaluationSession::new();
+
+        let granted = policy
+            .check(
+                &session,
+                &TestSubject { id: owner },
+                &TestAction,
+                &resource,
+                &TestContext,
+            )
+            .await;
+        assert!(granted.is_granted());
+        granted.assert_granted_by("OwnerOnly");
+
+        let denied = policy
+            .check(
+                &session,
+                &TestSubject {
+                    id: uuid::Uuid::new_v4(),
+                },
+                &TestAction,
+                &resource,
+                &TestContext,
+            )
+            .await;
+        assert!(!denied.is_granted());
+        denied.assert_not_applicable_by("OwnerOnly");
+        denied.assert_denied_with_reason_containing("Not granted by OwnerOnly");
+    }
+    #[tokio::test]
+    async fn test_impersonation_policy_requires_both_subjects() {
+        struct SupportDomain;
+        impl PolicyDomain for SupportDomain {
+            type Subject = &'static str;
+            type Action = ();
+            type Resource = ();
+            // The impersonating agent, if any.
+            type Context = Option<&'static str>;
+        }
+
+        let policy = ImpersonationPolicy::new(
+            PolicyBuilder::<SupportDomain>::new("CanRefund")
+                .subjects(|subject: &&'static str| ["alice", "lead-agent"].contains(subject))
+                .build(),
+            |impersonator: &Option<&'static str>| *impersonator,
+        );
+        let session = EvaluationSession::new();
+
+        let direct = policy.check(&session, &"alice", &(), &(), &None).await;
+        direct.assert_granted_by("CanRefund");
+
+        let trusted = policy
+            .check(&session, &"alice", &(), &(), &Some("lead-agent"))
+            .await;
+        trusted.assert_granted_by("CanRefund");
+
+        // alice may refund, but the junior agent acting for her may not.
+        let denied = policy
+            .check(&session, &"alice", &(), &(), &Some("junior-agent"))
+            .await;
+        assert!(!denied.is_granted());
+        let Some(PolicyEvalResult::Combined { children, .. }) = denied.trace().root() else {
+            panic!("expected an AND node, got:\n{}", denied.display_trace());
+        };
+        assert!(children[0].is_granted());
+        assert!(!children[1].is_granted());
+    }
+
+    #[tokio::test]
+    async fn test_cached_policy_reuses_results_until_they_expire() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = calls.clone();
+        let policy = CachedPolicy::new(
+            PolicyBuilder::<TestDomain>::new("Expensive")
+                .when(
+                    move |subject: &TestSubject, _, resource: &TestResource, _| {
+                        counted.fetch_add(1, Ordering::SeqCst);
+                        subject.id == resource.id
+                    },
+                )
+                .build(),
+            |subject: &TestSubject, _: &TestAction, resource: &TestResource, _: &TestContext| {
+                (subject.id, resource.id)
+            },
+            1,
+            std::time::Duration::from_millis(50),
+        );
+        let session = EvaluationSession::new();
+        let id = uuid::Uuid::new_v4();
+        let owner = TestSubject { id };
+        let owned = TestResource { id };
+        let other = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let first = policy
+            .check(&session, &owner, &TestAction, &owned, &TestContext)
+            .await;
+        first.assert_granted_by("Expensive");
+        let second = policy
+            .check(&session, &owner, &TestAction, &owned, &TestContext)
+            .await;
+        second.assert_granted_by("Expensive");
+        second.assert_trace_contains("(cached)");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Capacity 1: a second key evicts the first.
+        let denied = policy
+            .check(&session, &owner, &TestAction, &other, &TestContext)
+            .await;
+        assert!(!denied.is_granted());
+        policy
+            .check(&session, &owner, &TestAction, &owned, &TestContext)
+            .await
+            .assert_granted_by("Expensive");
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+
+        tokio::time::sleep(std::time::Duration::from_millis(60)).await;
+        policy
+            .check(&session, &owner, &TestAction, &owned, &TestContext)
+            .await
+            .assert_granted_by("Expensive");
+        assert_eq!(calls.load(Ordering::SeqCst), 4);
+    }
+    #[tokio::test]
+    async fn test_short_circuit_culprit_is_the_failing_and_child() {
+        let policy = AndPolicy::try_new(vec![
+            Arc::new(AlwaysAllowPolicy),
+            Arc::new(AlwaysDenyPolicy("StopsTheAnd")),
+            Arc::new(AlwaysAllowPolicy),
+        ])
+        .expect("Unable to create and-policy policy");
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let result = policy
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+
+        let culprit = result
+            .short_circuit_culprit()
+            .expect("a denying AND has a culprit");
+        assert!(culprit.format(0).contains("StopsTheAnd"));
+        assert!(culprit.short_circuit_culprit().is_none());
+
+        let granted = AndPolicy::try_new(vec![Arc::new(AlwaysAllowPolicy)])
+            .expect("Unable to create and-policy policy")
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(granted.short_circuit_culprit().is_none());
+    }
+    #[tokio::test]
+    async fn test_one_context_drives_every_accessor_based_builtin() {
+        use std::time::{Duration, SystemTime};
+
+        struct RequestCtx {
+            received_at: SystemTime,
+            tenant: u64,
+            authed_at: Option<SystemTime>,
+        }
+        context_accessors!(RequestCtx {
+            time: received_at,
+            tenant: tenant as u64,
+            auth_time: authed_at,
+        });
+
+        struct TenantDomain;
+        impl PolicyDomain for TenantDomain {
+            type Subject = ();
+            type Action = ();
+            // The resource's tenant.
+            type Resource = u64;
+            type Context = RequestCtx;
+        }
+
+        let now = SystemTime::now();
+        let hour = Duration::from_secs(3600);
+        let mut checker =
+            PermissionChecker::<TenantDomain>::with_strategy(CombineStrategy::AllAllow);
+        checker.add_policy(TimeWindowPolicy::new(now - hour, now + hour));
+        checker.add_policy(
+            PolicyBuilder::<TenantDomain>::new("Reauthenticated")
+                .require_fresh_auth_from_context(Duration::from_secs(300))
+                .build(),
+        );
+        checker.add_policy(ContextTenantPolicy::new(|tenant: &u64| *tenant));
+        let session = EvaluationSession::new();
+        let ctx = |received_at, tenant, authed_at| RequestCtx {
+            received_at,
+            tenant,
+            authed_at: Some(authed_at),
+        };
+
+        let allowed = checker
+            .bind(&session, &(), &(), &ctx(now, 7, now))
+            .check(&7)
+            .await;
+        assert!(allowed.is_granted(), "{}", allowed.display_trace());
+        assert_eq!(allowed.valid_until(), Some(now + hour));
+
+        let other_tenant = checker
+            .bind(&session, &(), &(), &ctx(now, 7, now))
+            .check(&8)
+            .await;
+        other_tenant.assert_forbidden_by("ContextTenantPolicy");
+
+        let stale = checker
+            .bind(&session, &(), &(), &ctx(now, 7, now - hour))
+            .check(&7)
+            .await;
+        stale.assert_denied_with_reason_containing("Reauthenticated");
+
+        let closed = checker
+            .bind(&session, &(), &(), &ctx(now + 2 * hour, 7, now))
+            .check(&7)
+            .await;
+        closed.assert_not_applicable_by("TimeWindowPolicy");
+    }
+    #[tokio::test]
+    async fn test_combinator_builders_take_policies_by_value() {
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+
+        let and = AndPolicy::builder()
+            .with(AlwaysAllowPolicy)
+            .with(AlwaysDenyPolicy("DenyInAnd"))
+            .build()
+            .expect("Unable to create and-policy policy");
+        let result = and
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!result.is_granted());
+
+        let or = OrPolicy::builder()
+            .with(AlwaysDenyPolicy("DenyInOr"))
+            .with_arc(Arc::new(AlwaysAllowPolicy))
+            .build()
+            .expect("Unable to create or-policy policy");
+        let result = or
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(result.is_granted());
+
+        assert!(AndPolicy::<TestDomain>::builder().build().is_err());
+    }
+    #[tokio::test]
+    async fn test_not_policy_grant_explains_the_inner_denial() {
+        let mut checker = PermissionChecker::<TestDomain>::new();
+        checker.add_policy(AlwaysDenyPolicy("Account is not locked").not());
+        let subject = TestSubject {
+            id: uuid::Uuid::new_v4(),
+        };
+        let resource = TestResource {
+            id: uuid::Uuid::new_v4(),
+        };
+        let session = EvaluationSession::new();
+        let evaluation = checker
+            .bind(&session, &subject, &TestAction, &TestContext)
+            .check(&resource)
+            .await;
+
+        let AccessEvaluation::Granted { reason, .. } = &evaluation else {
+            panic!("expected a grant, got:\n{}", evaluation.display_trace());
+        };
+        assert_eq!(
+            reason.as_deref(),
+            Some("Granted because 'AlwaysDenyPolicy' did not grant: Account is not locked")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sort_policies_by_type_makes_traces_independent_of_add_order() {
+        fn checker(order: &[&'static str]) -> PermissionChecker<TestDomain> {
+            let mut checker = PermissionChecker::new();
+            for name in order {
+                checker.add_policy(PolicyBuilder::<TestDomain>::new(*name).build());
+                checker.add_policy(AlwaysDenyPolicy(name));
+            }
+            checker.add_policy(
+                PolicyBuilder::<TestDomain>::new("Suspended")
+                    .forbid()
+                    .subjects(|_subject| false)
+                    .build(),
+            );
+            checker.sort_policies_by_type();
+            checker
+        }
+
+        let forward = checker(&["Alpha", "Beta", "Gamma"]);
+        let backward = checker(&["Gamma", "Beta", "Alpha"]);
+        let (subject, resource) = (test_subject(), test_resource());
+        let forward = forward
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        let backward = backward
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+
+        assert_eq!(forward.trace().format(), backward.trace().format());
+        // The forbid stays ahead of allow-only policies, which sort by name.
+        forward.assert_granted_by("Alpha");
+        let Some(PolicyEvalResult::Combined { children, .. }) = forward.trace().root() else {
+            panic!("expected a combined root");
+        };
+        assert!(matches!(
+            &children[0],
+            PolicyEvalResult::NotApplicable { policy_type, .. } if policy_type == "Suspended"
+        ));
+    }
+    #[tokio::test]
+    async fn test_first_match_strategy_lets_the_first_matching_rule_decide() {
+        async fn decide(
+            checker: &PermissionChecker<TestDomain>,
+        ) -> (AccessEvaluation, AccessEvaluation) {
+            let session = EvaluationSession::empty();
+            let subject = test_subject();
+            let bound = checker.bind(&session, &subject, &TestAction, &TestContext);
+            let single = bound.check(&test_resource()).await;
+            let (_, batch) = bound.evaluate([test_resource()]).await.remove(0);
+            assert_eq!(single.is_granted(), batch.is_granted());
+            assert_eq!(single.trace().format(), batch.trace().format());
+            (single, batch)
+        }
+        fn block() -> impl Policy<TestDomain> {
+            PolicyBuilder::<TestDomain>::new("Block").forbid().build()
+        }
+        let strategy = |default| CombineStrategy::FirstMatch { default };
+
+        // An allow added before a forbid wins, and the forbid is never run.
+        let mut checker = PermissionChecker::with_strategy(strategy(Effect::Forbid));
+        checker.add_policy(AlwaysDenyPolicy("abstains"));
+        checker.add_policy(AlwaysAllowPolicy);
+        checker.add_policy(block());
+        let (evaluation, _) = decide(&checker).await;
+        evaluation.assert_granted_by("AlwaysAllowPolicy");
+        let Some(PolicyEvalResult::Combined {
+            operation,
+            children,
+            ..
+        }) = evaluation.trace().root()
+        else {
+            panic!("expected a combined root");
+        };
+        assert_eq!(*operation, CombineOp::FirstApplicable);
+        assert_eq!(children.len(), 2);
+
+        // Reversed, the forbid decides.
+        let mut checker = PermissionChecker::with_strategy(strategy(Effect::Allow));
+        checker.add_policy(block());
+        checker.add_policy(AlwaysAllowPolicy);
+        decide(&checker).await.0.assert_forbidden_by("Block");
+
+        // When every rule abstains, the default decides.
+        let mut checker = PermissionChecker::with_strategy(strategy(Effect::Allow));
+        checker.add_policy(AlwaysDenyPolicy("abstains"));
+        assert!(decide(&checker).await.0.is_granted());
+        checker.set_strategy(strategy(Effect::Forbid));
+        assert!(!decide(&checker).await.0.is_granted());
+
+        // An indeterminate rule stops evaluation and denies.
+        let mut checker = PermissionChecker::with_strategy(strategy(Effect::Allow));
+        checker.add_policy(UnavailablePolicy);
+        checker.add_policy(AlwaysAllowPolicy);
+        let (evaluation, _) = decide(&checker).await;
+        assert!(!evaluation.is_granted());
+        let Some(PolicyEvalResult::Combined { children, .. }) = evaluation.trace().root() else {
+            panic!("expected a combined root");
+        };
+        assert_eq!(children.len(), 1);
+        assert!(children[0].is_indeterminate());
+    }
+    #[derive(Default)]
+    struct InMemoryDecisionCache {
+        entries: Mutex<HashMap<String, PolicyEvalResult>>,
+    }
+
+    #[async_trait]
+    impl DecisionCache for InMemoryDecisionCache {
+        async fn get(&self, key: &str) -> Option<PolicyEvalResult> {
+            self.entries.lock().unwrap().get(key).cloned()
+        }
+
+        async fn put(&self, key: &str, result: &PolicyEvalResult, _ttl: std::time::Duration) {
+            self.entries
+                .lock()
+                .unwrap()
+                .insert(key.to_string(), result.clone());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_cached_decision_policy_reads_through_the_external_cache() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counted = calls.clone();
+        let cache = Arc::new(InMemoryDecisionCache::default());
+        let policy = CachedDecisionPolicy::new(
+            |subject: &TestSubject, _: &TestAction, resource: &TestResource, _: &TestContext| {
+                format!("{}:{}", subject.id, resource.id)
+            },
+            cache.clone(),
+            PolicyBuilder::<TestDomain>::new("Expensive")
+                .when(move |_, _, _, _| {
+                    counted.fetch_add(1, Ordering::SeqCst);
+                    true
+                })
+                .build(),
+        );
+        let session = EvaluationSession::new();
+        let (subject, resource) = (test_subject(), test_resource());
+
+        for _ in 0..3 {
+            policy
+                .check(&session, &subject, &TestAction, &resource, &TestContext)
+                .await
+                .assert_granted_by("Expensive");
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(cache.entries.lock().unwrap().len(), 1);
+        policy
+            .check(&session, &subject, &TestAction, &resource, &TestContext)
+            .await
+            .assert_trace_contains("(cached)");
+
+        // Indeterminate results are never stored.
+        let unavailable = CachedDecisionPolicy::new(
+            |_: &TestSubject, _: &TestAction, _: &TestResource, _: &TestContext| {
+                "unavailable".to_string()
+            },
+            cache.clone(),
+            UnavailablePolicy,
+        );
+        unavailable
+            .check(&session, &subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!cache.entries.lock().unwrap().contains_key("unavailable"));
+    }
+
+    #[test]
+    fn attribute_expr_treats_missing_and_mistyped_attributes_as_unknown() {
+        let attributes = Attributes::new()
+            .with("subject.department", "finance")
+            .with("resource.amount", 2_500_i64)
+            .with("resource.locked", false);
+        let finance = AttributeExpr::Eq("subject.department".into(), "finance".into());
+        let small = AttributeExpr::Lt("resource.amount".into(), 10_000.0);
+        let missing = AttributeExpr::Eq("subject.clearance".into(), "secret".into());
+        let mistyped = AttributeExpr::Gt("subject.department".into(), 1.0);
+
+        assert_eq!(finance.evaluate(&attributes), Some(true));
+        assert_eq!(small.evaluate(&attributes), Some(true));
+        assert_eq!(
+            AttributeExpr::In(
+                "subject.department".into(),
+                vec!["hr".into(), "legal".into()]
+            )
+            .evaluate(&attributes),
+            Some(false)
+        );
+        assert_eq!(missing.evaluate(&attributes), None);
+        assert_eq!(mistyped.evaluate(&attributes), None);
+        assert_eq!(
+            AttributeExpr::Not(Box::new(missing.clone())).evaluate(&attributes),
+            None
+        );
+        assert_eq!(
+            AttributeExpr::And(vec![
+                missing.clone(),
+                AttributeExpr::Not(Box::new(small.clone()))
+            ])
+            .evaluate(&attributes),
+            Some(false)
+        );
+        assert_eq!(
+            AttributeExpr::Or(vec![missing, finance.clone()]).evaluate(&attributes),
+            Some(true)
+        );
+        assert_eq!(
+            AttributeExpr::And(vec![finance, small]).to_string(),
+            r#"(subject.department == "finance" && resource.amount < 10000)"#
+        );
+    }
+
+    #[tokio::test]
+    async fn abac_policy_grants_only_when_the_expression_holds() {
+        let owner = test_subject();
+        let stranger = test_subject();
+        let resource = test_resource();
+        let owner_id = owner.id;
+        let mut checker = PermissionChecker::<TestDomain>::new();
+        checker.add_policy(AbacPolicy::<TestDomain, _>::from_expr(
+            AttributeExpr::Eq("subject.is_owner".into(), true.into()),
+            move |subject: &TestSubject, _: &TestAction, _: &TestResource, _: &TestContext| {
+                let attributes = Attributes::new();
+                if subject.id == owner_id {
+                    attributes.with("subject.is_owner", true)
+                } else {
+                    attributes
+                }
+            },
+        ));
+        let session = EvaluationSession::empty();
+
+        let granted = checker
+            .bind(&session, &owner, &TestAction, &TestContext)
+            .check(&resource)
+            .await;
+        granted.assert_granted_by("AbacPolicy");
+
+        let denied = checker
+            .bind(&session, &stranger, &TestAction, &TestContext)
+            .check(&resource)
+            .await;
+        denied.assert_trace_contains("Attribute expression is unknown");
+    }
+
+    #[test]
+    fn pruned_trace_keeps_only_branches_that_affected_the_decision() {
+        let combined = |policy_type: &'static str,
+                        operation: CombineOp,
+                        outcome: bool,
+                        children: Vec<PolicyEvalResult>| {
+            PolicyEvalResult::Combined {
+                policy_type: policy_type.into(),
+                policy_id: None,
+                operation,
+                children,
+                outcome,
+            }
+        };
+        let trace = EvalTrace::with_root(combined(
+            "PermissionChecker",
+            CombineOp::Or,
+            true,
+            vec![
+                PolicyEvalResult::not_applicable("InvoiceOnly", "resource is not an invoice"),
+                PolicyEvalResult::granted("Owner", Some("owns the document".into())),
+                combined(
+                    "AndPolicy",
+                    CombineOp::And,
+                    false,
+                    vec![
+                        PolicyEvalResult::granted("Staff", None),
+                        PolicyEvalResult::not_applicable("Reviewer", "not a reviewer"),
+                    ],
+                ),
+                combined(
+                    "NotPolicy",
+                    CombineOp::Not,
+                    true,
+                    vec![PolicyEvalResult::not_applicable("Archived", "not archived")],
+                ),
+                PolicyEvalResult::indeterminate("Quota", "quota service unavailable"),
+            ],
+        ));
+
+        let pruned = trace.pruned().format();
+        assert!(pruned.contains("Owner GRANTED"));
+        assert!(pruned.contains("Archived"));
+        assert!(pruned.contains("Quota"));
+        assert!(!pruned.contains("InvoiceOnly"));
+        assert!(!pruned.contains("AndPolicy"));
+        assert!(!pruned.contains("Reviewer"));
+        assert!(trace.format().contains("InvoiceOnly"));
+
+        let nothing_applied = EvalTrace::with_root(combined(
+            "PermissionChecker",
+            CombineOp::Or,
+            false,
+            vec![PolicyEvalResult::not_applicable(
+                "InvoiceOnly",
+                "not an invoice",
+            )],
+        ));
+        let pruned = nothing_applied.pruned();
+        let Some(PolicyEvalResult::Combined { children, .. }) = pruned.root() else {
+            panic!("root is kept");
+        };
+        assert!(children.is_empty());
+    }
+
+    #[tokio::test]
+    async fn async_abac_policy_awaits_its_condition() {
+        let owner = test_subject();
+        let resource = test_resource();
+        let shares = Arc::new(Mutex::new(HashMap::from([(owner.id, resource.id)])));
+        let lookups = Arc::clone(&shares);
+        let mut checker = PermissionChecker::<TestDomain>::new();
+        checker.add_policy(AsyncAbacPolicy::<TestDomain>::new(
+            "SharedWithSubject",
+            move |subject, _action, resource, _context| {
+                let shares = Arc::clone(&lookups);
+                let (subject_id, resource_id) = (subject.id, resource.id);
+                Box::pin(async move {
+                    tokio::task::yield_now().await;
+                    shares.lock().unwrap().get(&subject_id) == Some(&resource_id)
+                })
+            },
+        ));
+        let session = EvaluationSession::empty();
+
+        checker
+            .bind(&session, &owner, &TestAction, &TestContext)
+            .check(&resource)
+            .await
+            .assert_granted_by("SharedWithSubject");
+
+        shares.lock().unwrap().clear();
+        let denied = checker
+            .bind(&session, &owner, &TestAction, &TestContext)
+            .check(&resource)
+            .await;
+        denied.assert_not_applicable_by("SharedWithSubject");
+        denied.assert_trace_contains("Policy predicate did not match");
+    }
+    #[tokio::test]
+    async fn test_access_evaluation_stats_count_policies_run_before_the_decision() {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(AlwaysAllowPolicy);
+        checker.add_policy(AlwaysDenyPolicy("First"));
+        checker.add_policy(AlwaysDenyPolicy("Second"));
+        let subject = test_subject();
+        let resource = test_resource();
+        let session = EvaluationSession::empty();
+        let bound = checker.bind(&session, &subject, &TestAction, &TestContext);
+
+        let granted = bound.check(&resource).await;
+        assert_eq!(
+            granted.stats(),
+            Some(EvalStats {
+                evaluated: 1,
+                total: 3,
+                short_circuited: true,
+                memoized: false,
+            })
+        );
+
+        // Concurrent evaluation runs every policy.
+        let parallel = bound.check_parallel(&resource).await;
+        assert_eq!(parallel.stats().map(|stats| stats.evaluated), Some(3));
+        assert_eq!(
+            parallel.stats().map(|stats| stats.short_circuited),
+            Some(false)
+        );
+
+        let batch = bound.evaluate([resource.clone()]).await;
+        assert_eq!(batch[0].1.stats(), granted.stats());
+
+        let mut denying = PermissionChecker::new();
+        denying.add_policy(AlwaysDenyPolicy("First"));
+        denying.add_policy(AlwaysDenyPolicy("Second"));
+        let denied = denying
+            .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert_eq!(
+            denied.stats(),
+            Some(EvalStats {
+                evaluated: 2,
+                total: 2,
+                short_circuited: false,
+                memoized: false,
+            })
+        );
+
+        let unchecked = AccessEvaluation::Denied {
+            trace: EvalTrace::new(),
+            reason: "built by hand".to_string(),
+        };
+        assert_eq!(unchecked.stats(), None);
+    }
+    #[tokio::test]
+    async fn test_exhaustive_and_policy_reports_every_failing_child() {
+        let policies = || -> Vec<Arc<dyn Policy<TestDomain>>> {
+            vec![
+                Arc::new(AlwaysDenyPolicy("NeedsTitle")),
+                Arc::new(AlwaysAllowPolicy),
+                Arc::new(AlwaysDenyPolicy("NeedsBody")),
+            ]
+        };
+        let short_circuit = AndPolicy::try_new(policies()).unwrap();
+        let exhaustive = AndPolicy::try_new(policies()).unwrap().exhaustive();
+        let subject = test_subject();
+        let resource = test_resource();
+        let session = EvaluationSession::empty();
+        let ctx = EvalCtx {
+            session: &session,
+            subject: &subject,
+            action: &TestAction,
+            resource: &resource,
+            context: &TestContext,
+            policy_type: "AndPolicy".into(),
+        };
+
+        let child_count = |result: &PolicyEvalResult| match result {
+            PolicyEvalResult::Combined { children, .. } => children.len(),
+            _ => panic!("expected a combined result"),
+        };
+        let quick = short_circuit.evaluate(&ctx).await;
+        let full = exhaustive.evaluate(&ctx).await;
+        assert_eq!(quick.is_granted(), full.is_granted());
+        assert!(!full.is_granted());
+        assert_eq!(child_count(&quick), 1);
+        assert_eq!(child_count(&full), 3);
+        let trace = full.format(0);
+        assert!(trace.contains("NeedsTitle") && trace.contains("NeedsBody"));
+
+        let batch_ctx = BatchEvalCtx {
+            session: &session,
+            subject: &subject,
+            action: &TestAction,
+            context: &TestContext,
+            items: &[PolicyBatchItem {
+                resource: &resource,
+            }],
+            policy_type: "AndPolicy".into(),
+        };
+        let batch = exhaustive.evaluate_batch(&batch_ctx).await;
+        assert_eq!(child_count(&batch[0]), 3);
+        assert!(!batch[0].is_granted());
+    }
+
+    #[tokio::test]
+    async fn test_into_summary_flattens_grants_and_denials() {
+        let subject = test_subject();
+        let resource = test_resource();
+        let session = EvaluationSession::empty();
+
+        let mut granting = PermissionChecker::new();
+        granting.add_policy(AlwaysAllowPolicy);
+        let granted = granting
+            .bind(&session, &subject, &TestAction, &TestContext)
+            .check(&resource)
+            .await
+            .into_summary();
+        assert!(granted.allowed);
+        assert_eq!(granted.reason, "Always allow policy");
+        assert!(granted.obligations.is_empty());
+        assert!(granted.trace.format().contains("AlwaysAllowPolicy"));
+
+        let mut denying = PermissionChecker::new();
+        denying.add_policy(AlwaysDenyPolicy("Not today"));
+        let evaluation = denying
+            .bind(&session, &subject, &TestAction, &TestContext)
+            .check(&resource)
+            .await;
+        let reason = match &evaluation {
+            AccessEvaluation::Denied { reason, .. } => reason.clone(),
+            AccessEvaluation::Granted { .. } => panic!("expected a denial"),
+        };
+        let denied = DecisionSummary::from(evaluation);
+        assert!(!denied.allowed);
+        assert_eq!(denied.reason, reason);
+        assert!(denied.trace.format().contains("Not today"));
+    }
+
+    struct FixedScore(&'static str, f32);
+
+    #[async_trait]
+    impl ScoredPolicy<TestDomain> for FixedScore {
+        async fn score(&self, _ctx: &EvalCtx<'_, TestDomain>) -> f32 {
+            self.1
+        }
+
+        fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed(self.0)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_score_checker_grants_when_contributions_reach_the_threshold() {
+        let subject = test_subject();
+        let resource = test_resource();
+        let session = EvaluationSession::empty();
+
+        let mut checker = ScoreChecker::<TestDomain>::new(1.0);
+        checker.add_policy(FixedScore("KnownDevice", 0.5));
+        checker.add_policy(FixedScore("UsualLocation", 0.5));
+        let granted = checker
+            .evaluate(&session, &subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(granted.is_granted());
+        granted.assert_trace_contains("KnownDevice");
+        granted.assert_trace_contains("contributed 0.5");
+        assert!(granted.to_string().contains("score 1 reached threshold 1"));
+
+        checker.add_policy(FixedScore("NewCountry", -0.25));
+        let denied = checker
+            .evaluate(&session, &subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!denied.is_granted());
+        denied.assert_trace_contains("contributed -0.25");
+        assert!(denied
+            .to_string()
+            .contains("score 0.75 is below threshold 1"));
+        assert!(denied.trace().format().contains("SCORE"));
+
+        checker.add_policy(FixedScore("Broken", f32::NAN));
+        checker.add_policy(FixedScore("Override", 10.0));
+        let nan = checker
+            .evaluate(&session, &subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!nan.is_granted());
+
+        let empty = ScoreChecker::<TestDomain>::new(0.0)
+            .evaluate(&session, &subject, &TestAction, &resource, &TestContext)
+            .await;
+        assert!(!empty.is_granted());
+    }
+
+    #[tokio::test]
+    async fn test_schedule_policy_grants_inside_weekly_windows_at_an_offset() {
+        use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+        struct At(SystemTime);
+        impl ProvidesTime for At {
+            fn now(&self) -> SystemTime {
+                self.0
+            }
+        }
+
+        struct ScheduleDomain;
+        impl PolicyDomain for ScheduleDomain {
+            type Subject = ();
+            type Action = ();
+            type Resource = ();
+            type Context = At;
+        }
+
+        // 2024-01-01T00:00:00Z, a Monday.
+        let monday = UNIX_EPOCH + Duration::from_secs(1_704_067_200);
+        let hour = Duration::from_secs(3600);
+        let business_hours = || vec![ScheduleWindow::new(Weekday::WEEKDAYS, (9, 0), (17, 0))];
+        let check = |checker: PermissionChecker<ScheduleDomain>, at: SystemTime| async move {
+            let session = EvaluationSession::empty();
+            checker.bind(&session, &(), &(), &At(at)).check(&()).await
+        };
+
+        let mut brisbane = PermissionChecker::<ScheduleDomain>::new();
+        brisbane.add_policy(SchedulePolicy::new(business_hours(), 600));
+
+        // 10:00 on Monday in UTC+10:00; the grant lasts until 17:00 local.
+        let open = check(brisbane.clone(), monday).await;
+        assert!(open.is_granted(), "{}", open.display_trace());
+        assert_eq!(open.valid_until(), Some(monday + 7 * hour));
+
+        // 10:00 on Saturday.
+        let weekend = check(brisbane.clone(), monday + 5 * 24 * hour).await;
+        weekend.assert_not_applicable_by("SchedulePolicy");
+        weekend.assert_trace_contains("access is next permitted Monday 09:00 (UTC+10:00)");
+
+        // 17:00 on Friday, the end of the window, is outside it.
+        let closing = check(brisbane, monday + (4 * 24 + 7) * hour).await;
+        closing.assert_trace_contains("next permitted Monday 09:00 (UTC+10:00)");
+
+        // 19:00 on the previous Sunday in UTC-05:00.
+        let mut new_york = PermissionChecker::<ScheduleDomain>::new();
+        new_york.add_policy(SchedulePolicy::new(business_hours(), -300));
+        let sunday = check(new_york, monday).await;
+        assert!(!sunday.is_granted());
+        sunday.assert_trace_contains("next permitted Monday 09:00 (UTC-05:00)");
+    }
+
+    #[tokio::test]
+    async fn test_cached_source_reuses_relationships_across_sessions() {
+        let subject_id = uuid::Uuid::new_v4();
+        let resource_id = uuid::Uuid::new_v4();
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let lookups = Arc::new(Mutex::new(Vec::new()));
+        let recorded = lookups.clone();
+        let registry = FactRegistry::builder()
+            .with::<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>, _>(
+                CachedSource::new(
+                    TestRelationshipSource {
+                        grants: HashSet::from([RelationshipQuery {
+                            subject_id,
+                            resource_id,
+                            relation: "owner".to_string(),
+                        }]),
+                        batch_sizes: batches.clone(),
+                        max_batch_size: NonZeroUsize::new(8),
+                    },
+                    16,
+                    std::time::Duration::from_secs(60),
+                )
+                .with_observer(Arc::new(
+                    move |fact: &'static str, hits, misses| {
+                        recorded.lock().unwrap().push((fact, hits, misses));
+                    },
+                )),
+            )
+            .build();
+        let subject = TestSubject { id: subject_id };
+        let resource = TestResource { id: resource_id };
+
+        for _ in 0..2 {
+            let session = registry.session();
+            let ctx = EvalCtx {
+                session: &session,
+                subject: &subject,
+                action: &TestAction,
+                resource: &resource,
+                context: &TestContext,
+                policy_type: std::borrow::Cow::Borrowed("TestPolicy"),
+            };
+            assert!(relationship_policy("owner".to_string())
+                .evaluate(&ctx)
+                .await
+                .is_granted());
+        }
+
+        // The second session is answered from the cache.
+        assert_eq!(*batches.lock().unwrap(), vec![1]);
+        let name = RelationshipQuery::<uuid::Uuid, uuid::Uuid, String>::NAME;
+        assert_eq!(*lookups.lock().unwrap(), vec![(name, 0, 1), (name, 1, 0)]);
+    }
+
+    #[tokio::test]
+    async fn test_permission_checker_builder_assembles_a_configured_checker() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let observed = Arc::new(AtomicUsize::new(0));
+        let counter = observed.clone();
+        let checker = PermissionChecker::<TestDomain>::builder()
+            .name("BuiltChecker")
+            .strategy(CombineStrategy::AllAllow)
+            .observer(Arc::new(
+                move |_: &str, _: &PolicyEvalResult, _: std::time::Duration| {
+                    counter.fetch_add(1, Ordering::SeqCst);
+                },
+            ))
+            .policy(AlwaysAllowPolicy)
+            .policy(AlwaysDenyPolicy("Second"))
+            .build();
+        assert_eq!(checker.name(), Some("BuiltChecker"));
+        assert_eq!(checker.strategy(), CombineStrategy::AllAllow);
+        assert_eq!(checker.len(), 2);
+
+        let session = EvaluationSession::empty();
+        let evaluation = checker
+            .bind(&session, &test_subject(), &TestAction, &TestContext)
+            .check(&test_resource())
+            .await;
+        // AllAllow needs every policy to grant.
+        assert!(!evaluation.is_granted());
+        assert_eq!(observed.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_in_memory_relationship_source_answers_added_relationships() {
+        let subject_id = uuid::Uuid::new_v4();
+        let resource_id = uuid::Uuid::new_v4();
+        let source =
+            || InMemoryRelationshipSource::new().add(subject_id, resource_id, "owner".to_string());
+        let subject = TestSubject { id: subject_id };
+        let resource = TestResource { id: resource_id };
+
+        let session = FactRegistry::builder()
+            .with::<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>, _>(source())
+            .build()
+            .session();
+        let ctx = EvalCtx {
+            session: &session,
+            subject: &subject,
+            action: &TestAction,
+            resource: &resource,
+            context: &TestContext,
+            policy_type: std::borrow::Cow::Borrowed("TestPolicy"),
+        };
+        assert!(relationship_policy("owner".to_string())
+            .evaluate(&ctx)
+            .await
+            .is_granted());
+        let viewer = relationship_policy("viewer".to_string())
+            .evaluate(&ctx)
+            .await;
+        assert!(!viewer.is_granted());
+        assert!(!viewer.is_indeterminate());
+
+        let failing = FactRegistry::builder()
+            .with::<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>, _>(
+                source().with_error("relationship store is down"),
+            )
+            .build()
+            .session();
+        let ctx = EvalCtx {
+            session: &failing,
+            ..ctx
+        };
+        let owner = relationship_policy("owner".to_string())
+            .evaluate(&ctx)
+            .await;
+        assert!(!owner.is_granted());
+        assert!(owner.is_indeterminate());
+    }
+
+    #[tokio::test]
+    async fn test_chained_and_or_flatten_into_one_level() {
+        let and = AlwaysAllowPolicy
+            .and(AlwaysAllowPolicy)
+            .and(AlwaysDenyPolicy("Third"));
+        let result = and
+            .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+            .await;
+        let PolicyEvalResult::Combined {
+            operation,
+            children,
+            outcome,
+            ..
+        } = &result
+        else {
+            panic!("expected a combined result, got {result:?}");
+        };
+        assert_eq!(*operation, CombineOp::And);
+        assert_eq!(children.len(), 3);
+        assert!(!outcome);
+        assert!(children
+            .iter()
+            .all(|child| !matches!(child, PolicyEvalResult::Combined { .. })));
+
+        let or = AlwaysDenyPolicy("First").or(AlwaysDenyPolicy("Second").or(AlwaysAllowPolicy));
+        let composition = or.composition().expect("OrPolicy has a composition");
+        assert_eq!(composition.operation, CombineOp::Or);
+        assert_eq!(composition.children.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_chained_and_keeps_identified_and_mixed_operands_nested() {
+        let identified = AlwaysAllowPolicy
+            .and(AlwaysAllowPolicy)
+            .with_id("inner")
+            .and(AlwaysAllowPolicy);
+        let composition = identified
+            .composition()
+            .expect("AndPolicy has a composition");
+        assert_eq!(composition.children.len(), 2);
+
+        let mixed = AlwaysAllowPolicy
+            .or(AlwaysDenyPolicy("Other"))
+            .and(AlwaysAllowPolicy);
+        let result = mixed
+            .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+            .await;
+        assert!(result.is_granted());
+        let PolicyEvalResult::Combined { children, .. } = &result else {
+            panic!("expected a combined result, got {result:?}");
+        };
+        assert_eq!(children.len(), 2);
+        assert!(children.iter().any(|child| matches!(
+            child,
+            PolicyEvalResult::Combined {
+                operation: CombineOp::Or,
+                ..
+            }
+        )));
+    }
+
+    #[test]
+    fn test_trace_visitor_brackets_combinators_around_their_children() {
+        #[derive(Default)]
+        struct Html(String);
+
+        impl TraceVisitor for Html {
+            fn visit_leaf(&mut self, depth: usize, leaf: &PolicyEvalResult) {
+                self.0.push_str(&format!(
+                    "<li data-depth={depth}>{}</li>",
+                    leaf.is_granted()
+                ));
+            }
+
+            fn enter_combined(&mut self, _depth: usize, _node: &PolicyEvalResult) {
+                self.0.push_str("<ul>");
+            }
+
+            fn leave_combined(&mut self, _depth: usize, _node: &PolicyEvalResult) {
+                self.0.push_str("</ul>");
+            }
+        }
+
+        let tree = PolicyEvalResult::Combined {
+            policy_type: "OrPolicy".into(),
+            policy_id: None,
+            operation: CombineOp::Or,
+            children: vec![
+                PolicyEvalResult::not_applicable("Owner", "not the owner"),
+                PolicyEvalResult::Combined {
+                    policy_type: "NotPolicy".into(),
+                    policy_id: None,
+                    operation: CombineOp::Not,
+                    children: vec![PolicyEvalResult::not_applicable("Locked", "unlocked")],
+                    outcome: true,
+                },
+            ],
+            outcome: true,
+        };
+
+        let mut html = Html::default();
+        tree.accept(&mut html);
+        assert_eq!(
+            html.0,
+            "<ul><li data-depth=1>false</li><ul><li data-depth=2>false</li></ul></ul>"
+        );
+
+        let mut visited = Vec::new();
+        tree.walk(&mut |depth, node| visited.push((depth, node.is_granted())));
+        assert_eq!(visited, [(0, true), (1, false), (1, true), (2, false)]);
+    }
+
+    #[test]
+    fn test_format_with_applies_symbols_indent_depth_reasons_and_colors() {
+        let trace = EvalTrace::with_root(PolicyEvalResult::Combined {
+            policy_type: "AndPolicy".into(),
+            policy_id: None,
+            operation: CombineOp::And,
+            children: vec![
+                PolicyEvalResult::granted("Admin", Some("is admin".into())),
+                PolicyEvalResult::Combined {
+                    policy_type: "OrPolicy".into(),
+                    policy_id: None,
+                    operation: CombineOp::Or,
+                    children: vec![PolicyEvalResult::forbidden("Suspended", "suspended")],
+                    outcome: false,
+                },
+            ],
+            outcome: false,
+        });
+
+        assert_eq!(trace.format_with(&FormatOptions::new()), trace.format());
+        assert_eq!(
+            trace.format_with(&FormatOptions::new().with_symbols(TraceSymbols::Ascii)),
+            "[-] AndPolicy (AND)\n  [+] Admin GRANTED: is admin\n  [-] OrPolicy (OR)\n    [!] Suspended FORBIDDEN: suspended"
+        );
+        assert_eq!(
+            trace.format_with(
+                &FormatOptions::new()
+                    .with_indent(4)
+                    .with_max_depth(1)
+                    .with_reasons(false)
+            ),
+            "✘ AndPolicy (AND)\n    ✔ Admin GRANTED\n    ✘ OrPolicy (OR)\n        …"
+        );
+        assert_eq!(
+            trace.format_with(
+                &FormatOptions::new()
+                    .with_symbols(TraceSymbols::Ascii)
+                    .with_max_depth(0)
+                    .with_colors(true)
+            ),
+            "\x1b[2m[-] AndPolicy (AND)\x1b[0m\n  ..."
+        );
+    }
+
+    #[tokio::test]
+    async fn test_add_forbid_policy_discards_memoized_grants() {
+        struct Hashable;
+
+        impl PolicyDomain for Hashable {
+            type Subject = u8;
+            type Action = ();
+            type Resource = u8;
+            type Context = ();
+        }
+
+        let mut checker = PermissionChecker::<Hashable>::new();
+        checker.add_policy(PolicyBuilder::<Hashable>::new("Everyone").build());
+        let mut checker = checker.with_memoization(8, std::time::Duration::from_secs(60));
+        let session = EvaluationSession::empty();
+
+        assert!(checker
+            .bind(&session, &1, &(), &())
+            .check(&1)
+            .await
+            .is_granted());
+        checker.add_forbid_policy(PolicyBuilder::<Hashable>::new("Suspended").forbid().build());
+
+        let evaluation = checker.bind(&session, &1, &(), &()).check(&1).await;
+        evaluation.assert_forbidden_by("Suspended");
+    }
+
+    #[tokio::test]
+    async fn test_xor_policy_denies_when_a_child_is_indeterminate() {
+        for mode in [XorMode::ExactlyOne, XorMode::Odd] {
+            let policy = XorPolicy::try_new(
+                mode,
+                vec![Arc::new(AlwaysAllowPolicy), Arc::new(UnavailablePolicy)],
+            )
+            .expect("Unable to create xor policy");
+            let result = policy
+                .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+                .await;
+            assert!(!result.is_granted(), "{mode:?} granted: {result}");
+            assert!(result.is_indeterminate());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_at_most_policy_denies_at_the_limit_when_a_child_is_indeterminate() {
+        let subject = test_subject();
+        let resource = test_resource();
+        let batch_items = [PolicyBatchItem {
+            resource: &resource,
+        }];
+        for (max_grants, expected) in [(1, false), (2, true)] {
+            let policy = at_most(
+                max_grants,
+                vec![Arc::new(AlwaysAllowPolicy), Arc::new(UnavailablePolicy)],
+            );
+            let interpreted = policy
+                .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+                .await;
+            assert_eq!(interpreted.is_granted(), expected, "{interpreted}");
+            assert_eq!(interpreted.is_indeterminate(), !expected);
+
+            let batch = policy
+                .evaluate_access_batch(&subject, &TestAction, &TestContext, &batch_items)
+                .await;
+            assert_eq!(format!("{:?}", batch[0]), format!("{interpreted:?}"));
+
+            let compiled = policy
+                .compile()
+                .evaluate_access(&subject, &TestAction, &resource, &TestContext)
+                .await;
+            assert_eq!(format!("{compiled:?}"), format!("{interpreted:?}"));
+        }
+    }
+
+    fn sparse_relationship_session(
+        subject_id: uuid::Uuid,
+        granted: uuid::Uuid,
+        batch_sizes: &Arc<Mutex<Vec<usize>>>,
+    ) -> EvaluationSession {
+        FactRegistry::builder()
+            .with::<RelationshipQuery<uuid::Uuid, uuid::Uuid, String>, _>(TestRelationshipSource {
+                grants: HashSet::from([RelationshipQuery {
+                    subject_id,
+                    resource_id: granted,
+                    relation: "viewer".to_string(),
+                }]),
+                batch_sizes: Arc::clone(batch_sizes),
+                max_batch_size: None,
+            })
+            .build()
+            .session()
+    }
+
+    #[tokio::test]
+    async fn test_filter_take_loads_facts_in_growing_rounds_when_grants_are_sparse() {
+        let subject = test_subject();
+        let resources = (0..64)
+            .map(|value| TestResource {
+                id: uuid::Uuid::from_u128(value),
+            })
+            .collect::<Vec<_>>();
+        let batch_sizes = Arc::new(Mutex::new(Vec::new()));
+        let session = sparse_relationship_session(subject.id, resources[63].id, &batch_sizes);
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(relationship_policy("viewer".to_string()));
+
+        let taken = checker
+            .bind(&session, &subject, &TestAction, &TestContext)
+            .filter_take(&resources, 1)
+            .await;
+
+        assert_eq!(taken.len(), 1);
+        assert_eq!(taken[0].id, resources[63].id);
+        assert_eq!(*batch_sizes.lock().unwrap(), vec![1, 2, 4, 8, 16, 32, 1]);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test]
+    async fn test_evaluate_with_deadline_loads_facts_in_growing_rounds_by_default() {
+        let subject = test_subject();
+        let resources = (0..6)
+            .map(|value| TestResource {
+                id: uuid::Uuid::from_u128(value),
+            })
+            .collect::<Vec<_>>();
+        let batch_sizes = Arc::new(Mutex::new(Vec::new()));
+        let session = sparse_relationship_session(subject.id, resources[0].id, &batch_sizes);
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(relationship_policy("viewer".to_string()));
+        let deadline = tokio::time::Instant::now() + std::time::Duration::from_secs(60);
+
+        let results = checker
+            .bind(&session, &subject, &TestAction, &TestContext)
+            .evaluate_with_deadline(&resources, deadline)
+            .await;
+
+        assert!(results[0].1.is_granted());
+        assert!(results[1..]
+            .iter()
+            .all(|(_, evaluation)| !evaluation.is_granted()));
+        assert_eq!(*batch_sizes.lock().unwrap(), vec![1, 2, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_memoized_evaluation_stats_report_no_policies_evaluated() {
+        struct Hashable;
+
+        impl PolicyDomain for Hashable {
+            type Subject = u8;
+            type Action = ();
+            type Resource = u8;
+            type Context = ();
+        }
+
+        let mut checker = PermissionChecker::<Hashable>::new();
+        checker.add_policy(PolicyBuilder::<Hashable>::new("First").build());
+        checker.add_policy(PolicyBuilder::<Hashable>::new("Second").build());
+        let checker = checker.with_memoization(8, std::time::Duration::from_secs(60));
+        let session = EvaluationSession::empty();
+        let bound = checker.bind(&session, &1, &(), &());
+
+        assert_eq!(
+            bound.check(&1).await.stats(),
+            Some(EvalStats {
+                evaluated: 1,
+                total: 2,
+                short_circuited: true,
+                memoized: false,
+            })
+        );
+        assert_eq!(
+            bound.check(&1).await.stats(),
+            Some(EvalStats {
+                evaluated: 0,
+                total: 2,
+                short_circuited: false,
+                memoized: true,
+            })
+        );
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_ambient_deadline_bounds_batch_evaluation() {
+        let mut checker =
+            PermissionChecker::new().with_max_batch_size(NonZeroUsize::new(2).unwrap());
+        checker.add_policy(SlowAllowPolicy);
+        let subject = test_subject();
+        let resources = (0..4)
+            .map(|value| TestResource {
+                id: uuid::Uuid::from_u128(value),
+            })
+            .collect::<Vec<_>>();
+        let session = EvaluationSession::empty();
+        let bound = checker.bind(&session, &subject, &TestAction, &TestContext);
+        let in_ms = |ms| tokio::time::Instant::now() + std::time::Duration::from_millis(ms);
+
+        // The first chunk of two finishes at 20ms; the deadline interrupts
+        // the second.
+        let results = with_deadline(in_ms(25), bound.evaluate(&resources)).await;
+
+        assert_eq!(results.len(), resources.len());
+        for (index, (resource, evaluation)) in results.iter().enumerate() {
+            assert_eq!(resource.id, resources[index].id);
+            if index < 2 {
+                evaluation.assert_granted_by("SlowAllowPolicy");
+            } else {
+                evaluation.assert_denied_with_reason_containing(
+                    "deadline exceeded: 0 of 1 policies evaluated, 1 skipped",
+                );
+                assert!(evaluation
+                    .trace()
+                    .root()
+                    .is_some_and(PolicyEvalResult::is_indeterminate));
+            }
+        }
+
+        assert!(with_deadline(in_ms(25), bound.filter(&resources))
+            .await
+            .iter()
+            .map(|resource| resource.id)
+            .eq(resources[..2].iter().map(|resource| resource.id)));
+    }
+
+    #[cfg(feature = "tokio")]
+    #[tokio::test(start_paused = true)]
+    async fn test_ambient_deadline_gives_batch_and_single_checks_the_same_decision() {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(AsyncAbacPolicy::<TestDomain>::new(
+            "SlowLookup",
+            |_, _, _, _| {
+                Box::pin(async {
+                    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+                    false
+                })
+            },
+        ));
+        checker.add_policy(AlwaysDenyPolicy("Second"));
+        checker.add_policy(AlwaysAllowPolicy);
+        let subject = test_subject();
+        let resource = test_resource();
+        let session = EvaluationSession::empty();
+        let bound = checker.bind(&session, &subject, &TestAction, &TestContext);
+        let in_ms = |ms| tokio::time::Instant::now() + std::time::Duration::from_millis(ms);
+
+        let single = with_deadline(in_ms(10), bound.check(&resource)).await;
+        let batch = with_deadline(in_ms(10), bound.evaluate([&resource])).await;
+
+        let (_, batched) = &batch[0];
+        batched.assert_denied_with_reason_containing(
+            "deadline exceeded: 1 of 3 policies evaluated, 2 skipped",
+        );
+        assert_eq!(batched.decision(), single.decision());
+        assert_eq!(batched.stats(), single.stats());
+    }
+
+    #[tokio::test]
+    async fn test_compiled_policy_keeps_combinator_ids_in_traces() {
+        let tree = || {
+            AlwaysAllowPolicy
+                .and(NotPolicy::new(AlwaysDenyPolicy("Inner")).with_id("not-denied"))
+                .with_id("both")
+        };
+        let interpreted = tree();
+        let compiled = tree().compile();
+        assert_eq!(compiled.combinator_count(), 2);
+
+        let expected = interpreted
+            .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+            .await;
+        let actual = compiled
+            .evaluate_access(&test_subject(), &TestAction, &test_resource(), &TestContext)
+            .await;
+
+        assert_eq!(actual.policy_id(), Some("both"));
+        assert_eq!(format!("{actual:?}"), format!("{expected:?}"));
+    }
+}
+
+mod policy_builder_tests {
+    use super::*;
+    use std::future::Future;
+    use std::pin::Pin;
+    use uuid::Uuid;
+
+    trait PolicyBoxExt<D>
+    where
+        D: PolicyDomain,
+    {
+        fn evaluate_access<'a>(
+            &'a self,
+            subject: &'a D::Subject,
+            action: &'a D::Action,
+            resource: &'a D::Resource,
+            context: &'a D::Context,
+        ) -> Pin<Box<dyn Future<Output = PolicyEvalResult> + Send + 'a>>;
+    }
+
+    impl<D> PolicyBoxExt<D> for Box<dyn Policy<D>>
+    where
+        D: PolicyDomain,
+    {
+        fn evaluate_access<'a>(
+            &'a self,
+            subject: &'a D::Subject,
+            action: &'a D::Action,
+            resource: &'a D::Resource,
+            context: &'a D::Context,
+        ) -> Pin<Box<dyn Future<Output = PolicyEvalResult> + Send + 'a>> {
+            Box::pin(async move {
+                let session = EvaluationSession::new();
+                let policy_type = self.policy_type();
+                let ctx = EvalCtx {
+                    session: &session,
+                    subject,
+                    action,
+                    resource,
+                    context,
+                    policy_type,
+                };
+                self.evaluate(&ctx).await
+            })
+        }
+    }
+
+    // Define simple test types
+    #[derive(Debug, Clone)]
+    struct TestSubject {
+        pub name: String,
+    }
+    #[derive(Debug, Clone)]
+    struct TestAction;
+    #[derive(Debug, Clone)]
+    struct TestResource;
+    #[derive(Debug, Clone)]
+    struct TestContext;
+
+    struct TestDomain;
+
+    impl PolicyDomain for TestDomain {
+        type Subject = TestSubject;
+        type Action = TestAction;
+        type Resource = TestResource;
+        type Context = TestContext;
+    }
+
+    // Test that with no predicates the builder returns a policy that always "matches"
+    #[tokio::test]
+    async fn test_policy_builder_allows_when_no_predicates() {
+        let policy = PolicyBuilder::<TestDomain>::new("NoPredicatesPolicy").build();
+
+        let result = policy
+            .evaluate_access(
+                &TestSubject { name: "Any".into() },
+                &TestAction,
+                &TestResource,
+                &TestContext,
+            )
+            .await;
+        assert!(
+            result.is_granted(),
+            "Policy built with no predicates should allow access (default true)"
+        );
+    }
+
+    // Test that a subject predicate is applied correctly.
+    #[tokio::test]
+    async fn test_policy_builder_with_subject_predicate() {
+        let policy = PolicyBuilder::<TestDomain>::new("SubjectPolicy")
+            .subjects(|s: &TestSubject| s.name == "Alice")
+            .build();
+
+        // Should allow if the subject's name is "Alice"
+        let result1 = policy
+            .evaluate_access(
+                &TestSubject {
+                    name: "Alice".into(),
+                },
+                &TestAction,
+                &TestResource,
+                &TestContext,
+            )
+            .await;
+        assert!(
+            result1.is_granted(),
+            "Policy should allow access for subject 'Alice'"
+        );
+
+        // Otherwise, it should not apply.
+        let result2 = policy
+            .evaluate_access(
+                &TestSubject { name: "Bob".into() },
+                &TestAction,
+                &TestResource,
+                &TestContext,
+            )
+            .await;
+        assert!(
+            !result2.is_granted(),
+            "Policy should not grant access for subject not named 'Alice'"
+        );
+    }
+
+    // Test that `.forbid()` turns an otherwise matching predicate into an active veto.
+    #[tokio::test]
+    async fn test_policy_builder_forbid() {
+        let policy = PolicyBuilder::<TestDomain>::new("ForbidPolicy")
+            .forbid()
+            .build();
+
+        // Even though no predicate fails (so predicate returns true),
         // the forbid effect should result in a Denied outcome.
         let result = policy
             .evaluate_access(
@@ -3477,6 +6341,31 @@ mod policy_builder_tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_policy_builder_sets_policy_id_from_its_name() {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(
+            PolicyBuilder::<TestDomain>::new("AlicePolicy")
+                .subjects(|subject: &TestSubject| subject.name == "Alice")
+                .build(),
+        );
+        let session = EvaluationSession::empty();
+
+        for name in ["Alice", "Bob"] {
+            let subject = TestSubject { name: name.into() };
+            let bound = checker.bind(&session, &subject, &TestAction, &TestContext);
+            let single = bound.check(&TestResource).await;
+            let batch = bound.evaluate([TestResource]).await;
+            for evaluation in [&single, &batch[0].1] {
+                let Some(PolicyEvalResult::Combined { children, .. }) = evaluation.trace().root()
+                else {
+                    panic!("expected a root node, got:\n{}", evaluation.display_trace());
+                };
+                assert_eq!(children[0].policy_id(), Some("AlicePolicy"));
+            }
+        }
+    }
+
     /// A non-matching `Effect::Forbid` policy contributes nothing: the allow
     /// set still decides, and the trace root reflects deny-overrides.
     #[tokio::test]
@@ -3859,6 +6748,110 @@ mod policy_builder_tests {
         assert!(!result.is_granted(), "Policy should deny wrong context");
     }
 
+    #[tokio::test]
+    async fn test_policy_builder_and_when_or_when_name_the_unmet_condition() {
+        let policy = PolicyBuilder::<TestDomain>::new("NamedPolicy")
+            .and_when("StartsWithA", |s: &TestSubject, _, _, _| {
+                s.name.starts_with('A')
+            })
+            .and_when("ShortName", |s: &TestSubject, _, _, _| s.name.len() <= 5)
+            .or_when("IsRoot", |s: &TestSubject, _, _, _| s.name == "root")
+            .build();
+        let evaluate = |name: &str| {
+            let subject = TestSubject { name: name.into() };
+            let policy = &policy;
+            async move {
+                policy
+                    .evaluate_access(&subject, &TestAction, &TestResource, &TestContext)
+                    .await
+            }
+        };
+
+        let result = evaluate("Alice").await;
+        assert!(result.is_granted());
+        assert_eq!(
+            result.reason().as_deref(),
+            Some("Policy allowed access; conditions met: StartsWithA, ShortName")
+        );
+
+        let result = evaluate("root").await;
+        assert!(result.is_granted());
+        assert_eq!(
+            result.reason().as_deref(),
+            Some("Policy allowed access; conditions met: IsRoot")
+        );
+
+        let result = evaluate("Alexandra").await;
+        assert!(!result.is_granted());
+        assert_eq!(
+            result.reason().as_deref(),
+            Some("No alternative matched; conditions not met: ShortName, IsRoot")
+        );
+
+        let single = PolicyBuilder::<TestDomain>::new("SinglePolicy")
+            .and_when("StartsWithA", |s: &TestSubject, _, _, _| {
+                s.name.starts_with('A')
+            })
+            .forbid()
+            .build();
+        let subject = TestSubject { name: "Bob".into() };
+        let result = single
+            .evaluate_access(&subject, &TestAction, &TestResource, &TestContext)
+            .await;
+        assert_eq!(
+            result.reason().as_deref(),
+            Some("Condition not met: StartsWithA")
+        );
+        let subject = TestSubject { name: "Ann".into() };
+        let result = single
+            .evaluate_access(&subject, &TestAction, &TestResource, &TestContext)
+            .await;
+        assert!(result.is_forbidden());
+    }
+
+    #[tokio::test]
+    async fn test_policy_builder_require_fresh_auth_denies_stale_sessions() {
+        let fifteen_minutes = std::time::Duration::from_secs(15 * 60);
+        let build = |authed_ago: std::time::Duration| {
+            PolicyBuilder::<TestDomain>::new("AliceOnly")
+                .subjects(|s: &TestSubject| s.name == "Alice")
+                .require_fresh_auth(fifteen_minutes, move |_, _| {
+                    Some(std::time::SystemTime::now() - authed_ago)
+                })
+                .build()
+        };
+        let alice = TestSubject {
+            name: "Alice".into(),
+        };
+
+        let fresh = build(std::time::Duration::from_secs(60))
+            .evaluate_access(&alice, &TestAction, &TestResource, &TestContext)
+            .await;
+        assert!(fresh.is_granted());
+
+        let stale = build(std::time::Duration::from_secs(20 * 60))
+            .evaluate_access(&alice, &TestAction, &TestResource, &TestContext)
+            .await;
+        assert!(!stale.is_granted());
+        let trace = stale.format(0);
+        assert!(trace.contains("✔ AliceOnly GRANTED"), "{trace}");
+        assert!(
+            trace.contains(
+                "✘ FreshAuth NOT_APPLICABLE: Authenticated 1200s ago; re-authentication required after 900s"
+            ),
+            "{trace}"
+        );
+
+        let bob = TestSubject { name: "Bob".into() };
+        let unmatched = build(std::time::Duration::from_secs(20 * 60))
+            .evaluate_access(&bob, &TestAction, &TestResource, &TestContext)
+            .await;
+        assert_eq!(
+            unmatched.reason().as_deref(),
+            Some("Policy predicate did not match")
+        );
+    }
+
     // ----- per-axis batch shortcut --------------------------------------
 
     use crate::{BatchEvalCtx, PolicyBatchItem};
@@ -4002,6 +6995,102 @@ mod policy_builder_tests {
         );
     }
 
+    #[tokio::test]
+    async fn deny_reason_callback_names_each_denied_item() {
+        let policy = PolicyBuilder::<BatchDomain>::new("ReportsOnly")
+            .resources(|r: &BatchResource| r.category == "report")
+            .deny_reason(|s: &BatchSubject, _, r: &BatchResource, _| {
+                format!("{} cannot open {}", s.role, r.category)
+            })
+            .build();
+        let unreasoned = PolicyBuilder::<BatchDomain>::new("ReportsOnly")
+            .resources(|r: &BatchResource| r.category == "report")
+            .build();
+
+        let staff = BatchSubject {
+            role: "staff".into(),
+        };
+        let resources = ["report", "invoice"].map(|category| BatchResource {
+            category: category.into(),
+        });
+        let items = make_items(&resources);
+        let session = EvaluationSession::new();
+        let bctx = batch_ctx(&session, &staff, &BatchAction, &BatchContext, &items);
+
+        let results = policy.evaluate_batch(&bctx).await;
+        assert!(results[0].is_granted());
+        assert_eq!(
+            results[0].reason().as_deref(),
+            Some("Policy allowed access")
+        );
+        assert_eq!(
+            results[1].reason().as_deref(),
+            Some("staff cannot open invoice")
+        );
+
+        let results = unreasoned.evaluate_batch(&bctx).await;
+        assert_eq!(
+            results[1].reason().as_deref(),
+            Some("Policy predicate did not match")
+        );
+
+        let guest_only = PolicyBuilder::<BatchDomain>::new("GuestsOnly")
+            .subjects(|s: &BatchSubject| s.role == "guest")
+            .deny_reason(|s: &BatchSubject, _, r: &BatchResource, _| {
+                format!("{} is not a guest for {}", s.role, r.category)
+            })
+            .build();
+        let results = guest_only.evaluate_batch(&bctx).await;
+        assert_eq!(
+            results[0].reason().as_deref(),
+            Some("staff is not a guest for report")
+        );
+        assert_eq!(
+            results[1].reason().as_deref(),
+            Some("staff is not a guest for invoice")
+        );
+    }
+
+    #[tokio::test]
+    async fn applies_when_guard_abstains_before_other_predicates() {
+        let policy = PolicyBuilder::<BatchDomain>::new("StaffReports")
+            .applies_when(|_, _, r: &BatchResource, _| r.category != "invoice")
+            .subjects(|s: &BatchSubject| s.role == "staff")
+            .resources(|r: &BatchResource| r.category == "report")
+            .deny_reason(|_, _, r: &BatchResource, _| format!("cannot open {}", r.category))
+            .build();
+
+        let staff = BatchSubject {
+            role: "staff".into(),
+        };
+        let resources = ["report", "invoice", "memo"].map(|category| BatchResource {
+            category: category.into(),
+        });
+        let items = make_items(&resources);
+        let session = EvaluationSession::new();
+        let bctx = batch_ctx(&session, &staff, &BatchAction, &BatchContext, &items);
+
+        let results = policy.evaluate_batch(&bctx).await;
+        assert!(results[0].is_granted());
+        assert!(matches!(results[1], PolicyEvalResult::NotApplicable { .. }));
+        assert_eq!(
+            results[1].reason().as_deref(),
+            Some("Policy does not apply to this request")
+        );
+        assert_eq!(results[2].reason().as_deref(), Some("cannot open memo"));
+
+        let guest = BatchSubject {
+            role: "guest".into(),
+        };
+        let bctx = batch_ctx(&session, &guest, &BatchAction, &BatchContext, &items);
+        let results = policy.evaluate_batch(&bctx).await;
+        assert_eq!(
+            results[1].reason().as_deref(),
+            Some("Policy does not apply to this request")
+        );
+        assert_eq!(results[0].reason().as_deref(), Some("cannot open report"));
+    }
+
     #[tokio::test]
     async fn context_only_policy_evaluates_context_predicate_once_per_batch() {
         let calls = Arc::new(AtomicUsize::new(0));
@@ -4108,3 +7197,464 @@ mod policy_builder_tests {
         assert!(results.is_empty());
     }
 }
+
+mod enforcer_tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    struct CasbinDomain;
+
+    impl PolicyDomain for CasbinDomain {
+        type Subject = String;
+        type Action = String;
+        type Resource = String;
+        type Context = ();
+    }
+
+    /// The classic casbin RBAC model: `g(sub, role)` role assignments and
+    /// `p(role, obj, act)` permission rows.
+    fn rbac_enforcer() -> Enforcer<CasbinDomain> {
+        let assignments: HashMap<&'static str, Vec<&'static str>> = HashMap::from([
+            ("alice", vec!["data1_admin"]),
+            ("bob", vec!["data2_reader"]),
+        ]);
+        let permissions: Vec<(&'static str, &'static str, &'static str)> = vec![
+            ("data1_admin", "data1", "read"),
+            ("data1_admin", "data1", "write"),
+            ("data2_reader", "data2", "read"),
+        ];
+
+        let rbac = RbacPolicy::<CasbinDomain, _, _>::new(
+            move |act: &String, obj: &String| {
+                permissions
+                    .iter()
+                    .filter(|(_, p_obj, p_act)| p_obj == obj && p_act == act)
+                    .map(|(role, _, _)| *role)
+                    .collect::<Vec<_>>()
+            },
+            move |sub: &String| assignments.get(sub.as_str()).cloned().unwrap_or_default(),
+        );
+
+        let mut checker = PermissionChecker::<CasbinDomain>::new();
+        checker.add_policy(rbac);
+        Enforcer::new(checker)
+    }
+
+    #[tokio::test]
+    async fn enforce_applies_casbin_style_rbac_model() {
+        let enforcer = rbac_enforcer();
+
+        assert!(enforcer.enforce("alice", "data1", "read").await);
+        assert!(enforcer.enforce("alice", "data1", "write").await);
+        assert!(!enforcer.enforce("alice", "data2", "read").await);
+        assert!(enforcer.enforce("bob", "data2", "read").await);
+        assert!(!enforcer.enforce("bob", "data2", "write").await);
+        assert!(!enforcer.enforce("bob", "data1", "read").await);
+        assert!(!enforcer.enforce("mallory", "data1", "read").await);
+    }
+
+    #[tokio::test]
+    async fn enforce_ex_exposes_the_checker_trace() {
+        let enforcer = rbac_enforcer();
+
+        let evaluation = enforcer.enforce_ex("alice", "data1", "read").await;
+        evaluation.assert_granted_by("RbacPolicy");
+
+        let evaluation = enforcer.enforce_ex("bob", "data1", "read").await;
+        evaluation.assert_not_applicable_by("RbacPolicy");
+    }
+
+    #[tokio::test]
+    async fn enforce_without_registry_fails_closed_for_fact_backed_policies() {
+        let rebac = RebacPolicy::<CasbinDomain, String, String, &'static str>::new(
+            |sub: &String| sub.clone(),
+            |obj: &String| obj.clone(),
+            "viewer",
+        );
+        let mut checker = PermissionChecker::<CasbinDomain>::new();
+        checker.add_policy(rebac);
+        let enforcer = Enforcer::new(checker);
+
+        assert!(!enforcer.enforce("alice", "doc", "read").await);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod recording_tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use std::sync::Mutex;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Employee {
+        id: u32,
+        department: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct Ledger {
+        department: String,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    enum LedgerAction {
+        Read,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    struct RequestContext {
+        on_vpn: bool,
+    }
+
+    struct LedgerDomain;
+
+    impl PolicyDomain for LedgerDomain {
+        type Subject = Employee;
+        type Action = LedgerAction;
+        type Resource = Ledger;
+        type Context = RequestContext;
+    }
+
+    fn ledger_checker() -> PermissionChecker<LedgerDomain> {
+        let mut checker = PermissionChecker::<LedgerDomain>::named("ledgers");
+        checker.add_policy(
+            PolicyBuilder::<LedgerDomain>::new("SameDepartmentOnVpn")
+                .when(
+                    |employee: &Employee,
+                     _action: &LedgerAction,
+                     ledger: &Ledger,
+                     ctx: &RequestContext| {
+                        ctx.on_vpn && employee.department == ledger.department
+                    },
+                )
+                .build(),
+        );
+        checker
+    }
+
+    #[tokio::test]
+    async fn recorded_evaluation_replays_to_the_same_decision() {
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let sink_records = Arc::clone(&records);
+        let recording = RecordingChecker::new(
+            ledger_checker(),
+            move |record: EvaluationRecord<LedgerDomain>| {
+                sink_records.lock().unwrap().push(record);
+            },
+        );
+
+        let session = EvaluationSession::empty();
+        let employee = Employee {
+            id: 7,
+            department: "finance".into(),
+        };
+        let ledger = Ledger {
+            department: "finance".into(),
+        };
+        let granted = recording
+            .check(
+                &session,
+                &employee,
+                &LedgerAction::Read,
+                &ledger,
+                &RequestContext { on_vpn: true },
+            )
+            .await;
+        let denied = recording
+            .check(
+                &session,
+                &employee,
+                &LedgerAction::Read,
+                &ledger,
+                &RequestContext { on_vpn: false },
+            )
+            .await;
+        assert!(granted.is_granted());
+        assert!(!denied.is_granted());
+
+        let captured = std::mem::take(&mut *records.lock().unwrap());
+        assert_eq!(captured.len(), 2);
+        assert_eq!(captured[0].checker.as_deref(), Some("ledgers"));
+        assert_eq!(captured[0].subject, employee);
+        assert!(captured[0].granted);
+        assert!(!captured[1].granted);
+
+        // Round-trip through the wire format an incident log would carry,
+        // then replay against a freshly built checker.
+        let json = serde_json::to_string(&captured).unwrap();
+        let restored: Vec<EvaluationRecord<LedgerDomain>> = serde_json::from_str(&json).unwrap();
+        let local_checker = ledger_checker();
+        for record in &restored {
+            let replayed = record.replay(&local_checker, &session).await;
+            assert_eq!(replayed.is_granted(), record.granted);
+        }
+
+        let replayed = recording.replay(&session, &restored[1]).await;
+        replayed.assert_denied();
+        assert!(records.lock().unwrap().is_empty(), "replay must not record");
+    }
+}
+
+#[cfg(feature = "serde")]
+mod document_tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct Member {
+        id: u32,
+        roles: Vec<String>,
+    }
+
+    #[derive(Debug, Clone)]
+    struct Page {
+        owner_id: u32,
+        archived: bool,
+    }
+
+    struct WikiDomain;
+
+    impl PolicyDomain for WikiDomain {
+        type Subject = Member;
+        type Action = ();
+        type Resource = Page;
+        type Context = ();
+    }
+
+    const WIKI_DOCUMENT: &str = r#"{
+        "policies": [
+            { "rbac": { "roles": ["admin"] } },
+            { "and": [
+                { "abac": { "condition": "is_owner" } },
+                { "not": { "abac": { "condition": "is_archived" } } }
+            ] }
+        ]
+    }"#;
+
+    fn wiki_registry() -> ConditionRegistry<WikiDomain> {
+        ConditionRegistry::new()
+            .with_subject_roles(|member: &Member| member.roles.clone())
+            .with_condition("is_owner", |member: &Member, _, page: &Page, _| {
+                member.id == page.owner_id
+            })
+            .with_condition("is_archived", |_: &Member, _, page: &Page, _| page.archived)
+    }
+
+    fn hand_built_checker() -> PermissionChecker<WikiDomain> {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(RbacPolicy::<WikiDomain, _, _>::new(
+            |_: &(), _: &Page| vec!["admin".to_string()],
+            |member: &Member| member.roles.clone(),
+        ));
+        let is_owner: Arc<dyn Policy<WikiDomain>> = Arc::from(
+            PolicyBuilder::<WikiDomain>::new("is_owner")
+                .when(|member, _, page, _| member.id == page.owner_id)
+                .build(),
+        );
+        let not_archived: Arc<dyn Policy<WikiDomain>> = Arc::new(NotPolicy::new(
+            PolicyBuilder::<WikiDomain>::new("is_archived")
+                .when(|_, _, page, _| page.archived)
+                .build(),
+        ));
+        checker.add_policy(AndPolicy::try_new(vec![is_owner, not_archived]).unwrap());
+        checker
+    }
+
+    #[tokio::test]
+    async fn compiled_document_matches_hand_built_checker() {
+        let document: PolicyDocument = serde_json::from_str(WIKI_DOCUMENT).unwrap();
+        let mut compiled = PermissionChecker::<WikiDomain>::new();
+        for policy in document.compile(&wiki_registry()).unwrap() {
+            compiled.add_policy(policy);
+        }
+        let hand_built = hand_built_checker();
+        let session = EvaluationSession::empty();
+
+        for roles in [
+            vec![],
+            vec!["admin".to_string()],
+            vec!["editor".to_string()],
+        ] {
+            let member = Member { id: 1, roles };
+            for owner_id in [1, 2] {
+                for archived in [false, true] {
+                    let page = Page { owner_id, archived };
+                    let expected = hand_built
+                        .bind(&session, &member, &(), &())
+                        .check(&page)
+                        .await;
+                    let actual = compiled
+                        .bind(&session, &member, &(), &())
+                        .check(&page)
+                        .await;
+                    assert_eq!(
+                        actual.is_granted(),
+                        expected.is_granted(),
+                        "{member:?} on {page:?}"
+                    );
+                    assert_eq!(actual.trace().format(), expected.trace().format());
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn policy_document_round_trips_through_serde() {
+        let document: PolicyDocument = serde_json::from_str(WIKI_DOCUMENT).unwrap();
+        let json = serde_json::to_string(&document).unwrap();
+        let restored: PolicyDocument = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored, document);
+    }
+
+    #[tokio::test]
+    async fn expr_nodes_compile_to_abac_policies() {
+        let document: PolicyDocument = serde_json::from_str(
+            r#"{ "policies": [{ "expr": { "and": [
+                { "in": ["subject.role", ["editor", "admin"]] },
+                { "not": { "eq": ["resource.archived", true] } }
+            ] } }] }"#,
+        )
+        .unwrap();
+        let registry = wiki_registry().with_attributes(|member: &Member, _, page: &Page, _| {
+            Attributes::new()
+                .with(
+                    "subject.role",
+                    member.roles.first().cloned().unwrap_or_default(),
+                )
+                .with("resource.archived", page.archived)
+        });
+        let mut checker = PermissionChecker::<WikiDomain>::new();
+        for policy in document.compile(&registry).unwrap() {
+            checker.add_policy(policy);
+        }
+        let session = EvaluationSession::empty();
+        let editor = Member {
+            id: 1,
+            roles: vec!["editor".to_string()],
+        };
+
+        for (archived, granted) in [(false, true), (true, false)] {
+            let page = Page {
+                owner_id: 2,
+                archived,
+            };
+            let evaluation = checker.bind(&session, &editor, &(), &()).check(&page).await;
+            assert_eq!(evaluation.is_granted(), granted, "{page:?}");
+        }
+
+        let err = document.compile(&wiki_registry()).err().unwrap();
+        assert!(matches!(err, PolicyDocumentError::MissingAttributes));
+
+        let json = serde_json::to_string(&document).unwrap();
+        assert_eq!(
+            serde_json::from_str::<PolicyDocument>(&json).unwrap(),
+            document
+        );
+    }
+
+    #[test]
+    fn compile_rejects_unregistered_names() {
+        let document: PolicyDocument = serde_json::from_str(WIKI_DOCUMENT).unwrap();
+
+        let registry = ConditionRegistry::<WikiDomain>::new()
+            .with_subject_roles(|member: &Member| member.roles.clone())
+            .with_condition("is_owner", |member: &Member, _, page: &Page, _| {
+                member.id == page.owner_id
+            });
+        let err = document.compile(&registry).err().unwrap();
+        assert!(
+            matches!(err, PolicyDocumentError::UnknownCondition(ref name) if name == "is_archived")
+        );
+
+        let registry = ConditionRegistry::<WikiDomain>::new();
+        let err = document.compile(&registry).err().unwrap();
+        assert!(matches!(err, PolicyDocumentError::MissingSubjectRoles));
+
+        let document: PolicyDocument =
+            serde_json::from_str(r#"{ "policies": [{ "or": [] }] }"#).unwrap();
+        let err = document.compile(&wiki_registry()).err().unwrap();
+        assert_eq!(err.to_string(), "OrPolicy must have at least one policy");
+    }
+}
+
+mod subject_only_tests {
+    use super::*;
+
+    #[derive(Debug, Clone)]
+    struct GroupPermission {
+        scope: &'static str,
+        entity: &'static str,
+    }
+
+    #[derive(Debug, Clone)]
+    struct StaffUser {
+        permissions: Vec<GroupPermission>,
+    }
+
+    /// The organization being administered in this request.
+    #[derive(Debug, Clone)]
+    struct OrgRequest {
+        org_id: &'static str,
+        required_scope: &'static str,
+    }
+
+    fn org_admin_checker() -> SubjectOnlyChecker<StaffUser, OrgRequest> {
+        let mut checker = SubjectOnlyChecker::<StaffUser, OrgRequest>::new();
+        checker.add_subject_policy("ScopedPermission", |user, request| {
+            user.permissions
+                .iter()
+                .any(|p| p.scope == request.required_scope && p.entity == request.org_id)
+        });
+        checker.add_subject_policy("GlobalAdmin", |user, _request| {
+            user.permissions.iter().any(|p| p.scope == "global_admin")
+        });
+        checker
+    }
+
+    #[tokio::test]
+    async fn subject_only_checker_reproduces_scoped_admin_example() {
+        let checker = org_admin_checker();
+        let session = EvaluationSession::empty();
+
+        let scoped = StaffUser {
+            permissions: vec![GroupPermission {
+                scope: "edit_user_settings",
+                entity: "org-1",
+            }],
+        };
+        let global = StaffUser {
+            permissions: vec![GroupPermission {
+                scope: "global_admin",
+                entity: "*",
+            }],
+        };
+        let edit_users_org_1 = OrgRequest {
+            org_id: "org-1",
+            required_scope: "edit_user_settings",
+        };
+        let edit_users_org_2 = OrgRequest {
+            org_id: "org-2",
+            required_scope: "edit_user_settings",
+        };
+        let edit_settings_org_1 = OrgRequest {
+            org_id: "org-1",
+            required_scope: "edit_org_settings",
+        };
+
+        checker
+            .check(&session, &scoped, &edit_users_org_1)
+            .await
+            .assert_granted_by("ScopedPermission");
+        checker
+            .check(&session, &scoped, &edit_users_org_2)
+            .await
+            .assert_denied();
+        checker
+            .check(&session, &scoped, &edit_settings_org_1)
+            .await
+            .assert_denied();
+        checker
+            .check(&session, &global, &edit_users_org_2)
+            .await
+            .assert_granted_by("GlobalAdmin");
+    }
+}
@@ -0,0 +1,219 @@
+#![cfg(any(feature = "axum", feature = "actix"))]
+
+use async_trait::async_trait;
+use gatehouse::{
+    AccessEvaluation, AccessResponse, EvalCtx, EvaluationSession, Obligation, ObligationHandlers,
+    PermissionChecker, Policy, PolicyBuilder, PolicyDomain, PolicyEvalResult,
+};
+use std::borrow::Cow;
+
+struct Invoices;
+
+#[derive(Debug, Clone)]
+struct Caller {
+    tenant: &'static str,
+}
+
+#[derive(Debug, Clone)]
+struct Read;
+
+#[derive(Debug, Clone)]
+struct Invoice {
+    tenant: &'static str,
+}
+
+impl PolicyDomain for Invoices {
+    type Subject = Caller;
+    type Action = Read;
+    type Resource = Invoice;
+    type Context = ();
+}
+
+async fn evaluate(caller_tenant: &'static str) -> AccessEvaluation {
+    let mut checker = PermissionChecker::<Invoices>::new();
+    checker.add_policy(
+        PolicyBuilder::<Invoices>::new("SameTenant")
+            .when(|caller: &Caller, _: &Read, invoice: &Invoice, _: &()| {
+                caller.tenant == invoice.tenant
+            })
+            .build(),
+    );
+    let session = EvaluationSession::empty();
+    checker
+        .bind(
+            &session,
+            &Caller {
+                tenant: caller_tenant,
+            },
+            &Read,
+            &(),
+        )
+        .check(&Invoice { tenant: "acme" })
+        .await
+}
+
+/// Grants with an obligation to watermark the invoice, and one to archive
+/// it that no test registers a handler for.
+struct WatermarkedGrant;
+
+#[async_trait]
+impl Policy<Invoices> for WatermarkedGrant {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Invoices>) -> PolicyEvalResult {
+        ctx.grant("watermarked copy")
+            .with_obligation(Obligation::new("watermark", "confidential"))
+            .with_obligation(Obligation::new("archive", "7y"))
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        Cow::Borrowed("WatermarkedGrant")
+    }
+}
+
+async fn watermarked_grant() -> AccessEvaluation {
+    let mut checker = PermissionChecker::<Invoices>::new();
+    checker.add_policy(WatermarkedGrant);
+    let session = EvaluationSession::empty();
+    checker
+        .bind(&session, &Caller { tenant: "acme" }, &Read, &())
+        .check(&Invoice { tenant: "acme" })
+        .await
+}
+
+fn denial_reason(evaluation: &AccessEvaluation) -> String {
+    match evaluation {
+        AccessEvaluation::Denied { reason, .. } => reason.clone(),
+        _ => panic!("expected a denial"),
+    }
+}
+
+#[cfg(feature = "axum")]
+mod axum_responses {
+    use super::*;
+    use axum::body::to_bytes;
+    use axum::http::{header, StatusCode};
+    use axum::response::IntoResponse;
+    use gatehouse::DenialBody;
+
+    async fn body_text(response: axum::response::Response) -> String {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        String::from_utf8(bytes.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn granted_evaluation_is_an_empty_ok() {
+        let response = evaluate("acme").await.into_response();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(body_text(response).await.is_empty());
+    }
+
+    #[tokio::test]
+    async fn denied_evaluation_is_forbidden_with_the_reason_only() {
+        let evaluation = evaluate("globex").await;
+        let reason = denial_reason(&evaluation);
+        let response = evaluation.into_response();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(
+            response.headers()[header::CONTENT_TYPE],
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(body_text(response).await, reason);
+    }
+
+    #[tokio::test]
+    async fn trace_denial_body_appends_the_trace() {
+        let response = AccessResponse::new(evaluate("globex").await)
+            .with_denial_body(DenialBody::Trace)
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(body_text(response).await.contains("SameTenant"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[tokio::test]
+    async fn json_denial_body_is_a_forbidden_response() {
+        let response = AccessResponse::new(evaluate("globex").await)
+            .with_denial_body(DenialBody::Json)
+            .into_response();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], "application/json");
+        let body: serde_json::Value = serde_json::from_str(&body_text(response).await).unwrap();
+        assert_eq!(body["code"], gatehouse::ForbiddenResponse::NOT_PERMITTED);
+    }
+
+    #[tokio::test]
+    async fn obligation_handlers_set_headers_and_report_unenforced_obligations() {
+        let obligations = ObligationHandlers::<axum::response::Response>::new()
+            .header("watermark", "x-watermark");
+        let evaluation = watermarked_grant().await;
+        let mut response = "invoice".into_response();
+
+        let unenforced = obligations.apply(&evaluation, &mut response);
+
+        assert_eq!(response.headers()["x-watermark"], "confidential");
+        assert_eq!(unenforced, [&Obligation::new("archive", "7y")]);
+    }
+
+    #[tokio::test]
+    async fn obligation_handlers_ignore_denials() {
+        let obligations = ObligationHandlers::<axum::response::Response>::new()
+            .on("watermark", |_, _| panic!("denials carry no obligations"));
+        let mut response = StatusCode::FORBIDDEN.into_response();
+
+        assert!(obligations
+            .apply(&evaluate("globex").await, &mut response)
+            .is_empty());
+    }
+}
+
+#[cfg(feature = "actix")]
+mod actix_responses {
+    use super::*;
+    use actix_web::body::to_bytes;
+    use actix_web::http::{header, StatusCode};
+    use actix_web::test::TestRequest;
+    use actix_web::Responder;
+
+    #[actix_web::test]
+    async fn granted_evaluation_is_an_empty_ok() {
+        let request = TestRequest::default().to_http_request();
+        let response = evaluate("acme").await.respond_to(&request);
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(to_bytes(response.into_body()).await.unwrap().is_empty());
+    }
+
+    #[actix_web::test]
+    async fn denied_evaluation_is_forbidden_with_the_reason_only() {
+        let request = TestRequest::default().to_http_request();
+        let evaluation = evaluate("globex").await;
+        let reason = denial_reason(&evaluation);
+        let response = AccessResponse::from(evaluation).respond_to(&request);
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/plain; charset=utf-8"
+        );
+        assert_eq!(to_bytes(response.into_body()).await.unwrap(), reason);
+    }
+
+    #[actix_web::test]
+    async fn obligation_handlers_set_headers() {
+        let obligations =
+            ObligationHandlers::<actix_web::HttpResponse>::new().header("watermark", "x-watermark");
+        let evaluation = watermarked_grant().await;
+        let mut response = actix_web::HttpResponse::Ok().finish();
+
+        let unenforced = obligations.apply(&evaluation, &mut response);
+
+        assert_eq!(
+            response.headers().get("x-watermark").unwrap(),
+            "confidential"
+        );
+        assert_eq!(unenforced.len(), 1);
+    }
+}
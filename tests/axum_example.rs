@@ -1,3 +1,5 @@
+#![cfg(feature = "serde")]
+
 use axum::{
     body::{to_bytes, Body},
     http::{Request, StatusCode},
@@ -56,6 +58,11 @@ async fn view_invoice_denied_without_admin() {
 
     let response = app.oneshot(request).await.unwrap();
     assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    let body: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(body["code"], "not_permitted");
+    assert!(body["message"].is_string());
+    assert!(!body["requirements"].as_array().unwrap().is_empty());
 }
 
 #[tokio::test]
@@ -1,9 +1,11 @@
 use async_trait::async_trait;
 use gatehouse::{
-    AccessEvaluation, AndPolicy, BatchEvalCtx, DelegatingPolicy, Effect, EvalCtx,
-    EvaluationSession, FactLoadResult, FactSource, Hydrator, LookupAuthorizedError, LookupPage,
-    LookupSource, NotPolicy, OrPolicy, PermissionChecker, Policy, PolicyBatchItem, PolicyBuilder,
-    PolicyDomain, PolicyEvalResult, RebacPolicy, RelationshipQuery,
+    diff_checkers, AccessDenied, AccessEvaluation, ActionClass, AndPolicy, AuditOnDeny,
+    BatchEvalCtx, CombineStrategy, Decision, DeclaredFacts, DelegatingPolicy, Effect, EvalCtx,
+    EvaluationSession, FactLoadResult, FactSource, FieldDecision, FieldPolicy, Hydrator,
+    LookupAuthorizedError, LookupPage, LookupSource, MappedPolicy, NotPolicy, Obligation, OrPolicy,
+    PermissionChecker, Policy, PolicyBatchItem, PolicyBuilder, PolicyDomain, PolicyEvalResult,
+    PolicyExt, RebacPolicy, RelationshipQuery, TenantIsolationPolicy,
 };
 use proptest::prelude::*;
 use std::collections::HashSet;
@@ -931,6 +933,115 @@ async fn mixed_policy_stack_uses_in_memory_policy_and_rebac_session() {
     );
 }
 
+#[tokio::test]
+async fn rebac_relationship_resolver_requires_a_relation_per_request() {
+    let subject = MixedSubject { id: 7 };
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let grants = [2, 3].map(|resource_id| RelationshipQuery {
+        subject_id: subject.id,
+        resource_id,
+        relation: "viewer",
+    });
+    let session = gatehouse::FactRegistry::builder()
+        .with::<RelationshipQuery<u8, u8, &'static str>, _>(MixedRelationshipSource {
+            grants: HashSet::from(grants),
+            calls: Arc::clone(&calls),
+        })
+        .build()
+        .session();
+
+    let mut checker = PermissionChecker::<MixedDomain>::new();
+    checker.add_policy(
+        RebacPolicy::<MixedDomain, u8, u8, &'static str>::with_relationship_resolver(
+            |subject: &MixedSubject| subject.id,
+            |resource: &MixedResource| resource.id,
+            |_: &MixedAction, resource: &MixedResource| {
+                if resource.public {
+                    "viewer"
+                } else {
+                    "editor"
+                }
+            },
+        ),
+    );
+    let public = MixedResource {
+        id: 2,
+        public: true,
+    };
+    let private = MixedResource {
+        id: 3,
+        public: false,
+    };
+    let results = checker
+        .bind(&session, &subject, &MixedAction, &MixedCtx)
+        .evaluate([public, private])
+        .await;
+
+    assert_granted(&results[0].1, true);
+    assert_granted(&results[1].1, false);
+    results[1]
+        .1
+        .assert_trace_contains("Subject does not have 'editor' relationship with resource");
+    let relations = calls.lock().unwrap()[0]
+        .iter()
+        .map(|key| key.relation)
+        .collect::<Vec<_>>();
+    assert_eq!(relations, ["viewer", "editor"]);
+}
+
+#[tokio::test]
+async fn evaluate_subjects_tags_results_by_index_and_prefetches_declared_facts() {
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let session = gatehouse::FactRegistry::builder()
+        .with::<RelationshipQuery<u8, u8, &'static str>, _>(MixedRelationshipSource {
+            grants: HashSet::from([1, 3].map(|subject_id| RelationshipQuery {
+                subject_id,
+                resource_id: 9,
+                relation: "editor",
+            })),
+            calls: Arc::clone(&calls),
+        })
+        .build()
+        .session();
+    let mut checker = PermissionChecker::<MixedDomain>::new();
+    checker.add_policy(
+        RebacPolicy::<MixedDomain, u8, u8, &'static str>::new(
+            |subject: &MixedSubject| subject.id,
+            |resource: &MixedResource| resource.id,
+            "editor",
+        )
+        .with_prefetch(),
+    );
+    let subjects = [1, 2, 3, 4].map(|id| MixedSubject { id });
+    let document = MixedResource {
+        id: 9,
+        public: false,
+    };
+
+    let results = checker
+        .evaluate_subjects(
+            &session,
+            &subjects,
+            &MixedAction,
+            &document,
+            &MixedCtx,
+            NonZeroUsize::new(2),
+        )
+        .await;
+
+    let indexes = results.iter().map(|(index, _)| *index).collect::<Vec<_>>();
+    assert_eq!(indexes, [0, 1, 2, 3]);
+    let editors = results
+        .iter()
+        .filter(|(_, evaluation)| evaluation.is_granted())
+        .map(|(index, _)| subjects[*index].id)
+        .collect::<Vec<_>>();
+    assert_eq!(editors, [1, 3]);
+    let calls = calls.lock().unwrap();
+    assert_eq!(calls.len(), 1);
+    assert_eq!(calls[0].len(), subjects.len());
+}
+
 // ---- deny-overrides semantics -------------------------------------
 
 fn allow_everything(name: &str) -> Box<dyn Policy<Domain>> {
@@ -1604,3 +1715,1445 @@ async fn wrong_length_batch_from_forbid_policy_fails_closed() {
         );
     }
 }
+
+struct StaticFieldPolicy {
+    name: &'static str,
+    fields: &'static [&'static str],
+}
+
+#[async_trait]
+impl FieldPolicy<Domain> for StaticFieldPolicy {
+    async fn evaluate_fields(&self, _ctx: &EvalCtx<'_, Domain>) -> FieldDecision {
+        FieldDecision::only(self.fields.iter().copied())
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(self.name)
+    }
+}
+
+#[tokio::test]
+async fn authorized_fields_intersects_field_policy_decisions() {
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::new();
+    checker.add_policy(allow_everything("AllowAll"));
+
+    let unrestricted = bind(&checker, &session)
+        .authorized_fields(&Resource { id: 0 })
+        .await;
+    assert_eq!(unrestricted, FieldDecision::All);
+
+    checker.add_field_policy(StaticFieldPolicy {
+        name: "AB",
+        fields: &["a", "b"],
+    });
+    checker.add_field_policy(StaticFieldPolicy {
+        name: "AC",
+        fields: &["a", "c"],
+    });
+
+    let fields = bind(&checker, &session)
+        .authorized_fields(&Resource { id: 0 })
+        .await;
+    assert_eq!(fields, FieldDecision::only(["a"]));
+    assert!(fields.allows("a"));
+    assert!(!fields.allows("b"));
+    assert!(!fields.allows("c"));
+}
+
+#[tokio::test]
+async fn authorized_fields_is_empty_when_resource_is_denied() {
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::new();
+    checker.add_policy(allow_everything("AllowAll"));
+    checker.add_policy(forbid_odd_resources("ForbidOdd"));
+    checker.add_field_policy(StaticFieldPolicy {
+        name: "AB",
+        fields: &["a", "b"],
+    });
+
+    let evaluator = bind(&checker, &session);
+    assert_eq!(
+        evaluator.authorized_fields(&Resource { id: 2 }).await,
+        FieldDecision::only(["a", "b"])
+    );
+    let denied = evaluator.authorized_fields(&Resource { id: 1 }).await;
+    assert!(denied.is_empty());
+    assert!(!denied.allows("a"));
+}
+
+#[tokio::test]
+async fn diff_checkers_reports_each_divergent_sample_with_both_decisions() {
+    let session = EvaluationSession::empty();
+    let mut old = PermissionChecker::new();
+    old.add_policy(grant_even_resources("EvenResource"));
+
+    // The refactored checker accidentally grants everything below 4 and
+    // forbids 2.
+    let mut new = PermissionChecker::new();
+    new.add_policy(
+        PolicyBuilder::<Domain>::new("BelowFour")
+            .resources(|resource: &Resource| resource.id < 4)
+            .build(),
+    );
+    new.add_policy(
+        PolicyBuilder::<Domain>::new("ForbidTwo")
+            .resources(|resource: &Resource| resource.id == 2)
+            .forbid()
+            .build(),
+    );
+
+    let samples = (0..6)
+        .map(|id| (Subject, Action, Resource { id }, Ctx))
+        .collect::<Vec<_>>();
+    let divergences = diff_checkers(&old, &new, &session, &samples).await;
+
+    assert_eq!(
+        divergences
+            .iter()
+            .map(|divergence| (
+                divergence.index,
+                divergence.sample.2.id,
+                divergence.before.is_granted(),
+                divergence.after.is_granted(),
+            ))
+            .collect::<Vec<_>>(),
+        vec![
+            (1, 1, false, true),
+            (2, 2, true, false),
+            (3, 3, false, true),
+            (4, 4, true, false),
+        ]
+    );
+    divergences[1].before.assert_granted_by("EvenResource");
+    divergences[1].after.assert_forbidden_by("ForbidTwo");
+}
+
+#[tokio::test]
+async fn filter_take_stops_evaluating_once_limit_grants_are_collected() {
+    let seen_batches = Arc::new(Mutex::new(Vec::new()));
+    let mut checker = PermissionChecker::new();
+    checker.add_policy(BatchGrantPolicy {
+        name: "MultipleOfThree",
+        batch_calls: Arc::new(AtomicUsize::new(0)),
+        single_calls: Arc::new(AtomicUsize::new(0)),
+        seen_batches: Arc::clone(&seen_batches),
+        grant: Arc::new(|id| id % 3 == 0),
+    });
+
+    let session = EvaluationSession::empty();
+    let resources = (1..=200).map(|id| Resource { id }).collect::<Vec<_>>();
+    let taken = bind(&checker, &session)
+        .filter_take(resources.iter(), 3)
+        .await;
+
+    assert_eq!(
+        taken.iter().map(|resource| resource.id).collect::<Vec<_>>(),
+        vec![3, 6, 9]
+    );
+    let evaluated = seen_batches
+        .lock()
+        .unwrap()
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<_>>();
+    assert_eq!(evaluated, (1..=9).collect::<Vec<_>>());
+
+    let none = bind(&checker, &session)
+        .filter_take(resources.iter(), 0)
+        .await;
+    assert!(none.is_empty());
+
+    let all = bind(&checker, &session)
+        .filter_take(resources.iter().take(10), 50)
+        .await;
+    assert_eq!(
+        all.iter().map(|resource| resource.id).collect::<Vec<_>>(),
+        vec![3, 6, 9]
+    );
+}
+
+#[tokio::test]
+async fn audit_on_deny_fires_even_when_another_policy_grants() {
+    let audited = Arc::new(Mutex::new(Vec::new()));
+    let sink_audited = Arc::clone(&audited);
+    let mut checker = PermissionChecker::new();
+    checker.add_policy(AuditOnDeny::new(
+        PolicyBuilder::<Domain>::new("OnlyZero")
+            .resources(|resource: &Resource| resource.id == 0)
+            .build(),
+        move |ctx: &EvalCtx<'_, Domain>, result: &PolicyEvalResult| {
+            assert!(!result.is_granted());
+            sink_audited
+                .lock()
+                .unwrap()
+                .push((ctx.resource.id, ctx.policy_type.to_string()));
+        },
+    ));
+    checker.add_policy(allow_everything("AllowAll"));
+
+    let session = EvaluationSession::empty();
+    let evaluation = check_resource(&checker, &session, &Resource { id: 5 }).await;
+    evaluation.assert_granted_by("AllowAll");
+    assert_eq!(
+        std::mem::take(&mut *audited.lock().unwrap()),
+        vec![(5, "OnlyZero".to_string())]
+    );
+
+    let granted = check_resource(&checker, &session, &Resource { id: 0 }).await;
+    granted.assert_granted_by("OnlyZero");
+    assert!(audited.lock().unwrap().is_empty());
+
+    let results = evaluate_resources(
+        &checker,
+        &session,
+        vec![Resource { id: 0 }, Resource { id: 1 }, Resource { id: 2 }],
+    )
+    .await;
+    assert!(results
+        .iter()
+        .all(|(_, evaluation)| evaluation.is_granted()));
+    assert_eq!(
+        audited
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(id, _)| *id)
+            .collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+struct ClearanceKey(u8);
+
+impl gatehouse::FactKey for ClearanceKey {
+    type Value = bool;
+
+    const NAME: &'static str = "clearance";
+}
+
+type EventLog = Arc<Mutex<Vec<String>>>;
+
+struct LoggingClearanceSource {
+    log: EventLog,
+}
+
+#[async_trait]
+impl FactSource<ClearanceKey> for LoggingClearanceSource {
+    async fn load_many(&self, keys: &[ClearanceKey]) -> Vec<FactLoadResult<bool>> {
+        self.log.lock().unwrap().push(format!(
+            "load {:?}",
+            keys.iter().map(|key| key.0).collect::<Vec<_>>()
+        ));
+        keys.iter()
+            .map(|key| FactLoadResult::Found(key.0 % 3 == 0))
+            .collect()
+    }
+}
+
+/// Reads the clearance fact at `resource.id + offset` and declares it up
+/// front.
+struct DeclaringClearancePolicy {
+    name: &'static str,
+    offset: u8,
+    log: EventLog,
+}
+
+#[async_trait]
+impl Policy<Domain> for DeclaringClearancePolicy {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Domain>) -> PolicyEvalResult {
+        self.log
+            .lock()
+            .unwrap()
+            .push(format!("evaluate {} {}", self.name, ctx.resource.id));
+        match ctx
+            .session
+            .get(ClearanceKey(ctx.resource.id + self.offset))
+            .await
+        {
+            FactLoadResult::Found(true) => ctx.grant("cleared"),
+            _ => ctx.not_applicable("not cleared"),
+        }
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(self.name)
+    }
+
+    fn declared_facts(
+        &self,
+        _subject: &Subject,
+        _action: &Action,
+        resource: &Resource,
+        _context: &Ctx,
+        facts: &mut DeclaredFacts,
+    ) {
+        facts.declare(ClearanceKey(resource.id + self.offset));
+    }
+}
+
+fn declaring_checker(log: &EventLog) -> (PermissionChecker<Domain>, EvaluationSession) {
+    let mut checker = PermissionChecker::new();
+    checker.add_policy(DeclaringClearancePolicy {
+        name: "Odd",
+        offset: 1,
+        log: Arc::clone(log),
+    });
+    checker.add_policy(
+        DeclaringClearancePolicy {
+            name: "Even",
+            offset: 0,
+            log: Arc::clone(log),
+        }
+        .not()
+        .not(),
+    );
+    let session = gatehouse::FactRegistry::builder()
+        .with::<ClearanceKey, _>(LoggingClearanceSource {
+            log: Arc::clone(log),
+        })
+        .build()
+        .session();
+    (checker, session)
+}
+
+#[tokio::test]
+async fn declared_facts_are_prefetched_in_one_load_before_any_policy_runs() {
+    let log = EventLog::default();
+    let (checker, session) = declaring_checker(&log);
+
+    let evaluation = check_resource(&checker, &session, &Resource { id: 1 }).await;
+    evaluation.assert_denied();
+    assert_eq!(
+        std::mem::take(&mut *log.lock().unwrap()),
+        vec!["load [2, 1]", "evaluate Odd 1", "evaluate Even 1"]
+    );
+
+    let (checker, session) = declaring_checker(&log);
+    let results = evaluate_resources(
+        &checker,
+        &session,
+        vec![Resource { id: 1 }, Resource { id: 3 }, Resource { id: 5 }],
+    )
+    .await;
+    assert_eq!(
+        results
+            .iter()
+            .map(|(resource, evaluation)| (resource.id, evaluation.is_granted()))
+            .collect::<Vec<_>>(),
+        vec![(1, false), (3, true), (5, true)]
+    );
+    let log = log.lock().unwrap();
+    assert_eq!(log[0], "load [2, 1, 4, 3, 6, 5]");
+    assert_eq!(
+        log.iter().filter(|event| event.starts_with("load")).count(),
+        1
+    );
+}
+
+#[tokio::test]
+async fn all_allow_strategy_requires_every_grant_capable_policy_on_both_paths() {
+    let mut checker = PermissionChecker::<Domain>::with_strategy(CombineStrategy::AllAllow);
+    checker.add_policy(allow_everything("Everyone"));
+    checker.add_policy(grant_even_resources("EvenOnly"));
+    checker.add_policy(
+        PolicyBuilder::<Domain>::new("BlockFour")
+            .resources(|resource: &Resource| resource.id == 4)
+            .forbid()
+            .build(),
+    );
+    let session = EvaluationSession::empty();
+    let resources = (0..6).map(|id| Resource { id }).collect::<Vec<_>>();
+
+    let batch = evaluate_resources(&checker, &session, resources.iter()).await;
+    for (resource, batch_evaluation) in batch {
+        let evaluation = check_resource(&checker, &session, resource).await;
+        assert_eq!(evaluation.is_granted(), batch_evaluation.is_granted());
+        assert_eq!(evaluation.denied_reason(), batch_evaluation.denied_reason());
+        evaluation.assert_trace_contains("(AND)");
+
+        match resource.id {
+            4 => assert_eq!(evaluation.forbidden_by(), Some("BlockFour")),
+            id if id % 2 == 0 => {
+                evaluation.assert_granted_by("Everyone");
+                let Some(PolicyEvalResult::Combined { children, .. }) = evaluation.trace().root()
+                else {
+                    panic!("expected a combined checker root");
+                };
+                assert_eq!(children.len(), 3, "every policy is evaluated on a grant");
+            }
+            _ => assert!(evaluation
+                .denied_reason()
+                .is_some_and(|reason| reason.starts_with("Not granted by EvenOnly"))),
+        }
+    }
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn strategy_sets_root_operation_without_changing_any_allow_decisions() {
+    let mut checker = PermissionChecker::<Domain>::new();
+    assert_eq!(checker.strategy(), CombineStrategy::DenyOverrides);
+    checker.add_policy(forbid_odd_resources("ForbidOdd"));
+    checker.add_policy(allow_everything("Everyone"));
+    let session = EvaluationSession::empty();
+
+    let default_grant = check_resource(&checker, &session, &Resource { id: 2 }).await;
+    default_grant.assert_trace_contains("DENY_OVERRIDES");
+
+    checker.set_strategy(CombineStrategy::AnyAllow);
+    let grant = check_resource(&checker, &session, &Resource { id: 2 }).await;
+    grant.assert_granted_by("Everyone");
+    grant.assert_trace_contains("(OR)");
+    let forbid = check_resource(&checker, &session, &Resource { id: 3 }).await;
+    assert_eq!(forbid.forbidden_by(), Some("ForbidOdd"));
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn any_allow_decides_exactly_like_deny_overrides() {
+    let session = EvaluationSession::empty();
+    let checker_with = |strategy| {
+        let mut checker = PermissionChecker::<Domain>::with_strategy(strategy);
+        checker.add_policy(NamedNoopPolicy { name: "Alpha" });
+        checker.add_policy(forbid_odd_resources("ForbidOdd"));
+        checker.add_policy(grant_even_resources("EvenOnly"));
+        checker.add_policy(allow_everything("Everyone"));
+        checker
+    };
+    let any_allow = checker_with(CombineStrategy::AnyAllow);
+    let deny_overrides = checker_with(CombineStrategy::DenyOverrides);
+    let resources = (0..6).map(|id| Resource { id }).collect::<Vec<_>>();
+
+    for resource in &resources {
+        let alias = check_resource(&any_allow, &session, resource).await;
+        let canonical = check_resource(&deny_overrides, &session, resource).await;
+        assert_eq!(alias.decision(), canonical.decision());
+        assert_eq!(alias.forbidden_by(), canonical.forbidden_by());
+        assert_eq!(alias.stats(), canonical.stats());
+    }
+    assert!(filter_resources(&any_allow, &session, resources.clone())
+        .await
+        .iter()
+        .map(|resource| resource.id)
+        .eq(filter_resources(&deny_overrides, &session, resources)
+            .await
+            .iter()
+            .map(|resource| resource.id)));
+}
+
+#[tokio::test]
+async fn tenant_isolation_vetoes_cross_tenant_access_despite_grants() {
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(allow_everything("Everyone"));
+    checker.add_policy(TenantIsolationPolicy::<Domain, _, _>::new(
+        |_subject: &Subject| 1u8,
+        |resource: &Resource| resource.id / 10,
+    ));
+    let session = EvaluationSession::empty();
+
+    let same_tenant = check_resource(&checker, &session, &Resource { id: 12 }).await;
+    same_tenant.assert_granted_by("Everyone");
+
+    let cross_tenant = check_resource(&checker, &session, &Resource { id: 22 }).await;
+    assert_eq!(cross_tenant.forbidden_by(), Some("TenantIsolationPolicy"));
+    assert_eq!(
+        cross_tenant.denied_reason(),
+        Some("Forbidden by TenantIsolationPolicy: Resource belongs to another tenant")
+    );
+
+    let batch = filter_resources(&checker, &session, [12, 22, 15].map(|id| Resource { id })).await;
+    assert_eq!(
+        batch.iter().map(|resource| resource.id).collect::<Vec<_>>(),
+        vec![12, 15]
+    );
+}
+
+#[tokio::test]
+#[allow(deprecated)]
+async fn every_strategy_fails_closed_when_every_policy_is_indeterminate() {
+    let session = EvaluationSession::empty();
+    for strategy in [
+        CombineStrategy::DenyOverrides,
+        CombineStrategy::AnyAllow,
+        CombineStrategy::AllAllow,
+    ] {
+        let mut checker = PermissionChecker::<Domain>::with_strategy(strategy)
+            .with_max_batch_size(NonZeroUsize::new(1).unwrap());
+        checker.add_policy(forbid_odd_resources("ForbidOdd"));
+        checker.add_policy(allow_everything("Everyone"));
+        checker.add_policy(grant_even_resources("EvenOnly"));
+
+        // The real policies grant resource 2 under every strategy.
+        assert!(check_resource(&checker, &session, &Resource { id: 2 })
+            .await
+            .is_granted());
+        for id in 0..4 {
+            checker
+                .assert_fail_closed(&session, &Subject, &Action, &Resource { id }, &Ctx)
+                .await;
+        }
+        PermissionChecker::<Domain>::with_strategy(strategy)
+            .assert_fail_closed(&session, &Subject, &Action, &Resource { id: 2 }, &Ctx)
+            .await;
+    }
+}
+
+#[tokio::test]
+async fn all_denial_reasons_lists_every_denying_leaf_of_an_or_checker() {
+    let mut checker = PermissionChecker::<Domain>::with_strategy(CombineStrategy::DenyOverrides);
+    checker.add_policy(NamedNoopPolicy { name: "Alpha" });
+    checker.add_policy(grant_even_resources("EvenOnly"));
+    checker.add_policy(NamedNoopPolicy { name: "Beta" }.or(NamedNoopPolicy { name: "Gamma" }));
+    let session = EvaluationSession::empty();
+
+    let denied = check_resource(&checker, &session, &Resource { id: 3 }).await;
+    assert_eq!(denied.denied_reason(), Some("All policies denied access"));
+    assert_eq!(
+        denied.all_denial_reasons(),
+        vec![
+            ("Alpha".to_string(), "not applicable".to_string()),
+            (
+                "EvenOnly".to_string(),
+                "Policy predicate did not match".to_string()
+            ),
+            ("Beta".to_string(), "not applicable".to_string()),
+            ("Gamma".to_string(), "not applicable".to_string()),
+        ]
+    );
+
+    let granted = check_resource(&checker, &session, &Resource { id: 2 }).await;
+    assert!(granted.all_denial_reasons().is_empty());
+}
+
+#[tokio::test]
+async fn denied_and_granted_policies_split_the_trace_leaves() {
+    let mut checker = PermissionChecker::<Domain>::with_strategy(CombineStrategy::DenyOverrides);
+    checker.add_policy(NamedNoopPolicy { name: "Alpha" });
+    checker.add_policy(grant_even_resources("EvenOnly"));
+    let session = EvaluationSession::empty();
+
+    let granted = check_resource(&checker, &session, &Resource { id: 2 }).await;
+    assert_eq!(
+        granted.denied_policies(),
+        vec![("Alpha".to_string(), "not applicable".to_string())]
+    );
+    assert_eq!(
+        granted.granted_policies(),
+        vec![("EvenOnly".to_string(), "Policy allowed access".to_string())]
+    );
+
+    let denied = check_resource(&checker, &session, &Resource { id: 3 }).await;
+    assert_eq!(denied.denied_policies(), denied.all_denial_reasons());
+    assert!(denied.granted_policies().is_empty());
+}
+
+struct SleepingPolicy {
+    name: &'static str,
+    delay_ms: u64,
+    grant: bool,
+}
+
+#[async_trait]
+impl Policy<Domain> for SleepingPolicy {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Domain>) -> PolicyEvalResult {
+        tokio::time::sleep(std::time::Duration::from_millis(self.delay_ms)).await;
+        if self.grant {
+            ctx.grant("slept")
+        } else {
+            ctx.not_applicable("slept")
+        }
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(self.name)
+    }
+}
+
+struct PanickingPolicy;
+
+#[async_trait]
+impl Policy<Domain> for PanickingPolicy {
+    async fn evaluate(&self, _ctx: &EvalCtx<'_, Domain>) -> PolicyEvalResult {
+        panic!("policy bug");
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("PanickingPolicy")
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn check_parallel_overlaps_policies_and_matches_sequential_trace() {
+    let mut checker = PermissionChecker::<Domain>::new();
+    for (name, delay_ms, grant) in [
+        ("Slow", 30, false),
+        ("Slower", 40, false),
+        ("Last", 20, true),
+    ] {
+        checker.add_policy(SleepingPolicy {
+            name,
+            delay_ms,
+            grant,
+        });
+    }
+    let session = EvaluationSession::empty();
+    let bound = bind(&checker, &session);
+    let resource = Resource { id: 1 };
+
+    let started = tokio::time::Instant::now();
+    let sequential = bound.check(&resource).await;
+    assert_eq!(started.elapsed(), std::time::Duration::from_millis(90));
+
+    let started = tokio::time::Instant::now();
+    let parallel = bound.check_parallel(&resource).await;
+    assert_eq!(started.elapsed(), std::time::Duration::from_millis(40));
+
+    parallel.assert_granted_by("Last");
+    assert_eq!(parallel.display_trace(), sequential.display_trace());
+}
+
+#[tokio::test]
+async fn check_parallel_fails_closed_when_a_policy_panics() {
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(allow_everything("Everyone"));
+    checker.add_policy(PanickingPolicy);
+    let session = EvaluationSession::empty();
+
+    let evaluation = bind(&checker, &session)
+        .check_parallel(&Resource { id: 2 })
+        .await;
+    evaluation.assert_denied_with_reason_containing("PanickingPolicy panicked");
+    evaluation.assert_trace_contains(
+        "⚠ PanickingPolicy INDETERMINATE: Policy panicked during evaluation",
+    );
+}
+
+struct TimeWindowPolicy {
+    name: &'static str,
+    not_after: std::time::SystemTime,
+}
+
+#[async_trait]
+impl Policy<Domain> for TimeWindowPolicy {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Domain>) -> PolicyEvalResult {
+        if std::time::SystemTime::now() < self.not_after {
+            ctx.grant("inside the time window")
+                .with_valid_until(self.not_after)
+        } else {
+            ctx.not_applicable("time window has closed")
+        }
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(self.name)
+    }
+}
+
+#[tokio::test]
+async fn grant_valid_until_is_the_earliest_expiry_behind_the_decision() {
+    let now = std::time::SystemTime::now();
+    let hour = std::time::Duration::from_secs(3600);
+    let session = EvaluationSession::empty();
+    let resource = Resource { id: 2 };
+
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(TimeWindowPolicy {
+        name: "BusinessHours",
+        not_after: now + hour,
+    });
+    let evaluation = check_resource(&checker, &session, &resource).await;
+    evaluation.assert_granted_by("BusinessHours");
+    assert_eq!(evaluation.valid_until(), Some(now + hour));
+
+    let mut checker = PermissionChecker::<Domain>::with_strategy(CombineStrategy::AllAllow);
+    checker.add_policy(TimeWindowPolicy {
+        name: "Contract",
+        not_after: now + 8 * hour,
+    });
+    checker.add_policy(
+        TimeWindowPolicy {
+            name: "OnCallShift",
+            not_after: now + 2 * hour,
+        }
+        .and(allow_everything("Everyone")),
+    );
+    let evaluation = check_resource(&checker, &session, &resource).await;
+    assert!(evaluation.is_granted());
+    assert_eq!(evaluation.valid_until(), Some(now + 2 * hour));
+
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(TimeWindowPolicy {
+        name: "Expired",
+        not_after: now - hour,
+    });
+    checker.add_policy(allow_everything("Everyone"));
+    let evaluation = check_resource(&checker, &session, &resource).await;
+    evaluation.assert_granted_by("Everyone");
+    assert_eq!(evaluation.valid_until(), None);
+}
+
+#[tokio::test]
+async fn decide_returns_a_compact_decision_for_both_outcomes() {
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(grant_even_resources("EvenOnly"));
+
+    match checker
+        .decide(&session, &Subject, &Action, &Resource { id: 2 }, &Ctx)
+        .await
+    {
+        Decision::Allow => {}
+        Decision::Deny(reason) => panic!("expected allow, got deny: {reason}"),
+        other => panic!("unexpected decision: {other:?}"),
+    }
+
+    let odd = Resource { id: 3 };
+    let evaluation = check_resource(&checker, &session, &odd).await;
+    let Decision::Deny(reason) = checker
+        .decide(&session, &Subject, &Action, &odd, &Ctx)
+        .await
+    else {
+        panic!("expected deny");
+    };
+    assert_eq!(evaluation.decision(), Decision::Deny(reason));
+}
+
+#[tokio::test]
+async fn authorize_propagates_denials_as_errors() {
+    async fn handler(
+        checker: &PermissionChecker<Domain>,
+        session: &EvaluationSession,
+        resource: &Resource,
+    ) -> Result<u8, AccessDenied> {
+        checker
+            .authorize(session, &Subject, &Action, resource, &Ctx)
+            .await?;
+        Ok(resource.id)
+    }
+
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(grant_even_resources("EvenOnly"));
+
+    assert!(
+        checker
+            .is_allowed(&session, &Subject, &Action, &Resource { id: 2 }, &Ctx)
+            .await
+    );
+    assert!(
+        !checker
+            .is_allowed(&session, &Subject, &Action, &Resource { id: 3 }, &Ctx)
+            .await
+    );
+    assert_eq!(
+        handler(&checker, &session, &Resource { id: 2 })
+            .await
+            .unwrap(),
+        2
+    );
+
+    let odd = Resource { id: 3 };
+    let denied = handler(&checker, &session, &odd).await.unwrap_err();
+    let evaluation = check_resource(&checker, &session, &odd).await;
+    assert_eq!(Some(denied.reason()), evaluation.denied_reason());
+    assert_eq!(
+        denied.to_string(),
+        format!("access denied: {}", denied.reason())
+    );
+    assert_eq!(denied.trace().format(), evaluation.trace().format());
+    let _: &dyn std::error::Error = &denied;
+}
+
+#[tokio::test]
+async fn policy_ids_tell_apart_instances_of_one_policy_type() {
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(
+        TenantIsolationPolicy::new(|_: &Subject| 0, |resource: &Resource| resource.id / 10)
+            .with_id("tenant-by-range"),
+    );
+    checker.add_policy(
+        TenantIsolationPolicy::new(|_: &Subject| 0, |resource: &Resource| resource.id % 2)
+            .with_id("tenant-by-parity"),
+    );
+    checker.add_policy(allow_everything("Everyone"));
+
+    let evaluation = check_resource(&checker, &session, &Resource { id: 3 }).await;
+    evaluation.assert_forbidden_by("TenantIsolationPolicy");
+    let Some(PolicyEvalResult::Combined { children, .. }) = evaluation.trace().root() else {
+        panic!("expected a root node, got:\n{}", evaluation.display_trace());
+    };
+    let ids = children
+        .iter()
+        .map(PolicyEvalResult::policy_id)
+        .collect::<Vec<_>>();
+    assert_eq!(ids, vec![Some("tenant-by-range"), Some("tenant-by-parity")]);
+    evaluation.assert_trace_contains("✘ tenant-by-range NOT_APPLICABLE");
+    evaluation.assert_trace_contains("⛔ tenant-by-parity FORBIDDEN");
+}
+
+#[tokio::test]
+async fn new_with_globals_enforces_registered_global_policies() {
+    // A domain of its own, so no other test sees the registration.
+    struct Accounts;
+    impl PolicyDomain for Accounts {
+        // Whether the account is suspended.
+        type Subject = bool;
+        type Action = ();
+        type Resource = ();
+        type Context = ();
+    }
+
+    gatehouse::global_policies::register(|| {
+        PolicyBuilder::<Accounts>::new("SuspendedAccount")
+            .subjects(|suspended: &bool| *suspended)
+            .forbid()
+            .build()
+    });
+
+    let mut checker = PermissionChecker::<Accounts>::new_with_globals();
+    checker.add_policy(PolicyBuilder::<Accounts>::new("Everyone").build());
+    let session = EvaluationSession::empty();
+    checker
+        .bind(&session, &false, &(), &())
+        .check(&())
+        .await
+        .assert_granted_by("Everyone");
+    checker
+        .bind(&session, &true, &(), &())
+        .check(&())
+        .await
+        .assert_forbidden_by("SuspendedAccount");
+
+    let mut unregistered = PermissionChecker::<Accounts>::new();
+    unregistered.add_policy(PolicyBuilder::<Accounts>::new("Everyone").build());
+    assert!(unregistered
+        .bind(&session, &true, &(), &())
+        .check(&())
+        .await
+        .is_granted());
+
+    gatehouse::global_policies::clear::<Accounts>();
+    let mut cleared = PermissionChecker::<Accounts>::new_with_globals();
+    cleared.add_policy(PolicyBuilder::<Accounts>::new("Everyone").build());
+    assert!(cleared
+        .bind(&session, &true, &(), &())
+        .check(&())
+        .await
+        .is_granted());
+}
+
+struct RedactingPolicy {
+    name: &'static str,
+    field: &'static str,
+}
+
+#[async_trait]
+impl Policy<Domain> for RedactingPolicy {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Domain>) -> PolicyEvalResult {
+        ctx.grant("readable with redaction")
+            .with_obligation(Obligation::new("redact", self.field))
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(self.name)
+    }
+}
+
+#[tokio::test]
+async fn obligations_are_merged_from_the_grants_behind_the_decision() {
+    let session = EvaluationSession::empty();
+    let resource = Resource { id: 2 };
+
+    let mut checker = PermissionChecker::<Domain>::with_strategy(CombineStrategy::AllAllow);
+    checker.add_policy(RedactingPolicy {
+        name: "HideSalary",
+        field: "salary",
+    });
+    checker.add_policy(
+        RedactingPolicy {
+            name: "HideAddress",
+            field: "address",
+        }
+        .and(allow_everything("Everyone")),
+    );
+    let evaluation = check_resource(&checker, &session, &resource).await;
+    assert!(evaluation.is_granted());
+    assert_eq!(
+        evaluation.obligations(),
+        vec![
+            &Obligation::new("redact", "salary"),
+            &Obligation::new("redact", "address"),
+        ]
+    );
+
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(RedactingPolicy {
+        name: "HideSalary",
+        field: "salary",
+    });
+    checker.add_policy(forbid_odd_resources("NoOdd"));
+    let evaluation = check_resource(&checker, &session, &Resource { id: 3 }).await;
+    assert!(!evaluation.is_granted());
+    assert!(evaluation.obligations().is_empty());
+}
+
+#[derive(Debug, Clone, Copy)]
+enum DocumentAction {
+    View,
+    Edit,
+}
+
+struct ClassifiedDomain;
+
+impl PolicyDomain for ClassifiedDomain {
+    type Subject = Subject;
+    type Action = DocumentAction;
+    type Resource = Resource;
+    type Context = Ctx;
+}
+
+struct NoopFor<D>(&'static str, std::marker::PhantomData<D>);
+
+#[async_trait]
+impl<D: PolicyDomain> Policy<D> for NoopFor<D> {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, D>) -> PolicyEvalResult {
+        ctx.not_applicable("not applicable")
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed(self.0)
+    }
+}
+
+#[tokio::test]
+async fn default_allow_grants_unhandled_reads_but_not_unhandled_writes() {
+    let session = EvaluationSession::empty();
+    let resource = Resource { id: 1 };
+    let mut checker = PermissionChecker::<ClassifiedDomain>::new()
+        .with_action_classifier(|action: &DocumentAction| match action {
+            DocumentAction::View => ActionClass::Read,
+            DocumentAction::Edit => ActionClass::Write,
+        })
+        .with_default_allow(ActionClass::Read);
+    checker.add_policy(NoopFor("EditorsOnly", std::marker::PhantomData));
+
+    let read = checker
+        .bind(&session, &Subject, &DocumentAction::View, &Ctx)
+        .check(&resource)
+        .await;
+    read.assert_granted_by("PermissionChecker");
+    read.assert_trace_contains("read actions are allowed by default");
+
+    let write = checker
+        .bind(&session, &Subject, &DocumentAction::Edit, &Ctx)
+        .check(&resource)
+        .await;
+    assert_eq!(write.denied_reason(), Some("All policies denied access"));
+
+    let batch = checker
+        .bind(&session, &Subject, &DocumentAction::View, &Ctx)
+        .evaluate([Resource { id: 2 }, Resource { id: 3 }])
+        .await;
+    assert!(batch.iter().all(|(_, evaluation)| evaluation.is_granted()));
+
+    let mut vetoed = checker.clone();
+    vetoed.add_policy(
+        PolicyBuilder::<ClassifiedDomain>::new("Quarantine")
+            .forbid()
+            .build(),
+    );
+    let read = vetoed
+        .bind(&session, &Subject, &DocumentAction::View, &Ctx)
+        .check(&resource)
+        .await;
+    assert_eq!(read.forbidden_by(), Some("Quarantine"));
+}
+
+#[tokio::test]
+async fn default_allow_does_not_cover_indeterminate_results() {
+    struct Unavailable;
+
+    #[async_trait]
+    impl Policy<ClassifiedDomain> for Unavailable {
+        async fn evaluate(&self, ctx: &EvalCtx<'_, ClassifiedDomain>) -> PolicyEvalResult {
+            ctx.indeterminate("backend unavailable")
+        }
+
+        fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Unavailable")
+        }
+    }
+
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<ClassifiedDomain>::new()
+        .with_action_classifier(|_: &DocumentAction| ActionClass::Read)
+        .with_default_allow(ActionClass::Read);
+    checker.add_policy(Unavailable);
+
+    let evaluation = checker
+        .bind(&session, &Subject, &DocumentAction::View, &Ctx)
+        .check(&Resource { id: 1 })
+        .await;
+    assert!(!evaluation.is_granted());
+}
+
+#[tokio::test]
+async fn coverage_sorts_every_policy_by_whether_it_decided() {
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(grant_even_resources("EvenOwners"));
+    checker.add_policy(NamedNoopPolicy { name: "Auditors" });
+    checker.add_policy(forbid_odd_resources("OddQuarantine"));
+    checker.add_policy(allow_everything("Everyone"));
+
+    let coverage = checker
+        .coverage(&session, &Subject, &Action, &Resource { id: 2 }, &Ctx)
+        .await;
+    assert_eq!(coverage.decided, ["EvenOwners", "Everyone"]);
+    assert_eq!(coverage.abstained, ["OddQuarantine", "Auditors"]);
+    assert!(coverage.indeterminate.is_empty());
+    assert!(!coverage.is_gap());
+
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(grant_even_resources("EvenOwners"));
+    checker.add_policy(NamedNoopPolicy { name: "Auditors" });
+    let coverage = checker
+        .coverage(&session, &Subject, &Action, &Resource { id: 3 }, &Ctx)
+        .await;
+    assert!(coverage.decided.is_empty());
+    assert_eq!(coverage.abstained, ["EvenOwners", "Auditors"]);
+    assert!(coverage.is_gap());
+}
+
+#[tokio::test]
+async fn only_one_applicable_requires_exactly_one_deciding_policy() {
+    let session = EvaluationSession::empty();
+    let mut checker =
+        PermissionChecker::<Domain>::with_strategy(CombineStrategy::OnlyOneApplicable);
+    checker.add_policy(NamedNoopPolicy { name: "Auditors" });
+    checker.add_policy(grant_even_resources("EvenOwners"));
+    checker.add_policy(forbid_odd_resources("OddQuarantine"));
+
+    // Exactly one applies to each resource: the grant to even ids, the forbid
+    // to odd ids.
+    let even = check_resource(&checker, &session, &Resource { id: 2 }).await;
+    even.assert_granted_by("EvenOwners");
+    assert!(even.display_trace().contains("ONLY_ONE_APPLICABLE"));
+    let odd = check_resource(&checker, &session, &Resource { id: 3 }).await;
+    assert_eq!(odd.forbidden_by(), Some("OddQuarantine"));
+
+    // None applies.
+    let mut unhandled =
+        PermissionChecker::<Domain>::with_strategy(CombineStrategy::OnlyOneApplicable);
+    unhandled.add_policy(NamedNoopPolicy { name: "Auditors" });
+    unhandled.add_policy(grant_even_resources("EvenOwners"));
+    let evaluation = check_resource(&unhandled, &session, &Resource { id: 3 }).await;
+    assert_eq!(
+        evaluation.denied_reason(),
+        Some("All policies denied access")
+    );
+
+    // Two apply, even though both grant.
+    checker.add_policy(allow_everything("Everyone"));
+    let evaluation = check_resource(&checker, &session, &Resource { id: 2 }).await;
+    assert_eq!(
+        evaluation.denied_reason(),
+        Some("multiple applicable policies")
+    );
+    assert!(evaluation
+        .trace()
+        .root()
+        .is_some_and(|root| root.is_indeterminate()));
+
+    let batch =
+        evaluate_resources(&checker, &session, [Resource { id: 2 }, Resource { id: 3 }]).await;
+    for (resource, evaluation) in &batch {
+        let single = check_resource(&checker, &session, resource).await;
+        assert_eq!(evaluation.denied_reason(), single.denied_reason());
+        assert_eq!(evaluation.display_trace(), single.display_trace());
+    }
+    for id in 0..4 {
+        checker
+            .assert_fail_closed(&session, &Subject, &Action, &Resource { id }, &Ctx)
+            .await;
+    }
+}
+
+#[test]
+#[allow(deprecated)]
+fn checker_debug_reports_the_configured_strategy() {
+    for strategy in [
+        CombineStrategy::DenyOverrides,
+        CombineStrategy::AnyAllow,
+        CombineStrategy::AllAllow,
+        CombineStrategy::OnlyOneApplicable,
+    ] {
+        let mut checker = PermissionChecker::<Domain>::with_strategy(strategy);
+        checker.add_policy(allow_everything("Everyone"));
+        assert_eq!(checker.strategy(), strategy);
+        let debug = format!("{checker:?}");
+        assert!(
+            debug.contains(&format!("strategy: {strategy:?}")),
+            "{debug}"
+        );
+        assert!(debug.contains("\"Everyone\""), "{debug}");
+    }
+
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.set_strategy(CombineStrategy::AnyAllow);
+    assert_eq!(checker.strategy(), CombineStrategy::AnyAllow);
+    assert_eq!(checker.clone().strategy(), CombineStrategy::AnyAllow);
+}
+
+#[tokio::test]
+async fn explain_reports_every_policy_and_matches_the_fast_path_decision() {
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(grant_even_resources("EvenOwners"));
+    checker.add_policy(NamedNoopPolicy { name: "Auditors" });
+    checker.add_policy(forbid_odd_resources("OddQuarantine"));
+    checker.add_policy(allow_everything("Everyone"));
+
+    for (id, winner, grants) in [(2, "EvenOwners", 2), (3, "OddQuarantine", 1)] {
+        let resource = Resource { id };
+        let explanation = checker
+            .explain(&session, &Subject, &Action, &resource, &Ctx)
+            .await;
+        let fast = check_resource(&checker, &session, &resource).await;
+
+        assert_eq!(explanation.decision.is_granted(), fast.is_granted());
+        assert_eq!(
+            explanation.decision.display_trace(),
+            fast.display_trace(),
+            "the decision trace is the short-circuited one"
+        );
+        assert_eq!(explanation.winning_policy.as_deref(), Some(winner));
+        // Every policy is reported, including grants the fast path skipped.
+        assert_eq!(explanation.results.len(), 4);
+        assert_eq!(
+            explanation
+                .results
+                .iter()
+                .filter(|result| result.is_granted())
+                .count(),
+            grants
+        );
+    }
+}
+
+#[tokio::test]
+async fn max_trace_children_summarizes_excess_denials() {
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<Domain>::new().with_max_trace_children(10);
+    for _ in 0..100 {
+        checker.add_policy(NamedNoopPolicy { name: "Abstains" });
+    }
+
+    let evaluation = check_resource(&checker, &session, &Resource { id: 1 }).await;
+    assert!(!evaluation.is_granted());
+    let Some(PolicyEvalResult::Combined { children, .. }) = evaluation.trace().root() else {
+        panic!("expected a combined root");
+    };
+    assert_eq!(children.len(), 11);
+    assert_eq!(
+        children[10].reason_str(),
+        Some("... and 90 more denied"),
+        "{}",
+        evaluation.display_trace()
+    );
+
+    // The deciding grant survives truncation.
+    checker.add_policy(allow_everything("Everyone"));
+    let evaluation = check_resource(&checker, &session, &Resource { id: 1 }).await;
+    evaluation.assert_granted_by("Everyone");
+    evaluation.assert_trace_contains("Everyone");
+    evaluation.assert_trace_contains("... and 90 more denied");
+}
+
+#[tokio::test]
+async fn max_trace_children_keeps_indeterminate_results() {
+    struct Unavailable;
+
+    #[async_trait]
+    impl Policy<Domain> for Unavailable {
+        async fn evaluate(&self, ctx: &EvalCtx<'_, Domain>) -> PolicyEvalResult {
+            ctx.indeterminate("backend unavailable")
+        }
+
+        fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+            std::borrow::Cow::Borrowed("Unavailable")
+        }
+    }
+
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<Domain>::new().with_max_trace_children(2);
+    for _ in 0..5 {
+        checker.add_policy(NamedNoopPolicy { name: "Abstains" });
+    }
+    checker.add_policy(Unavailable);
+
+    let evaluation = check_resource(&checker, &session, &Resource { id: 1 }).await;
+    assert!(
+        evaluation.is_indeterminate(),
+        "{}",
+        evaluation.display_trace()
+    );
+    evaluation.assert_trace_contains("Unavailable INDETERMINATE");
+    evaluation.assert_trace_contains("... and 3 more denied");
+}
+
+#[tokio::test]
+async fn observer_sees_each_evaluated_policy_on_every_path() {
+    let observed = Arc::new(Mutex::new(Vec::new()));
+    let sink = Arc::clone(&observed);
+    let mut checker = PermissionChecker::<Domain>::new().with_observer(Arc::new(
+        move |policy_type: &str, result: &PolicyEvalResult, _duration| {
+            sink.lock()
+                .unwrap()
+                .push((policy_type.to_string(), result.is_granted()));
+        },
+    ));
+    checker.add_policy(NamedNoopPolicy { name: "Auditors" });
+    checker.add_policy(allow_everything("Everyone"));
+    let session = EvaluationSession::empty();
+    let expected = vec![
+        ("Auditors".to_string(), false),
+        ("Everyone".to_string(), true),
+    ];
+
+    check_resource(&checker, &session, &Resource { id: 1 }).await;
+    assert_eq!(std::mem::take(&mut *observed.lock().unwrap()), expected);
+
+    bind(&checker, &session)
+        .check_parallel(&Resource { id: 1 })
+        .await;
+    assert_eq!(std::mem::take(&mut *observed.lock().unwrap()), expected);
+
+    evaluate_resources(&checker, &session, [Resource { id: 1 }, Resource { id: 2 }]).await;
+    assert_eq!(observed.lock().unwrap().len(), 4);
+}
+
+#[tokio::test]
+async fn mounted_sub_checker_only_engages_for_routed_resources() {
+    let is_payment = |resource: &Resource| resource.id >= 100;
+    let mut billing = PermissionChecker::<Domain>::named("billing");
+    billing.add_policy(allow_everything("BillingClerk"));
+    billing.add_policy(forbid_odd_resources("FrozenAccounts"));
+    let mut root = PermissionChecker::<Domain>::new();
+    root.mount(is_payment, billing);
+    root.add_policy(grant_even_resources("Posts"));
+    let session = EvaluationSession::empty();
+
+    let payment = check_resource(&root, &session, &Resource { id: 100 }).await;
+    assert_eq!(payment.granted_policy_type(), Some("billing"));
+    payment.assert_trace_contains("BillingClerk");
+
+    let frozen = check_resource(&root, &session, &Resource { id: 101 }).await;
+    assert_eq!(frozen.forbidden_by(), Some("FrozenAccounts"));
+
+    let post = check_resource(&root, &session, &Resource { id: 2 }).await;
+    assert_eq!(post.granted_policy_type(), Some("Posts"));
+
+    let odd_post = check_resource(&root, &session, &Resource { id: 3 }).await;
+    assert!(!odd_post.is_granted());
+    assert_eq!(odd_post.forbidden_by(), None);
+    odd_post.assert_trace_contains("Resource is not routed to this checker");
+}
+
+#[tokio::test]
+async fn policies_can_be_listed_and_removed_by_type() {
+    let mut checker = PermissionChecker::<Domain>::new();
+    assert!(checker.is_empty());
+    checker.add_policy(grant_even_resources("EvenOnly"));
+    checker.add_policy(forbid_odd_resources("NoOdd"));
+    checker.add_policy(allow_everything("Everyone"));
+    assert_eq!(checker.len(), 3);
+    assert_eq!(checker.policy_types(), ["NoOdd", "EvenOnly", "Everyone"]);
+    let session = EvaluationSession::empty();
+
+    assert!(checker.remove_policy_by_type("NoOdd"));
+    assert!(!checker.remove_policy_by_type("NoOdd"));
+    assert_eq!(checker.policy_types(), ["EvenOnly", "Everyone"]);
+    check_resource(&checker, &session, &Resource { id: 1 })
+        .await
+        .assert_granted_by("Everyone");
+
+    assert!(checker.remove_policy_by_type("Everyone"));
+    checker.add_policy(forbid_odd_resources("NoOdd"));
+    assert_eq!(checker.policy_types(), ["NoOdd", "EvenOnly"]);
+    assert_eq!(
+        check_resource(&checker, &session, &Resource { id: 1 })
+            .await
+            .forbidden_by(),
+        Some("NoOdd")
+    );
+}
+
+#[tokio::test]
+async fn mapped_policy_abstains_for_requests_it_cannot_map() {
+    let mut invoices = PermissionChecker::<UnitContextDomain>::named("Invoices");
+    invoices.add_policy(
+        PolicyBuilder::<UnitContextDomain>::new("PaidInvoices")
+            .resources(|resource: &Resource| resource.id % 2 == 0)
+            .build(),
+    );
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(MappedPolicy::<Domain, UnitContextDomain>::new(
+        "InvoiceRoute",
+        invoices,
+        |_: &Subject, _: &Action, resource: &Resource, _: &Ctx| {
+            (resource.id >= 100).then(|| (Subject, Action, resource.clone(), ()))
+        },
+    ));
+    let session = EvaluationSession::empty();
+
+    let paid = check_resource(&checker, &session, &Resource { id: 100 }).await;
+    paid.assert_granted_by("InvoiceRoute");
+    paid.assert_trace_contains("PaidInvoices");
+
+    let unpaid = check_resource(&checker, &session, &Resource { id: 101 }).await;
+    unpaid.assert_denied();
+    unpaid.assert_trace_contains("PaidInvoices");
+
+    let post = check_resource(&checker, &session, &Resource { id: 2 }).await;
+    post.assert_not_applicable_by("InvoiceRoute");
+    post.assert_trace_contains("Request does not map to the delegated checker");
+}
+
+#[tokio::test]
+async fn explain_reports_results_in_first_match_rule_order() {
+    let session = EvaluationSession::empty();
+    let mut checker = PermissionChecker::<Domain>::with_strategy(CombineStrategy::FirstMatch {
+        default: Effect::Allow,
+    });
+    checker.add_policy(allow_everything("Everyone"));
+    checker.add_forbid_policy(forbid_odd_resources("OddQuarantine"));
+
+    let explanation = checker
+        .explain(&session, &Subject, &Action, &Resource { id: 3 }, &Ctx)
+        .await;
+
+    explanation.decision.assert_granted_by("Everyone");
+    assert!(explanation.results[0].format(0).contains("Everyone"));
+    assert!(explanation.results[1].is_forbidden());
+}
+
+struct InvoiceIdDomain;
+
+impl PolicyDomain for InvoiceIdDomain {
+    type Subject = Subject;
+    type Action = Action;
+    type Resource = u8;
+    type Context = Ctx;
+}
+
+#[tokio::test]
+async fn map_resource_runs_narrowed_policies_only_for_mappable_resources() {
+    let paid = PolicyBuilder::<InvoiceIdDomain>::new("PaidInvoices")
+        .resources(|invoice: &u8| invoice % 2 == 0)
+        .build();
+    let mut checker = PermissionChecker::<Domain>::new();
+    checker.add_policy(paid.map_resource(|resource: &Resource| resource.id.checked_sub(100)));
+    let session = EvaluationSession::empty();
+
+    check_resource(&checker, &session, &Resource { id: 100 })
+        .await
+        .assert_granted_by("PaidInvoices");
+    check_resource(&checker, &session, &Resource { id: 101 })
+        .await
+        .assert_not_applicable_by("PaidInvoices");
+
+    let post = check_resource(&checker, &session, &Resource { id: 2 }).await;
+    post.assert_not_applicable_by("PaidInvoices");
+    post.assert_trace_contains("Resource is outside this policy's scope");
+}
+
+struct MemoDomain;
+
+impl PolicyDomain for MemoDomain {
+    type Subject = u8;
+    type Action = &'static str;
+    type Resource = u8;
+    type Context = ();
+}
+
+#[tokio::test]
+async fn with_memoization_reuses_evaluations_for_equal_inputs() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let counted = Arc::clone(&runs);
+    let mut checker = PermissionChecker::<MemoDomain>::new();
+    checker.add_policy(
+        PolicyBuilder::<MemoDomain>::new("Owner")
+            .when(move |subject: &u8, _: &&str, resource: &u8, _: &()| {
+                counted.fetch_add(1, Ordering::SeqCst);
+                subject == resource
+            })
+            .build(),
+    );
+    let checker = checker.with_memoization(2, std::time::Duration::from_secs(60));
+    let session = EvaluationSession::empty();
+    let bound = checker.bind(&session, &1, &"read", &());
+
+    bound.check(&1).await.assert_granted_by("Owner");
+    let memoized = bound.check(&1).await;
+    memoized.assert_granted_by("Owner");
+    memoized.assert_trace_contains("(cached)");
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+    assert!(!bound.check(&2).await.is_granted());
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+    // A third distinct request evicts the entry closest to expiry.
+    bound.check(&3).await;
+    bound.check(&2).await;
+    assert_eq!(runs.load(Ordering::SeqCst), 3);
+    bound.check(&1).await;
+    assert_eq!(runs.load(Ordering::SeqCst), 4);
+
+    checker.clear_memoized();
+    bound.check(&1).await;
+    assert_eq!(runs.load(Ordering::SeqCst), 5);
+}
+
+#[tokio::test]
+async fn explain_bypasses_the_memo_and_observers() {
+    let runs = Arc::new(AtomicUsize::new(0));
+    let counted = Arc::clone(&runs);
+    let observed = Arc::new(AtomicUsize::new(0));
+    let observations = Arc::clone(&observed);
+    let mut checker = PermissionChecker::<MemoDomain>::new();
+    checker.add_policy(
+        PolicyBuilder::<MemoDomain>::new("Owner")
+            .when(move |subject: &u8, _: &&str, resource: &u8, _: &()| {
+                counted.fetch_add(1, Ordering::SeqCst);
+                subject == resource
+            })
+            .build(),
+    );
+    let checker = checker
+        .with_observer(Arc::new(
+            move |_: &str, _: &PolicyEvalResult, _: std::time::Duration| {
+                observations.fetch_add(1, Ordering::SeqCst);
+            },
+        ))
+        .with_memoization(4, std::time::Duration::from_secs(60));
+    let session = EvaluationSession::empty();
+
+    let explanation = checker.explain(&session, &1, &"read", &1, &()).await;
+    explanation.decision.assert_granted_by("Owner");
+    assert_eq!(runs.load(Ordering::SeqCst), 1);
+    assert_eq!(observed.load(Ordering::SeqCst), 0);
+
+    // The explanation was not memoized, so the check evaluates afresh and
+    // memoizes its own result, which a second explanation does not reuse.
+    let checked = checker.bind(&session, &1, &"read", &()).check(&1).await;
+    assert!(!checked.display_trace().contains("(cached)"));
+    assert_eq!(runs.load(Ordering::SeqCst), 2);
+    let explanation = checker.explain(&session, &1, &"read", &1, &()).await;
+    assert!(!explanation.decision.display_trace().contains("(cached)"));
+    assert_eq!(runs.load(Ordering::SeqCst), 3);
+}
@@ -0,0 +1,134 @@
+#![cfg(feature = "tonic")]
+
+use axum::http::{Request, Response};
+use gatehouse::{AccessEvaluation, GrpcAuthLayer, PermissionChecker, PolicyBuilder, PolicyDomain};
+use std::convert::Infallible;
+use tonic::metadata::MetadataMap;
+use tonic::{Code, Status};
+use tower::{service_fn, Layer, ServiceExt};
+
+struct Invoices;
+
+#[derive(Debug, Clone)]
+struct Caller {
+    tenant: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Action {
+    Read,
+    Write,
+}
+
+#[derive(Debug, Clone)]
+struct Invoice {
+    tenant: String,
+}
+
+impl PolicyDomain for Invoices {
+    type Subject = Caller;
+    type Action = Action;
+    type Resource = Invoice;
+    type Context = ();
+}
+
+fn metadata_value(metadata: &MetadataMap, key: &str) -> String {
+    metadata
+        .get(key)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string()
+}
+
+fn layer() -> GrpcAuthLayer<Invoices> {
+    let mut checker = PermissionChecker::<Invoices>::new();
+    checker.add_policy(
+        PolicyBuilder::<Invoices>::new("SameTenantReads")
+            .actions(|action: &Action| *action == Action::Read)
+            .when(|caller: &Caller, _: &Action, invoice: &Invoice, _: &()| {
+                caller.tenant == invoice.tenant
+            })
+            .build(),
+    );
+    GrpcAuthLayer::new(
+        checker,
+        |metadata: &MetadataMap| Caller {
+            tenant: metadata_value(metadata, "x-tenant"),
+        },
+        |method: &str| {
+            if method.ends_with("/Get") {
+                Action::Read
+            } else {
+                Action::Write
+            }
+        },
+        |_: &MetadataMap| (),
+    )
+    .with_resource("/billing.Invoices/Get", |metadata: &MetadataMap| Invoice {
+        tenant: metadata_value(metadata, "x-invoice-tenant"),
+    })
+    .with_resource("/billing.Invoices/Delete", |metadata: &MetadataMap| {
+        Invoice {
+            tenant: metadata_value(metadata, "x-invoice-tenant"),
+        }
+    })
+}
+
+async fn call(layer: GrpcAuthLayer<Invoices>, method: &str, tenant: &str) -> Response<String> {
+    let service = layer.layer(service_fn(|request: Request<()>| async move {
+        let granted_by = request
+            .extensions()
+            .get::<AccessEvaluation>()
+            .and_then(AccessEvaluation::granted_policy_type)
+            .unwrap_or_default()
+            .to_string();
+        Ok::<_, Infallible>(Response::new(granted_by))
+    }));
+    let request = Request::builder()
+        .uri(method)
+        .header("x-tenant", "acme")
+        .header("x-invoice-tenant", tenant)
+        .body(())
+        .unwrap();
+    service.oneshot(request).await.unwrap()
+}
+
+fn grpc_status(response: &Response<String>) -> Option<Status> {
+    Status::from_header_map(response.headers())
+}
+
+#[tokio::test]
+async fn granted_calls_reach_the_service_with_the_evaluation() {
+    let response = call(layer(), "/billing.Invoices/Get", "acme").await;
+
+    assert!(grpc_status(&response).is_none());
+    assert_eq!(response.body(), "SameTenantReads");
+}
+
+#[tokio::test]
+async fn denied_calls_get_permission_denied() {
+    let response = call(layer(), "/billing.Invoices/Get", "globex").await;
+    let status = grpc_status(&response).unwrap();
+    assert_eq!(status.code(), Code::PermissionDenied);
+    assert!(!status.message().contains("SameTenantReads"));
+
+    let response = call(
+        layer().with_trace_in_status(),
+        "/billing.Invoices/Delete",
+        "acme",
+    )
+    .await;
+    let status = grpc_status(&response).unwrap();
+    assert_eq!(status.code(), Code::PermissionDenied);
+    assert!(status.message().contains("SameTenantReads"));
+}
+
+#[tokio::test]
+async fn methods_without_a_resource_extractor_are_denied() {
+    let response = call(layer(), "/billing.Invoices/List", "acme").await;
+
+    let status = grpc_status(&response).unwrap();
+    assert_eq!(status.code(), Code::PermissionDenied);
+    assert!(status.message().contains("/billing.Invoices/List"));
+    assert!(response.body().is_empty());
+}
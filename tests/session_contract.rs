@@ -849,6 +849,76 @@ async fn concurrent_waiters_observe_same_source_error() {
     assert_eq!(calls.load(Ordering::SeqCst), 1);
 }
 
+#[tokio::test]
+async fn get_or_load_dedupes_concurrent_loads_and_caches_per_session() {
+    let calls = Arc::new(AtomicUsize::new(0));
+    let started = Arc::new(Notify::new());
+    let release = Arc::new(Notify::new());
+    let session = EvaluationSession::new();
+
+    let load = |calls: Arc<AtomicUsize>, started: Arc<Notify>, release: Arc<Notify>| async move {
+        calls.fetch_add(1, Ordering::SeqCst);
+        started.notify_one();
+        release.notified().await;
+        FactLoadResult::Found(42)
+    };
+
+    let leader_session = session.clone();
+    let leader = tokio::spawn({
+        let (calls, started, release) = (calls.clone(), started.clone(), release.clone());
+        async move {
+            leader_session
+                .get_or_load(TestKey(1), || load(calls, started, release))
+                .await
+        }
+    });
+    started.notified().await;
+
+    let waiter_session = session.clone();
+    let waiter = tokio::spawn({
+        let (calls, started, release) = (calls.clone(), started.clone(), release.clone());
+        async move {
+            waiter_session
+                .get_or_load(TestKey(1), || load(calls, started, release))
+                .await
+        }
+    });
+    tokio::task::yield_now().await;
+
+    release.notify_one();
+    assert_found(&leader.await.unwrap(), 42);
+    assert_found(&waiter.await.unwrap(), 42);
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    // Later lookups, including plain `get`, are served from the cache.
+    let cached = session
+        .get_or_load(TestKey(1), || async { FactLoadResult::Found(0) })
+        .await;
+    assert_found(&cached, 42);
+    assert_found(&session.get(TestKey(1)).await, 42);
+
+    // A new session does not see the cached value.
+    let fresh = EvaluationSession::new()
+        .get_or_load(TestKey(1), || async { FactLoadResult::Found(7) })
+        .await;
+    assert_found(&fresh, 7);
+
+    // The shared empty session never caches.
+    let shared = EvaluationSession::shared_empty();
+    assert_found(
+        &shared
+            .get_or_load(TestKey(1), || async { FactLoadResult::Found(1) })
+            .await,
+        1,
+    );
+    assert_found(
+        &shared
+            .get_or_load(TestKey(1), || async { FactLoadResult::Found(2) })
+            .await,
+        2,
+    );
+}
+
 proptest! {
     #[test]
     fn get_many_preserves_input_order_for_arbitrary_duplicate_keys(raw_keys in prop::collection::vec(0u16..32, 0..200)) {
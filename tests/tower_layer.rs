@@ -0,0 +1,128 @@
+#![cfg(feature = "tower")]
+
+use async_trait::async_trait;
+use axum::{
+    body::{to_bytes, Body},
+    http::{Request, StatusCode},
+    routing::get,
+    Extension, Router,
+};
+use gatehouse::{
+    AccessEvaluation, EvalCtx, GatehouseLayer, Obligation, PermissionChecker, Policy, PolicyDomain,
+    PolicyEvalResult,
+};
+use std::borrow::Cow;
+use tower::ServiceExt;
+
+struct Documents;
+
+#[derive(Debug, Clone)]
+struct User {
+    name: String,
+}
+
+#[derive(Debug, Clone)]
+struct Read;
+
+#[derive(Debug, Clone)]
+struct Document {
+    path: String,
+}
+
+impl PolicyDomain for Documents {
+    type Subject = User;
+    type Action = Read;
+    type Resource = Document;
+    type Context = ();
+}
+
+type Extract = fn(&Request<Body>) -> (User, Read, Document, ());
+
+struct OwnDocuments;
+
+#[async_trait]
+impl Policy<Documents> for OwnDocuments {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Documents>) -> PolicyEvalResult {
+        if ctx.resource.path == format!("/docs/{}", ctx.subject.name) {
+            ctx.grant("reader owns the document")
+                .with_obligation(Obligation::new("watermark", "owner"))
+        } else {
+            ctx.not_applicable("reader does not own the document")
+        }
+    }
+
+    fn policy_type(&self) -> Cow<'static, str> {
+        Cow::Borrowed("OwnDocuments")
+    }
+}
+
+fn extract(request: &Request<Body>) -> (User, Read, Document, ()) {
+    let name = request
+        .headers()
+        .get("x-user")
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let path = request.uri().path().to_string();
+    (User { name }, Read, Document { path }, ())
+}
+
+fn app(layer: GatehouseLayer<Documents, Extract>) -> Router {
+    Router::new()
+        .route(
+            "/docs/{name}",
+            get(
+                |Extension(evaluation): Extension<AccessEvaluation>| async move {
+                    evaluation
+                        .obligations()
+                        .iter()
+                        .map(|obligation| obligation.to_string())
+                        .collect::<Vec<_>>()
+                        .join(",")
+                },
+            ),
+        )
+        .layer(layer)
+}
+
+fn checker() -> PermissionChecker<Documents> {
+    let mut checker = PermissionChecker::<Documents>::new();
+    checker.add_policy(OwnDocuments);
+    checker
+}
+
+async fn get_doc(app: Router, user: &str, path: &str) -> (StatusCode, String) {
+    let request = Request::builder()
+        .uri(path)
+        .header("x-user", user)
+        .body(Body::empty())
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    let status = response.status();
+    let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, String::from_utf8(body.to_vec()).unwrap())
+}
+
+#[tokio::test]
+async fn granted_requests_reach_the_handler_with_the_evaluation() {
+    let layer = GatehouseLayer::new(checker(), extract as Extract);
+
+    let (status, body) = get_doc(app(layer), "alice", "/docs/alice").await;
+
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body, "watermark=owner");
+}
+
+#[tokio::test]
+async fn denied_requests_are_answered_with_403_before_the_handler() {
+    let layer = GatehouseLayer::new(checker(), extract as Extract);
+
+    let (status, body) = get_doc(app(layer.clone()), "bob", "/docs/alice").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(!body.is_empty());
+    assert!(!body.contains("OwnDocuments"));
+
+    let (status, body) = get_doc(app(layer.with_trace_in_response()), "bob", "/docs/alice").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    assert!(body.contains("OwnDocuments"));
+}
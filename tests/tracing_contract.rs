@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use gatehouse::{
-    BatchEvalCtx, EvalCtx, EvaluationSession, PermissionChecker, Policy, PolicyBuilder,
-    PolicyDomain, PolicyEvalResult,
+    AndPolicy, BatchEvalCtx, EvalCtx, EvaluationSession, NotPolicy, PermissionChecker, Policy,
+    PolicyBuilder, PolicyDomain, PolicyEvalResult, PolicyExt,
 };
 use std::collections::{BTreeMap, BTreeSet};
 use std::fmt;
@@ -143,6 +143,19 @@ impl Policy<Domain> for WrongLengthTracePolicy {
     }
 }
 
+struct UndecidedPolicy;
+
+#[async_trait]
+impl Policy<Domain> for UndecidedPolicy {
+    async fn evaluate(&self, ctx: &EvalCtx<'_, Domain>) -> PolicyEvalResult {
+        ctx.indeterminate("fact store unavailable")
+    }
+
+    fn policy_type(&self) -> std::borrow::Cow<'static, str> {
+        std::borrow::Cow::Borrowed("UndecidedPolicy")
+    }
+}
+
 fn result_for(allowed: bool) -> PolicyEvalResult {
     if allowed {
         PolicyEvalResult::granted("TracePolicy", Some("allowed".to_string()))
@@ -154,6 +167,8 @@ fn result_for(allowed: bool) -> PolicyEvalResult {
 #[derive(Clone, Debug)]
 struct CapturedSpan {
     name: String,
+    /// The parent's `policy.type` when recorded at creation, else its name.
+    parent: Option<String>,
     fields: BTreeSet<String>,
     values: BTreeMap<String, String>,
 }
@@ -197,8 +212,16 @@ where
     fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: LayerContext<'_, S>) {
         let mut visitor = FieldValues::default();
         attrs.record(&mut visitor);
+        let parent = ctx.span(id).and_then(|span| span.parent()).map(|parent| {
+            parent
+                .extensions()
+                .get::<Arc<Mutex<CapturedSpan>>>()
+                .and_then(|captured| captured.lock().unwrap().values.get("policy.type").cloned())
+                .unwrap_or_else(|| parent.name().to_string())
+        });
         let span = Arc::new(Mutex::new(CapturedSpan {
             name: attrs.metadata().name().to_string(),
+            parent,
             fields: attrs
                 .metadata()
                 .fields()
@@ -511,6 +534,56 @@ fn tracing_fields_are_recorded_for_denied_decisions() {
     assert_value(batch, "max_batch_size", "2");
 }
 
+#[test]
+fn denials_mark_the_evaluate_span_as_an_otel_error() {
+    let checker = checker_with_policy();
+    let session = EvaluationSession::empty();
+    let (_result, spans) = capture_async(|| async {
+        checker
+            .bind(&session, &Subject, &Action, &Ctx)
+            .check(&Resource { allowed: true })
+            .await
+    });
+    let single = span(&spans, "evaluate_one");
+    assert_fields(
+        single,
+        &["otel.status_code", "otel.status_description", "error.type"],
+    );
+    assert!(
+        !single.values.contains_key("otel.status_code"),
+        "grants leave the span status unset; values: {:?}",
+        single.values
+    );
+
+    let (result, spans) = capture_async(|| async {
+        checker
+            .bind(&session, &Subject, &Action, &Ctx)
+            .check(&Resource { allowed: false })
+            .await
+    });
+    let single = span(&spans, "evaluate_one");
+    assert_value(single, "otel.status_code", "ERROR");
+    assert_value(
+        single,
+        "otel.status_description",
+        result.denied_reason().unwrap(),
+    );
+    assert_value(single, "error.type", "access_denied");
+
+    let mut checker = PermissionChecker::new();
+    checker.add_policy(UndecidedPolicy);
+    let (result, spans) = capture_async(|| async {
+        checker
+            .bind(&session, &Subject, &Action, &Ctx)
+            .check(&Resource { allowed: true })
+            .await
+    });
+    assert!(result.is_indeterminate());
+    let single = span(&spans, "evaluate_one");
+    assert_value(single, "otel.status_code", "ERROR");
+    assert_value(single, "error.type", "indeterminate");
+}
+
 #[test]
 fn tracing_fields_are_recorded_for_empty_policy_decisions() {
     let checker = PermissionChecker::<Domain>::new();
@@ -807,3 +880,75 @@ fn tracing_records_allow_effect_contract_violation_warning() {
         "an allow policy that does not forbid must not emit the contract-violation warning: {allow_events:#?}"
     );
 }
+
+#[test]
+fn policy_spans_nest_like_the_result_tree() {
+    let mut checker = PermissionChecker::new();
+    checker.add_policy(
+        AndPolicy::try_new(vec![Arc::new(TracePolicy), Arc::new(TracePolicy)]).unwrap(),
+    );
+    let session = EvaluationSession::empty();
+    let (_result, spans) = capture_async(|| async {
+        checker
+            .bind(&session, &Subject, &Action, &Ctx)
+            .check(&Resource { allowed: true })
+            .await
+    });
+
+    let policy_spans = spans
+        .iter()
+        .filter(|span| span.name == "gatehouse.policy")
+        .collect::<Vec<_>>();
+    assert_eq!(policy_spans.len(), 3, "{policy_spans:#?}");
+
+    let root = policy_spans[0];
+    assert_value(root, "policy.type", "AndPolicy");
+    assert_value(root, "outcome", "granted");
+    assert_eq!(root.parent.as_deref(), Some("evaluate_one"));
+    for leaf in &policy_spans[1..] {
+        assert_fields(leaf, &["policy.type", "outcome", "reason"]);
+        assert_value(leaf, "policy.type", "TracePolicy");
+        assert_value(leaf, "outcome", "granted");
+        assert_value(leaf, "reason", "allowed");
+        assert_eq!(leaf.parent.as_deref(), Some("AndPolicy"));
+    }
+}
+
+#[test]
+fn compiled_policy_spans_nest_like_the_interpreted_tree() {
+    let tree = || {
+        AndPolicy::try_new(vec![
+            Arc::new(TracePolicy),
+            Arc::new(NotPolicy::new(UndecidedPolicy).or(TracePolicy)),
+        ])
+        .unwrap()
+    };
+    let policy_spans = |policy: Arc<dyn Policy<Domain>>| {
+        let mut checker = PermissionChecker::new();
+        checker.add_policy(policy);
+        let session = EvaluationSession::empty();
+        let (_result, spans) = capture_async(|| async {
+            checker
+                .bind(&session, &Subject, &Action, &Ctx)
+                .check(&Resource { allowed: true })
+                .await
+        });
+        spans
+            .into_iter()
+            .filter(|span| span.name == "gatehouse.policy")
+            .map(|span| {
+                (
+                    span.values.get("policy.type").cloned(),
+                    span.parent,
+                    span.values.get("outcome").cloned(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let interpreted = policy_spans(Arc::new(tree()));
+    let compiled = policy_spans(Arc::new(tree().compile()));
+
+    assert_eq!(interpreted.len(), 6, "{interpreted:#?}");
+    assert_eq!(compiled, interpreted);
+}